@@ -0,0 +1,246 @@
+//! Typed reads of governance-rewards accounts.
+//!
+//! Fetchers work with any [`AccountSource`]. `BanksClient` is supported with the `banks`
+//! feature and the nonblocking `RpcClient` with the `rpc` feature. Accounts which still
+//! need one of the `migrate_*` instructions fail to deserialize.
+
+use std::fmt;
+
+use anchor_lang::{prelude::Pubkey, AccountDeserialize, Discriminator};
+use async_trait::async_trait;
+use governance_rewards::state::{
+    claim_data::ClaimData, distribution::Distribution, preferences::UserPreferences,
+};
+use solana_sdk::account::Account;
+
+use crate::pda;
+
+/// Offset of `ClaimData::distribution`, after the discriminator and `weight`.
+pub const CLAIM_DATA_DISTRIBUTION_OFFSET: usize = 8 + 8;
+/// Offset of `ClaimData::belongs_to`, after `distribution` and three single byte fields.
+pub const CLAIM_DATA_BELONGS_TO_OFFSET: usize = CLAIM_DATA_DISTRIBUTION_OFFSET + 32 + 3;
+
+#[derive(Debug)]
+pub enum FetchError {
+    /// The source failed to return accounts.
+    Source(Box<dyn std::error::Error + Send + Sync>),
+    /// The source cannot list program accounts.
+    Unsupported,
+    AccountNotFound(Pubkey),
+    /// The account is not owned by governance-rewards.
+    InvalidOwner(Pubkey),
+    /// The account does not hold the expected type, or has not been migrated.
+    InvalidData(Pubkey, anchor_lang::error::Error),
+}
+
+impl fmt::Display for FetchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FetchError::Source(err) => write!(f, "failed to fetch accounts: {}", err),
+            FetchError::Unsupported => write!(f, "source cannot list program accounts"),
+            FetchError::AccountNotFound(address) => write!(f, "account {} not found", address),
+            FetchError::InvalidOwner(address) => {
+                write!(f, "account {} is not owned by governance-rewards", address)
+            }
+            FetchError::InvalidData(address, err) => {
+                write!(f, "account {} failed to deserialize: {}", address, err)
+            }
+        }
+    }
+}
+
+impl std::error::Error for FetchError {}
+
+/// Matches accounts whose data holds `bytes` at `offset`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MemcmpFilter {
+    pub offset: usize,
+    pub bytes: Vec<u8>,
+}
+
+impl MemcmpFilter {
+    pub fn new(offset: usize, bytes: &[u8]) -> Self {
+        Self {
+            offset,
+            bytes: bytes.to_vec(),
+        }
+    }
+
+    /// Matches accounts of an anchor account type.
+    pub fn discriminator<T: Discriminator>() -> Self {
+        Self::new(0, &T::discriminator())
+    }
+
+    pub fn matches(&self, data: &[u8]) -> bool {
+        data.get(self.offset..self.offset + self.bytes.len()) == Some(&self.bytes[..])
+    }
+}
+
+#[async_trait]
+pub trait AccountSource {
+    async fn get_account(&mut self, address: Pubkey) -> Result<Option<Account>, FetchError>;
+
+    /**
+     * Lists the accounts owned by `program` which match every filter. Sources which
+     * cannot list accounts return `FetchError::Unsupported`.
+     */
+    async fn get_program_accounts(
+        &mut self,
+        program: Pubkey,
+        filters: &[MemcmpFilter],
+    ) -> Result<Vec<(Pubkey, Account)>, FetchError>;
+}
+
+#[cfg(feature = "banks")]
+#[async_trait]
+impl AccountSource for solana_banks_client::BanksClient {
+    async fn get_account(&mut self, address: Pubkey) -> Result<Option<Account>, FetchError> {
+        solana_banks_client::BanksClient::get_account(self, address)
+            .await
+            .map_err(|err| FetchError::Source(Box::new(err)))
+    }
+
+    async fn get_program_accounts(
+        &mut self,
+        _program: Pubkey,
+        _filters: &[MemcmpFilter],
+    ) -> Result<Vec<(Pubkey, Account)>, FetchError> {
+        Err(FetchError::Unsupported)
+    }
+}
+
+#[cfg(feature = "rpc")]
+#[async_trait]
+impl AccountSource for solana_client::nonblocking::rpc_client::RpcClient {
+    async fn get_account(&mut self, address: Pubkey) -> Result<Option<Account>, FetchError> {
+        self.get_account_with_commitment(&address, self.commitment())
+            .await
+            .map(|response| response.value)
+            .map_err(|err| FetchError::Source(Box::new(err)))
+    }
+
+    async fn get_program_accounts(
+        &mut self,
+        program: Pubkey,
+        filters: &[MemcmpFilter],
+    ) -> Result<Vec<(Pubkey, Account)>, FetchError> {
+        use solana_account_decoder::UiAccountEncoding;
+        use solana_client::{
+            rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig},
+            rpc_filter::{Memcmp, MemcmpEncodedBytes, RpcFilterType},
+        };
+
+        let filters = filters
+            .iter()
+            .map(|filter| {
+                RpcFilterType::Memcmp(Memcmp {
+                    offset: filter.offset,
+                    bytes: MemcmpEncodedBytes::Bytes(filter.bytes.clone()),
+                    encoding: None,
+                })
+            })
+            .collect();
+        self.get_program_accounts_with_config(
+            &program,
+            RpcProgramAccountsConfig {
+                filters: Some(filters),
+                account_config: RpcAccountInfoConfig {
+                    encoding: Some(UiAccountEncoding::Base64),
+                    commitment: Some(self.commitment()),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        )
+        .await
+        .map_err(|err| FetchError::Source(Box::new(err)))
+    }
+}
+
+fn deserialize<T: AccountDeserialize>(address: Pubkey, account: &Account) -> Result<T, FetchError> {
+    if account.owner != governance_rewards::id() {
+        return Err(FetchError::InvalidOwner(address));
+    }
+    T::try_deserialize(&mut &account.data[..]).map_err(|err| FetchError::InvalidData(address, err))
+}
+
+async fn get_optional<T: AccountDeserialize>(
+    source: &mut (impl AccountSource + Send),
+    address: Pubkey,
+) -> Result<Option<T>, FetchError> {
+    source
+        .get_account(address)
+        .await?
+        .map(|account| deserialize(address, &account))
+        .transpose()
+}
+
+async fn list<T: AccountDeserialize + Discriminator>(
+    source: &mut (impl AccountSource + Send),
+    mut filters: Vec<MemcmpFilter>,
+) -> Result<Vec<(Pubkey, T)>, FetchError> {
+    filters.insert(0, MemcmpFilter::discriminator::<T>());
+    source
+        .get_program_accounts(governance_rewards::id(), &filters)
+        .await?
+        .into_iter()
+        .map(|(address, account)| Ok((address, deserialize(address, &account)?)))
+        .collect()
+}
+
+pub async fn get_distribution(
+    source: &mut (impl AccountSource + Send),
+    distribution: Pubkey,
+) -> Result<Distribution, FetchError> {
+    get_optional(source, distribution)
+        .await?
+        .ok_or(FetchError::AccountNotFound(distribution))
+}
+
+/// Claim data of `user` in a distribution, or `None` if they have not registered.
+pub async fn get_claim(
+    source: &mut (impl AccountSource + Send),
+    user: Pubkey,
+    distribution: Pubkey,
+) -> Result<Option<ClaimData>, FetchError> {
+    get_optional(source, pda::claim_data(user, distribution)).await
+}
+
+/// Realm-wide preferences of `user`, or `None` if they have never set any.
+pub async fn get_user_preferences(
+    source: &mut (impl AccountSource + Send),
+    user: Pubkey,
+    realm: Pubkey,
+) -> Result<Option<UserPreferences>, FetchError> {
+    get_optional(source, pda::preferences(user, realm)).await
+}
+
+/// Claim data of every registrant of a distribution, with its address.
+pub async fn list_claims_for_distribution(
+    source: &mut (impl AccountSource + Send),
+    distribution: Pubkey,
+) -> Result<Vec<(Pubkey, ClaimData)>, FetchError> {
+    list(
+        source,
+        vec![MemcmpFilter::new(
+            CLAIM_DATA_DISTRIBUTION_OFFSET,
+            distribution.as_ref(),
+        )],
+    )
+    .await
+}
+
+/// Claim data of `user` in every distribution they registered for, with its address.
+pub async fn list_claims_for_user(
+    source: &mut (impl AccountSource + Send),
+    user: Pubkey,
+) -> Result<Vec<(Pubkey, ClaimData)>, FetchError> {
+    list(
+        source,
+        vec![MemcmpFilter::new(
+            CLAIM_DATA_BELONGS_TO_OFFSET,
+            user.as_ref(),
+        )],
+    )
+    .await
+}
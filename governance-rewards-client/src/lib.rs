@@ -1,6 +1,63 @@
-use anchor_lang::{prelude::Pubkey, solana_program::instruction::Instruction};
-use governance_rewards::state::distribution::Distribution;
-use governance_rewards::state::{claim_data::ClaimData, preferences::UserPreferences};
+pub mod fetch;
+pub mod pda;
+pub mod simulate;
+
+use anchor_lang::{
+    prelude::{AccountMeta, Pubkey},
+    solana_program::instruction::Instruction,
+    InstructionData, ToAccountMetas,
+};
+use governance_rewards::state::{
+    crank_fee::CrankFee, preferences::ResolutionPreference, release_schedule::ReleaseLimit,
+};
+
+fn build(accounts: impl ToAccountMetas, data: impl InstructionData) -> Instruction {
+    Instruction {
+        program_id: governance_rewards::id(),
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+/**
+ * Builds `create_distribution`. `funding` are the token accounts, owned by the
+ * distribution's payout authority, which become its options.
+ */
+#[allow(clippy::too_many_arguments)]
+pub fn create_distribution(
+    distribution: Pubkey,
+    realm: Pubkey,
+    voter_weight_program: Pubkey,
+    admin: Pubkey,
+    payer: Pubkey,
+    funding: &[Pubkey],
+    registration_cutoff: u64,
+    registrar: Option<Pubkey>,
+    crank_fee: Option<CrankFee>,
+) -> Instruction {
+    let mut ix = build(
+        governance_rewards::accounts::CreateDistribution {
+            distribution,
+            payout_authority: pda::payout_authority(distribution),
+            realm,
+            voter_weight_program,
+            payer,
+            admin,
+            system_program: solana_sdk::system_program::id(),
+        },
+        governance_rewards::instruction::CreateDistribution {
+            registration_cutoff,
+            registrar,
+            crank_fee,
+        },
+    );
+    ix.accounts.extend(
+        funding
+            .iter()
+            .map(|funding| AccountMeta::new_readonly(*funding, false)),
+    );
+    ix
+}
 
 pub fn register(
     user: Pubkey,
@@ -9,25 +66,54 @@ pub fn register(
     voter_weight_record: Pubkey,
     payer: Pubkey,
 ) -> Instruction {
-    let data = anchor_lang::InstructionData::data(&governance_rewards::instruction::Register {});
-    let accounts = anchor_lang::ToAccountMetas::to_account_metas(
-        &governance_rewards::accounts::RegisterForRewards {
+    build(
+        governance_rewards::accounts::RegisterForRewards {
             voter_weight_record,
             distribution,
-            preferences: UserPreferences::get_address(user, realm),
-            claim_data: ClaimData::get_address(user, distribution),
+            preferences: pda::preferences(user, realm),
+            distribution_preferences: pda::distribution_preferences(user, distribution),
+            claim_data: pda::claim_data(user, distribution),
             registrant: user,
             payer,
             system_program: solana_sdk::system_program::id(),
         },
-        None,
-    );
+        governance_rewards::instruction::Register {},
+    )
+}
 
-    Instruction {
-        program_id: governance_rewards::id(),
-        accounts,
-        data,
-    }
+/**
+ * Builds `deregister`, signed by either the user or the distribution admin as
+ * `authority`. `rent_payer` is refunded the claim data's rent.
+ */
+pub fn deregister(
+    user: Pubkey,
+    distribution: Pubkey,
+    rent_payer: Pubkey,
+    authority: Pubkey,
+) -> Instruction {
+    build(
+        governance_rewards::accounts::Deregister {
+            distribution,
+            claim_data: pda::claim_data(user, distribution),
+            registrant: user,
+            payer: rent_payer,
+            authority,
+        },
+        governance_rewards::instruction::Deregister {},
+    )
+}
+
+pub fn switch_option(user: Pubkey, distribution: Pubkey, realm: Pubkey) -> Instruction {
+    build(
+        governance_rewards::accounts::SwitchOption {
+            distribution,
+            claim_data: pda::claim_data(user, distribution),
+            preferences: pda::preferences(user, realm),
+            distribution_preferences: pda::distribution_preferences(user, distribution),
+            registrant: user,
+        },
+        governance_rewards::instruction::SwitchOption {},
+    )
 }
 
 pub fn claim(
@@ -36,50 +122,748 @@ pub fn claim(
     realm: Pubkey,
     rewards_account: Pubkey,
     to_account: Pubkey,
+    payout_mint: Pubkey,
     payer: Pubkey,
 ) -> Instruction {
-    let data = anchor_lang::InstructionData::data(&governance_rewards::instruction::Claim {});
-    let accounts = anchor_lang::ToAccountMetas::to_account_metas(
-        &governance_rewards::accounts::Claim {
+    build(
+        governance_rewards::accounts::Claim {
             caller: payer,
             claimant: user,
             distribution,
             rewards_account,
             to_account,
-            payout_authority: Distribution::get_payout_authority(distribution),
-            claim_data: ClaimData::get_address(user, distribution),
-            preferences: UserPreferences::get_address(user, realm),
+            escrow_state: pda::escrow_state(to_account),
+            payout_mint,
+            escrow_owner: pda::escrow_owner(realm),
+            payout_authority: pda::payout_authority(distribution),
+            claim_data: pda::claim_data(user, distribution),
+            preferences: pda::preferences(user, realm),
+            distribution_preferences: pda::distribution_preferences(user, distribution),
             token_program: anchor_spl::token::ID,
+            associated_token_program: anchor_spl::associated_token::ID,
             system_program: solana_sdk::system_program::id(),
+            rent: solana_sdk::sysvar::rent::id(),
+        },
+        governance_rewards::instruction::Claim {},
+    )
+}
+
+/**
+ * Builds `claim_to_governance` for a claimant whose resolution preference is
+ * `GovernanceDeposit { governance_program }`.
+ */
+#[allow(clippy::too_many_arguments)]
+pub fn claim_to_governance(
+    user: Pubkey,
+    distribution: Pubkey,
+    realm: Pubkey,
+    rewards_account: Pubkey,
+    payout_mint: Pubkey,
+    governance_program: Pubkey,
+    payer: Pubkey,
+) -> Instruction {
+    build(
+        governance_rewards::accounts::ClaimToGovernance {
+            distribution,
+            claim_data: pda::claim_data(user, distribution),
+            rewards_account,
+            payout_authority: pda::payout_authority(distribution),
+            preferences: pda::preferences(user, realm),
+            distribution_preferences: pda::distribution_preferences(user, distribution),
+            claimant: user,
+            realm,
+            governance_program,
+            governing_token_holding: pda::governing_token_holding(
+                governance_program,
+                realm,
+                payout_mint,
+            ),
+            token_owner_record: pda::token_owner_record(
+                governance_program,
+                realm,
+                payout_mint,
+                user,
+            ),
+            caller: payer,
+            token_program: anchor_spl::token::ID,
+            system_program: solana_sdk::system_program::id(),
+            rent: solana_sdk::sysvar::rent::id(),
+        },
+        governance_rewards::instruction::ClaimToGovernance {},
+    )
+}
+
+/**
+ * Remaining accounts to append to `claim`, `crank_claims` or `reclaim_user_data` when
+ * the distribution pays its crank fee in tokens.
+ */
+pub fn crank_fee_accounts(fee_option_wallet: Pubkey, to: Pubkey) -> Vec<AccountMeta> {
+    vec![
+        AccountMeta::new(fee_option_wallet, false),
+        AccountMeta::new(to, false),
+    ]
+}
+
+/**
+ * Accounts needed to claim on a single distribution with `claim_many`.
+ */
+#[derive(Clone, Copy, Debug)]
+pub struct ClaimTarget {
+    pub distribution: Pubkey,
+    pub rewards_account: Pubkey,
+    pub to_account: Pubkey,
+}
+
+pub fn claim_many(
+    user: Pubkey,
+    realm: Pubkey,
+    targets: &[ClaimTarget],
+    payer: Pubkey,
+) -> Instruction {
+    let mut ix = build(
+        governance_rewards::accounts::ClaimMany {
+            preferences: pda::preferences(user, realm),
+            realm,
+            claimant: user,
+            caller: payer,
+            token_program: anchor_spl::token::ID,
         },
-        None,
+        governance_rewards::instruction::ClaimMany {},
     );
 
-    Instruction {
-        program_id: governance_rewards::id(),
-        accounts,
-        data,
+    for target in targets {
+        ix.accounts.extend([
+            AccountMeta::new(target.distribution, false),
+            AccountMeta::new(pda::claim_data(user, target.distribution), false),
+            AccountMeta::new_readonly(
+                pda::distribution_preferences(user, target.distribution),
+                false,
+            ),
+            AccountMeta::new(target.rewards_account, false),
+            AccountMeta::new_readonly(pda::payout_authority(target.distribution), false),
+            AccountMeta::new(target.to_account, false),
+        ]);
     }
+
+    ix
+}
+
+/**
+ * Accounts needed to pay out a single claimant with `crank_claims`.
+ */
+#[derive(Clone, Copy, Debug)]
+pub struct CrankTarget {
+    pub claimant: Pubkey,
+    pub rewards_account: Pubkey,
+    pub to_account: Pubkey,
+}
+
+pub fn crank_claims(
+    distribution: Pubkey,
+    realm: Pubkey,
+    targets: &[CrankTarget],
+    fee_accounts: &[AccountMeta],
+    payer: Pubkey,
+) -> Instruction {
+    let mut ix = build(
+        governance_rewards::accounts::CrankClaims {
+            distribution,
+            payout_authority: pda::payout_authority(distribution),
+            caller: payer,
+            token_program: anchor_spl::token::ID,
+        },
+        governance_rewards::instruction::CrankClaims {},
+    );
+    ix.accounts.extend_from_slice(fee_accounts);
+
+    for target in targets {
+        ix.accounts.extend([
+            AccountMeta::new(pda::claim_data(target.claimant, distribution), false),
+            AccountMeta::new_readonly(pda::preferences(target.claimant, realm), false),
+            AccountMeta::new_readonly(
+                pda::distribution_preferences(target.claimant, distribution),
+                false,
+            ),
+            AccountMeta::new(target.rewards_account, false),
+            AccountMeta::new(target.to_account, false),
+        ]);
+    }
+
+    ix
+}
+
+pub fn reclaim_user_data(
+    user: Pubkey,
+    distribution: Pubkey,
+    rent_payer: Pubkey,
+    caller: Pubkey,
+) -> Instruction {
+    build(
+        governance_rewards::accounts::ReclaimUserData {
+            distribution,
+            claim_data: pda::claim_data(user, distribution),
+            payer: rent_payer,
+            payout_authority: pda::payout_authority(distribution),
+            caller,
+            token_program: anchor_spl::token::ID,
+        },
+        governance_rewards::instruction::ReclaimUserData {},
+    )
 }
 
 pub fn reclaim_funds(distribution: Pubkey, admin: Pubkey, from: Pubkey, to: Pubkey) -> Instruction {
-    let data =
-        anchor_lang::InstructionData::data(&governance_rewards::instruction::ReclaimFunds {});
-    let accounts = anchor_lang::ToAccountMetas::to_account_metas(
-        &governance_rewards::accounts::ReclaimFunds {
+    build(
+        governance_rewards::accounts::ReclaimFunds {
             admin,
             from,
             to,
             distribution,
-            payout_authority: Distribution::get_payout_authority(distribution),
+            payout_authority: pda::payout_authority(distribution),
             token_program: anchor_spl::token::ID,
         },
-        None,
+        governance_rewards::instruction::ReclaimFunds {},
+    )
+}
+
+pub fn sweep_funds(distribution: Pubkey, admin: Pubkey, from: Pubkey, to: Pubkey) -> Instruction {
+    build(
+        governance_rewards::accounts::SweepFunds {
+            admin,
+            from,
+            to,
+            distribution,
+            payout_authority: pda::payout_authority(distribution),
+            token_program: anchor_spl::token::ID,
+        },
+        governance_rewards::instruction::SweepFunds {},
+    )
+}
+
+/**
+ * Builds `migrate_distribution`. `wallets` are the wallets of the distribution's options,
+ * in option order.
+ */
+pub fn migrate_distribution(
+    distribution: Pubkey,
+    payer: Pubkey,
+    wallets: &[Pubkey],
+) -> Instruction {
+    let mut ix = build(
+        governance_rewards::accounts::MigrateDistribution {
+            distribution,
+            payer,
+            system_program: solana_sdk::system_program::id(),
+        },
+        governance_rewards::instruction::MigrateDistribution {},
+    );
+    ix.accounts.extend(
+        wallets
+            .iter()
+            .map(|wallet| AccountMeta::new_readonly(*wallet, false)),
     );
+    ix
+}
 
-    Instruction {
-        program_id: governance_rewards::id(),
-        accounts,
-        data,
-    }
+pub fn migrate_claim_data(user: Pubkey, distribution: Pubkey, payer: Pubkey) -> Instruction {
+    build(
+        governance_rewards::accounts::MigrateClaimData {
+            claim_data: pda::claim_data(user, distribution),
+            payer,
+            system_program: solana_sdk::system_program::id(),
+        },
+        governance_rewards::instruction::MigrateClaimData {},
+    )
+}
+
+pub fn migrate_user_preferences(user: Pubkey, realm: Pubkey, payer: Pubkey) -> Instruction {
+    build(
+        governance_rewards::accounts::MigrateUserPreferences {
+            preferences: pda::preferences(user, realm),
+            realm,
+            user,
+            payer,
+            system_program: solana_sdk::system_program::id(),
+        },
+        governance_rewards::instruction::MigrateUserPreferences {},
+    )
+}
+
+/**
+ * Builds `set_preferred_mint`, signed and paid for by `user`.
+ */
+pub fn set_preferred_mint(
+    user: Pubkey,
+    realm: Pubkey,
+    new_preference: Option<Pubkey>,
+) -> Instruction {
+    build(
+        governance_rewards::accounts::SetPreferredMint {
+            preferences: pda::preferences(user, realm),
+            realm,
+            user,
+            system_program: solana_sdk::system_program::id(),
+        },
+        governance_rewards::instruction::SetPreferredMint { new_preference },
+    )
+}
+
+/**
+ * Builds `set_preferred_mints`, signed and paid for by `user`.
+ */
+pub fn set_preferred_mints(
+    user: Pubkey,
+    realm: Pubkey,
+    new_preferences: Vec<Pubkey>,
+) -> Instruction {
+    build(
+        governance_rewards::accounts::SetPreferredMint {
+            preferences: pda::preferences(user, realm),
+            realm,
+            user,
+            system_program: solana_sdk::system_program::id(),
+        },
+        governance_rewards::instruction::SetPreferredMints { new_preferences },
+    )
+}
+
+/**
+ * Builds `set_resolution_preference`, signed and paid for by `user`.
+ */
+pub fn set_resolution_preference(
+    user: Pubkey,
+    realm: Pubkey,
+    new_preference: ResolutionPreference,
+) -> Instruction {
+    build(
+        governance_rewards::accounts::SetResolutionPreference {
+            preferences: pda::preferences(user, realm),
+            realm,
+            user,
+            system_program: solana_sdk::system_program::id(),
+        },
+        governance_rewards::instruction::SetResolutionPreference { new_preference },
+    )
+}
+
+/**
+ * Builds `set_distribution_preferences`, signed and paid for by `user`.
+ */
+pub fn set_distribution_preferences(
+    user: Pubkey,
+    distribution: Pubkey,
+    preferred_mint: Option<Pubkey>,
+    resolution_preference: Option<ResolutionPreference>,
+) -> Instruction {
+    build(
+        governance_rewards::accounts::SetDistributionPreferences {
+            distribution_preferences: pda::distribution_preferences(user, distribution),
+            distribution,
+            user,
+            system_program: solana_sdk::system_program::id(),
+        },
+        governance_rewards::instruction::SetDistributionPreferences {
+            preferred_mint,
+            resolution_preference,
+        },
+    )
+}
+
+/**
+ * Builds `create_escrow` for the escrow at `pda::escrow(realm, escrow_admin, user, mint)`.
+ */
+pub fn create_escrow(
+    user: Pubkey,
+    realm: Pubkey,
+    mint: Pubkey,
+    escrow_admin: Pubkey,
+    payer: Pubkey,
+) -> Instruction {
+    let escrow = pda::escrow(realm, escrow_admin, user, mint);
+    build(
+        governance_rewards::accounts::CreateEscrow {
+            escrow,
+            escrow_state: pda::escrow_state(escrow),
+            escrow_owner: pda::escrow_owner(realm),
+            realm,
+            mint,
+            user,
+            escrow_release_admin: escrow_admin,
+            payer,
+            token_program: anchor_spl::token::ID,
+            system_program: solana_sdk::system_program::id(),
+            rent: solana_sdk::sysvar::rent::id(),
+        },
+        governance_rewards::instruction::CreateEscrow {},
+    )
+}
+
+/**
+ * Builds `create_timelocked_escrow` for the escrow at
+ * `pda::timelocked_escrow(realm, escrow_admin, user, mint, unlock_ts)`.
+ */
+pub fn create_timelocked_escrow(
+    user: Pubkey,
+    realm: Pubkey,
+    mint: Pubkey,
+    escrow_admin: Pubkey,
+    payer: Pubkey,
+    unlock_ts: u64,
+) -> Instruction {
+    let escrow = pda::timelocked_escrow(realm, escrow_admin, user, mint, unlock_ts);
+    build(
+        governance_rewards::accounts::CreateTimelockedEscrow {
+            escrow,
+            escrow_state: pda::escrow_state(escrow),
+            escrow_owner: pda::escrow_owner(realm),
+            realm,
+            mint,
+            user,
+            escrow_release_admin: escrow_admin,
+            payer,
+            token_program: anchor_spl::token::ID,
+            system_program: solana_sdk::system_program::id(),
+            rent: solana_sdk::sysvar::rent::id(),
+        },
+        governance_rewards::instruction::CreateTimelockedEscrow { unlock_ts },
+    )
+}
+
+/**
+ * Builds `release_timelocked_escrow`, signed by the user or the escrow admin as
+ * `authority`. `to_account` must be the user's associated token account.
+ */
+#[allow(clippy::too_many_arguments)]
+pub fn release_timelocked_escrow(
+    escrow: Pubkey,
+    user: Pubkey,
+    realm: Pubkey,
+    mint: Pubkey,
+    escrow_admin: Pubkey,
+    to_account: Pubkey,
+    authority: Pubkey,
+    unlock_ts: u64,
+    amount: u64,
+) -> Instruction {
+    build(
+        governance_rewards::accounts::ReleaseTimelockedEscrow {
+            escrow,
+            to_account,
+            escrow_owner: pda::escrow_owner(realm),
+            realm,
+            mint,
+            user,
+            escrow_release_admin: escrow_admin,
+            authority,
+            token_program: anchor_spl::token::ID,
+            escrow_state: pda::escrow_state(escrow),
+        },
+        governance_rewards::instruction::ReleaseTimelockedEscrow { unlock_ts, amount },
+    )
+}
+
+/**
+ * Builds `close_escrow`, signed by the user or the escrow admin as `authority`.
+ * `unlock_ts` must be set for timelocked escrows.
+ */
+#[allow(clippy::too_many_arguments)]
+pub fn close_escrow(
+    escrow: Pubkey,
+    user: Pubkey,
+    realm: Pubkey,
+    escrow_admin: Pubkey,
+    rent_recipient: Pubkey,
+    authority: Pubkey,
+    unlock_ts: Option<u64>,
+) -> Instruction {
+    build(
+        governance_rewards::accounts::CloseEscrow {
+            escrow,
+            escrow_state: pda::escrow_state(escrow),
+            escrow_owner: pda::escrow_owner(realm),
+            realm,
+            user,
+            escrow_release_admin: escrow_admin,
+            rent_recipient,
+            authority,
+            token_program: anchor_spl::token::ID,
+        },
+        governance_rewards::instruction::CloseEscrow { unlock_ts },
+    )
+}
+
+/**
+ * Builds `transfer_from_escrow`, signed by the escrow admin.
+ */
+#[allow(clippy::too_many_arguments)]
+pub fn transfer_from_escrow(
+    escrow: Pubkey,
+    user: Pubkey,
+    realm: Pubkey,
+    mint: Pubkey,
+    escrow_admin: Pubkey,
+    to_account: Pubkey,
+    amount: u64,
+) -> Instruction {
+    build(
+        governance_rewards::accounts::TransferFromEscrow {
+            escrow,
+            escrow_owner: pda::escrow_owner(realm),
+            realm,
+            mint,
+            to_account,
+            user,
+            escrow_release_admin: escrow_admin,
+            token_program: anchor_spl::token::ID,
+            release_schedule: pda::release_schedule(escrow),
+            escrow_state: pda::escrow_state(escrow),
+        },
+        governance_rewards::instruction::TransferFromEscrow { amount },
+    )
+}
+
+/**
+ * Builds `transfer_from_escrow_with_consent`, signed by both the user and the escrow
+ * admin.
+ */
+#[allow(clippy::too_many_arguments)]
+pub fn transfer_from_escrow_with_consent(
+    escrow: Pubkey,
+    user: Pubkey,
+    realm: Pubkey,
+    mint: Pubkey,
+    escrow_admin: Pubkey,
+    to_account: Pubkey,
+    amount: u64,
+) -> Instruction {
+    build(
+        governance_rewards::accounts::TransferFromEscrowWithConsent {
+            escrow,
+            to_account,
+            escrow_owner: pda::escrow_owner(realm),
+            realm,
+            mint,
+            user,
+            escrow_release_admin: escrow_admin,
+            token_program: anchor_spl::token::ID,
+            escrow_state: pda::escrow_state(escrow),
+        },
+        governance_rewards::instruction::TransferFromEscrowWithConsent { amount },
+    )
+}
+
+/**
+ * Builds `deposit_from_escrow`, signed by the escrow admin, depositing `mint` tokens
+ * into the user's token owner record in the realm.
+ */
+#[allow(clippy::too_many_arguments)]
+pub fn deposit_from_escrow(
+    escrow: Pubkey,
+    user: Pubkey,
+    realm: Pubkey,
+    mint: Pubkey,
+    escrow_admin: Pubkey,
+    governance_program: Pubkey,
+    payer: Pubkey,
+    amount: u64,
+) -> Instruction {
+    build(
+        governance_rewards::accounts::DepositFromEscrow {
+            escrow,
+            escrow_owner: pda::escrow_owner(realm),
+            realm,
+            user,
+            escrow_release_admin: escrow_admin,
+            governance_program,
+            governing_token_holding: pda::governing_token_holding(governance_program, realm, mint),
+            token_owner_record: pda::token_owner_record(governance_program, realm, mint, user),
+            payer,
+            token_program: anchor_spl::token::ID,
+            system_program: solana_sdk::system_program::id(),
+            rent: solana_sdk::sysvar::rent::id(),
+            release_schedule: pda::release_schedule(escrow),
+            escrow_state: pda::escrow_state(escrow),
+        },
+        governance_rewards::instruction::DepositFromEscrow { amount },
+    )
+}
+
+/**
+ * Builds `set_early_release_policy`, signed by the escrow admin.
+ */
+#[allow(clippy::too_many_arguments)]
+pub fn set_early_release_policy(
+    escrow: Pubkey,
+    user: Pubkey,
+    realm: Pubkey,
+    escrow_admin: Pubkey,
+    penalty_account: Pubkey,
+    payer: Pubkey,
+    unlock_ts: u64,
+    penalty_basis_points: u16,
+) -> Instruction {
+    build(
+        governance_rewards::accounts::SetEarlyReleasePolicy {
+            escrow,
+            policy: pda::early_release_policy(escrow),
+            penalty_account,
+            realm,
+            user,
+            escrow_release_admin: escrow_admin,
+            payer,
+            system_program: solana_sdk::system_program::id(),
+            escrow_state: pda::escrow_state(escrow),
+        },
+        governance_rewards::instruction::SetEarlyReleasePolicy {
+            unlock_ts,
+            penalty_basis_points,
+        },
+    )
+}
+
+/**
+ * Builds `release_timelocked_escrow_early`, signed by the user.
+ */
+#[allow(clippy::too_many_arguments)]
+pub fn release_timelocked_escrow_early(
+    escrow: Pubkey,
+    user: Pubkey,
+    realm: Pubkey,
+    escrow_admin: Pubkey,
+    to_account: Pubkey,
+    penalty_account: Pubkey,
+    unlock_ts: u64,
+    amount: u64,
+) -> Instruction {
+    build(
+        governance_rewards::accounts::ReleaseTimelockedEscrowEarly {
+            escrow,
+            policy: pda::early_release_policy(escrow),
+            penalty_account,
+            to_account,
+            escrow_owner: pda::escrow_owner(realm),
+            realm,
+            user,
+            escrow_release_admin: escrow_admin,
+            token_program: anchor_spl::token::ID,
+            escrow_state: pda::escrow_state(escrow),
+        },
+        governance_rewards::instruction::ReleaseTimelockedEscrowEarly { unlock_ts, amount },
+    )
+}
+
+/**
+ * Builds `set_escrow_release_schedule`, signed by the escrow admin.
+ */
+pub fn set_escrow_release_schedule(
+    escrow: Pubkey,
+    user: Pubkey,
+    realm: Pubkey,
+    escrow_admin: Pubkey,
+    payer: Pubkey,
+    limit: ReleaseLimit,
+) -> Instruction {
+    build(
+        governance_rewards::accounts::SetEscrowReleaseSchedule {
+            escrow,
+            release_schedule: pda::release_schedule(escrow),
+            realm,
+            user,
+            escrow_release_admin: escrow_admin,
+            payer,
+            system_program: solana_sdk::system_program::id(),
+        },
+        governance_rewards::instruction::SetEscrowReleaseSchedule { limit },
+    )
+}
+
+/**
+ * Builds `create_escrow_admin_config`. `config` is a new keypair account which must
+ * also sign.
+ */
+pub fn create_escrow_admin_config(
+    config: Pubkey,
+    payer: Pubkey,
+    signers: Vec<Pubkey>,
+    threshold: u8,
+) -> Instruction {
+    build(
+        governance_rewards::accounts::CreateEscrowAdminConfig {
+            config,
+            payer,
+            system_program: solana_sdk::system_program::id(),
+        },
+        governance_rewards::instruction::CreateEscrowAdminConfig { signers, threshold },
+    )
+}
+
+/**
+ * Builds `propose_escrow_release`. `proposal_index` must be the config's current
+ * `proposal_count`, which numbers the new proposal.
+ */
+#[allow(clippy::too_many_arguments)]
+pub fn propose_escrow_release(
+    config: Pubkey,
+    proposal_index: u64,
+    escrow: Pubkey,
+    user: Pubkey,
+    realm: Pubkey,
+    to_account: Pubkey,
+    proposer: Pubkey,
+    payer: Pubkey,
+    amount: u64,
+) -> Instruction {
+    build(
+        governance_rewards::accounts::ProposeEscrowRelease {
+            config,
+            proposal: pda::escrow_release_proposal(config, proposal_index),
+            escrow,
+            to_account,
+            realm,
+            user,
+            proposer,
+            payer,
+            system_program: solana_sdk::system_program::id(),
+        },
+        governance_rewards::instruction::ProposeEscrowRelease { amount },
+    )
+}
+
+pub fn approve_escrow_release(config: Pubkey, proposal: Pubkey, approver: Pubkey) -> Instruction {
+    build(
+        governance_rewards::accounts::ApproveEscrowRelease {
+            config,
+            proposal,
+            approver,
+        },
+        governance_rewards::instruction::ApproveEscrowRelease {},
+    )
+}
+
+/**
+ * Builds `execute_escrow_release`. `rent_payer` must be the account which paid for the
+ * proposal, and is refunded its rent.
+ */
+pub fn execute_escrow_release(
+    config: Pubkey,
+    proposal: Pubkey,
+    escrow: Pubkey,
+    user: Pubkey,
+    realm: Pubkey,
+    to_account: Pubkey,
+    rent_payer: Pubkey,
+) -> Instruction {
+    build(
+        governance_rewards::accounts::ExecuteEscrowRelease {
+            config,
+            proposal,
+            escrow,
+            to_account,
+            escrow_owner: pda::escrow_owner(realm),
+            realm,
+            user,
+            payer: rent_payer,
+            token_program: anchor_spl::token::ID,
+        },
+        governance_rewards::instruction::ExecuteEscrowRelease {},
+    )
 }
@@ -0,0 +1,94 @@
+//! Addresses of every program derived account used by governance-rewards.
+//!
+//! These wrap the derivations in the program's state modules, so the seeds are only
+//! defined once. The spl-governance accounts the program deposits into are included too,
+//! derived by spl-governance itself.
+
+use anchor_lang::prelude::Pubkey;
+use governance_rewards::{
+    instructions::get_escrow_owner,
+    state::{
+        claim_data::ClaimData,
+        distribution::Distribution,
+        distribution_preferences::DistributionPreferences,
+        early_release::EarlyReleasePolicy,
+        escrow::EscrowState,
+        escrow_admin::EscrowReleaseProposal,
+        preferences::{
+            find_escrow_address, find_timelocked_escrow_address, ResolutionPreference,
+            UserPreferences,
+        },
+        release_schedule::EscrowReleaseSchedule,
+    },
+};
+use spl_governance::state::realm::get_governing_token_holding_address;
+
+/// Owner of the token accounts a distribution pays out of.
+pub fn payout_authority(distribution: Pubkey) -> Pubkey {
+    Distribution::get_payout_authority(distribution)
+}
+
+pub fn claim_data(user: Pubkey, distribution: Pubkey) -> Pubkey {
+    ClaimData::get_address(user, distribution)
+}
+
+/// Realm-wide preferences of a user.
+pub fn preferences(user: Pubkey, realm: Pubkey) -> Pubkey {
+    UserPreferences::get_address(user, realm)
+}
+
+/// Preferences of a user which only apply to one distribution.
+pub fn distribution_preferences(user: Pubkey, distribution: Pubkey) -> Pubkey {
+    DistributionPreferences::get_address(user, distribution)
+}
+
+/// Owner of every escrow token account in a realm.
+pub fn escrow_owner(realm: Pubkey) -> Pubkey {
+    get_escrow_owner(realm)
+}
+
+pub fn escrow(realm: Pubkey, escrow_admin: Pubkey, user: Pubkey, mint: Pubkey) -> Pubkey {
+    find_escrow_address(realm, escrow_admin, user, mint).0
+}
+
+pub fn timelocked_escrow(
+    realm: Pubkey,
+    escrow_admin: Pubkey,
+    user: Pubkey,
+    mint: Pubkey,
+    unlock_ts: u64,
+) -> Pubkey {
+    find_timelocked_escrow_address(realm, escrow_admin, user, mint, unlock_ts).0
+}
+
+pub fn escrow_state(escrow: Pubkey) -> Pubkey {
+    EscrowState::get_address(escrow)
+}
+
+pub fn early_release_policy(escrow: Pubkey) -> Pubkey {
+    EarlyReleasePolicy::get_address(escrow)
+}
+
+pub fn release_schedule(escrow: Pubkey) -> Pubkey {
+    EscrowReleaseSchedule::get_address(escrow)
+}
+
+/// Release proposal number `index` of an escrow admin config.
+pub fn escrow_release_proposal(config: Pubkey, index: u64) -> Pubkey {
+    EscrowReleaseProposal::get_address(config, index)
+}
+
+/// Account spl-governance holds a realm's deposited governing tokens in.
+pub fn governing_token_holding(governance_program: Pubkey, realm: Pubkey, mint: Pubkey) -> Pubkey {
+    get_governing_token_holding_address(&governance_program, &realm, &mint)
+}
+
+/// spl-governance record of the governing tokens `user` has deposited in a realm.
+pub fn token_owner_record(
+    governance_program: Pubkey,
+    realm: Pubkey,
+    mint: Pubkey,
+    user: Pubkey,
+) -> Pubkey {
+    ResolutionPreference::GovernanceDeposit { governance_program }.payout_address(user, mint, realm)
+}
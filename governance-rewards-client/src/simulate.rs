@@ -0,0 +1,116 @@
+//! Projects the rewards a registration would earn, without sending a transaction.
+//!
+//! Projections use the same math as the program, on a snapshot of a distribution. They
+//! assume nobody else registers, switches or deregisters before registration closes.
+
+use anchor_lang::prelude::*;
+use governance_rewards::{
+    error::GovernanceRewardsError,
+    state::{claim_data::ClaimData, distribution::Distribution},
+};
+
+/// A registration to project, as `register` would record it.
+#[derive(Debug, Clone, Default)]
+pub struct Registration {
+    pub weight: u64,
+    /// Preferred mints which apply to the distribution, from most to least preferred.
+    pub preferred_mints: Vec<Pubkey>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProjectedPayout {
+    pub option: u8,
+    pub mint: Pubkey,
+    pub amount: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Projection {
+    /// Option picked by the registration's preferred mints.
+    pub option: u8,
+    /// Payout from every option of the distribution, as if the registration picked it.
+    pub payouts: Vec<ProjectedPayout>,
+}
+
+impl Projection {
+    /// Payout from the option picked by the registration's preferred mints.
+    pub fn payout(&self) -> ProjectedPayout {
+        self.payouts
+            .iter()
+            .copied()
+            .find(|payout| payout.option == self.option)
+            .expect("projection includes its own option")
+    }
+
+    /**
+     * Payout after switching the preferred mint to `mint`, or `None` if the distribution
+     * does not pay in it.
+     */
+    pub fn payout_in(&self, mint: Pubkey) -> Option<ProjectedPayout> {
+        self.payouts
+            .iter()
+            .copied()
+            .find(|payout| payout.mint == mint)
+    }
+}
+
+/**
+ * Projects the payout of `registration` at unix time `now`.
+ *
+ * `claim_data` is the user's existing claim data, if they have already registered, whose
+ * weight is replaced the way `register` replaces it.
+ */
+pub fn simulate_registration(
+    distribution: &Distribution,
+    registration: &Registration,
+    claim_data: Option<&ClaimData>,
+    now: u64,
+) -> Result<Projection> {
+    require!(
+        distribution.can_register_at(now),
+        GovernanceRewardsError::RegistrationOver
+    );
+    require!(
+        registration.weight > 0,
+        GovernanceRewardsError::NoVoteWeight
+    );
+
+    let (option, _) = distribution
+        .distribution_options
+        .pick_by_mint(&registration.preferred_mints)?;
+
+    // The distribution as `register` leaves it before adding the new weight to an option.
+    let mut base = distribution.clone();
+    match claim_data {
+        Some(claim_data) if claim_data.has_registered => {
+            base.remove_weight(claim_data.claim_option, claim_data.weight)?;
+        }
+        _ => {
+            base.registrant_count = base
+                .registrant_count
+                .checked_add(1)
+                .ok_or(GovernanceRewardsError::MathOverflow)?;
+            base.assert_crank_fee_reserve_funded()?;
+        }
+    }
+
+    let payouts = (0..distribution.distribution_options.len() as u8)
+        .filter(|index| distribution.distribution_options.get_option(*index).is_ok())
+        .map(|index| project(&base, index, registration.weight))
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(Projection { option, payouts })
+}
+
+/// Payout of a registration with `weight` added to option `index` of `distribution`.
+fn project(distribution: &Distribution, index: u8, weight: u64) -> Result<ProjectedPayout> {
+    let mut distribution = distribution.clone();
+    distribution.add_weight(index, weight)?;
+
+    let option = distribution.distribution_options.get_option(index)?;
+    Ok(ProjectedPayout {
+        option: index,
+        mint: option.mint,
+        amount: distribution.calculate_rewards(option, weight)?,
+    })
+}
@@ -0,0 +1,91 @@
+#![cfg(feature = "rpc")]
+
+use anchor_lang::{prelude::Pubkey, AccountSerialize};
+use governance_rewards::state::claim_data::ClaimData;
+use governance_rewards_client::{fetch, pda};
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::{
+    account::AccountSharedData, commitment_config::CommitmentConfig, native_token::LAMPORTS_PER_SOL,
+};
+use solana_test_validator::TestValidatorGenesis;
+
+/**
+ * Adds the claim data of `user` in `distribution` to the validator's genesis, the way
+ * `register` writes it.
+ */
+fn add_claim_data(genesis: &mut TestValidatorGenesis, user: Pubkey, distribution: Pubkey) {
+    let (address, bump) = ClaimData::find_address(user, distribution);
+    let claim_data = ClaimData {
+        weight: 10,
+        distribution,
+        claim_option: 0,
+        has_claimed: false,
+        has_registered: true,
+        belongs_to: user,
+        payer: user,
+        version: ClaimData::VERSION,
+        bump,
+        reserved: [0; 31],
+    };
+
+    let mut data = Vec::with_capacity(ClaimData::SPACE);
+    claim_data.try_serialize(&mut data).unwrap();
+    data.resize(ClaimData::SPACE, 0);
+    let mut account =
+        AccountSharedData::new(LAMPORTS_PER_SOL, data.len(), &governance_rewards::id());
+    account.set_data(data);
+    genesis.add_account(address, account);
+}
+
+#[test]
+fn test_list_claims_with_rpc_client() {
+    // Arrange
+    let first = Pubkey::new_unique();
+    let second = Pubkey::new_unique();
+    let user = Pubkey::new_unique();
+    let other_user = Pubkey::new_unique();
+    let mut genesis = TestValidatorGenesis::default();
+    for (distribution, registrant) in [(first, user), (second, user), (first, other_user)] {
+        add_claim_data(&mut genesis, registrant, distribution);
+    }
+    let (test_validator, _payer) = genesis.start();
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+
+    // Act
+    let (first_claims, user_claims) = runtime.block_on(async {
+        let mut rpc_client =
+            RpcClient::new_with_commitment(test_validator.rpc_url(), CommitmentConfig::processed());
+        let first_claims = fetch::list_claims_for_distribution(&mut rpc_client, first)
+            .await
+            .unwrap();
+        let user_claims = fetch::list_claims_for_user(&mut rpc_client, user)
+            .await
+            .unwrap();
+        (first_claims, user_claims)
+    });
+
+    // Assert
+    let mut first_registrants: Vec<Pubkey> = first_claims
+        .iter()
+        .map(|(address, claim)| {
+            assert_eq!(*address, pda::claim_data(claim.belongs_to, first));
+            claim.belongs_to
+        })
+        .collect();
+    first_registrants.sort();
+    let mut expected = vec![user, other_user];
+    expected.sort();
+    assert_eq!(first_registrants, expected);
+
+    let mut user_distributions: Vec<Pubkey> = user_claims
+        .iter()
+        .map(|(address, claim)| {
+            assert_eq!(*address, pda::claim_data(user, claim.distribution));
+            claim.distribution
+        })
+        .collect();
+    user_distributions.sort();
+    let mut expected = vec![first, second];
+    expected.sort();
+    assert_eq!(user_distributions, expected);
+}
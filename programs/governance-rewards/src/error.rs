@@ -34,6 +34,7 @@ pub enum GovernanceRewardsError {
     WrongDistributionForClaim,
     #[msg("Cannot clean up user claims until all claims have been made")]
     CannotCleanUpYet,
+    // Deprecated: no longer returned, kept so later error codes are unchanged.
     #[msg("Cannot clean up the user claim of another user")]
     CannotCleanUpAfterOtherUser,
 
@@ -48,4 +49,102 @@ pub enum GovernanceRewardsError {
 
     #[msg("Provided account is not owned by the payout authority")]
     TokenAccountNotOwned,
+
+    #[msg("Too many preferred mints")]
+    TooManyPreferredMints,
+
+    #[msg("Only the registrant or the distribution admin may deregister")]
+    CannotDeregister,
+    #[msg("Rent must be refunded to the account which paid it")]
+    WrongRentPayer,
+
+    #[msg("Remaining accounts do not form complete claim groups")]
+    InvalidClaimGroups,
+    #[msg("Distribution does not belong to the provided realm")]
+    WrongRealmForDistribution,
+    #[msg("Claim data does not belong to the claimant")]
+    WrongClaimant,
+    #[msg("Provided the wrong preferences account")]
+    WrongPreferencesAccount,
+    #[msg("Provided the wrong payout authority")]
+    WrongPayoutAuthority,
+
+    #[msg("Crank fee must be paid from an existing distribution option")]
+    InvalidCrankFee,
+    #[msg("Crank fee accounts were not provided")]
+    MissingCrankFeeAccounts,
+    #[msg("Crank fee must be paid from the wallet of the fee option")]
+    WrongCrankFeeAccount,
+
+    #[msg("Only the escrow release admin or the user may release a timelocked escrow")]
+    WrongEscrowAuthority,
+    #[msg("Escrow cannot be released by the user before its unlock time")]
+    EscrowLocked,
+
+    #[msg("Escrow does not match the provided realm, admin, user and mint")]
+    WrongEscrowAccount,
+    #[msg("Only the user or the escrow release admin may close an escrow")]
+    CannotCloseEscrow,
+    #[msg("Escrow must be empty to be closed")]
+    EscrowNotEmpty,
+    #[msg("Destination token account mint does not match the escrow")]
+    EscrowMintMismatch,
+
+    #[msg("Rewards deposited into governance must be claimed with claim_to_governance")]
+    GovernanceDepositRequired,
+    #[msg("Governance program does not own the realm or match the user's preferences")]
+    WrongGovernanceProgram,
+    #[msg("Provided the wrong governance account")]
+    WrongGovernanceAccount,
+    #[msg("Token owner record must exist before depositing into it")]
+    MissingTokenOwnerRecord,
+
+    #[msg("Escrow admin threshold must be between 1 and the number of signers")]
+    InvalidEscrowAdminThreshold,
+    #[msg("Escrow admin signers must be unique")]
+    DuplicateEscrowAdminSigner,
+    #[msg("Too many escrow admin signers")]
+    TooManyEscrowAdminSigners,
+    #[msg("Signer is not one of the escrow admin signers")]
+    NotEscrowAdminSigner,
+    #[msg("Signer has already approved this escrow release")]
+    EscrowReleaseAlreadyApproved,
+    #[msg("Escrow release does not have enough approvals")]
+    EscrowReleaseNotApproved,
+
+    #[msg("Release schedule period and percentage must be non-zero, and percentage at most 100%")]
+    InvalidReleaseSchedule,
+    #[msg("Release exceeds the escrow's release schedule for this period")]
+    EscrowReleaseLimitExceeded,
+
+    #[msg("Early release penalty must be at most 100%")]
+    InvalidEarlyReleasePenalty,
+    #[msg("Penalty account does not match the escrow's early release policy")]
+    WrongPenaltyAccount,
+
+    #[msg("Arithmetic overflow in reward calculation")]
+    MathOverflow,
+    #[msg("Distribution option index does not refer to an option")]
+    InvalidOptionIndex,
+    #[msg("Clock sysvar is unavailable")]
+    ClockUnavailable,
+
+    #[msg("Cannot sweep funds until every claim has been paid out")]
+    CannotSweepYet,
+    #[msg("Option wallet holds fewer tokens than its funding, payouts and reclaims account for")]
+    ReconciliationFailed,
+
+    #[msg("Account is already at the current version")]
+    AccountAlreadyMigrated,
+    #[msg("Migrating a distribution requires the wallet of every option, in option order")]
+    MissingOptionWallets,
+
+    #[msg("Payout account is frozen")]
+    PayoutAccountFrozen,
+
+    #[msg("Crank fees reserved for every registrant would exceed the fee option's funding")]
+    CrankFeeReserveExceedsFunding,
+
+    #[msg("Escrow release proposal has expired")]
+    EscrowReleaseProposalExpired,
 }
@@ -0,0 +1,182 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{
+    crank_fee::CrankFee, preferences::ResolutionPreference, release_schedule::ReleaseLimit,
+};
+
+#[event]
+pub struct DistributionCreated {
+    pub distribution: Pubkey,
+    pub realm: Pubkey,
+    pub admin: Pubkey,
+    pub registration_cutoff: u64,
+    pub crank_fee: Option<CrankFee>,
+}
+
+/**
+ * Emitted by `register`. For a new registration `old_weight` is 0 and `old_option`
+ * equals `new_option`.
+ */
+#[event]
+pub struct Registered {
+    pub distribution: Pubkey,
+    pub registrant: Pubkey,
+    pub is_new: bool,
+    pub old_weight: u64,
+    pub new_weight: u64,
+    pub old_option: u8,
+    pub new_option: u8,
+}
+
+#[event]
+pub struct Deregistered {
+    pub distribution: Pubkey,
+    pub registrant: Pubkey,
+    pub weight: u64,
+    pub option: u8,
+}
+
+#[event]
+pub struct OptionSwitched {
+    pub distribution: Pubkey,
+    pub registrant: Pubkey,
+    pub old_option: u8,
+    pub new_option: u8,
+}
+
+#[event]
+pub struct Claimed {
+    pub distribution: Pubkey,
+    pub claimant: Pubkey,
+    pub amount: u64,
+    pub mint: Pubkey,
+    /// Token account or, for governance deposits, token owner record paid into.
+    pub destination: Pubkey,
+    pub resolution: ResolutionPreference,
+}
+
+#[event]
+pub struct CrankFeePaid {
+    pub distribution: Pubkey,
+    pub caller: Pubkey,
+    pub fee: CrankFee,
+}
+
+#[event]
+pub struct FundsReclaimed {
+    pub distribution: Pubkey,
+    pub from: Pubkey,
+    pub to: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct UserDataReclaimed {
+    pub distribution: Pubkey,
+    pub user: Pubkey,
+    pub rent_recipient: Pubkey,
+}
+
+#[event]
+pub struct EscrowCreated {
+    pub escrow: Pubkey,
+    pub realm: Pubkey,
+    pub escrow_admin: Pubkey,
+    pub user: Pubkey,
+    pub mint: Pubkey,
+    pub unlock_ts: Option<u64>,
+    pub payer: Pubkey,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq)]
+pub enum EscrowReleaseKind {
+    Admin,
+    Consent,
+    Timelock,
+    GovernanceDeposit,
+    Proposal,
+    Early,
+}
+
+/**
+ * Emitted whenever funds leave an escrow. `to` is the token owner record for
+ * governance deposits, and `penalty` is only charged by early releases.
+ */
+#[event]
+pub struct EscrowReleased {
+    pub escrow: Pubkey,
+    pub to: Pubkey,
+    pub amount: u64,
+    pub penalty: u64,
+    pub kind: EscrowReleaseKind,
+}
+
+#[event]
+pub struct EscrowClosed {
+    pub escrow: Pubkey,
+    pub rent_recipient: Pubkey,
+}
+
+#[event]
+pub struct EscrowAdminConfigCreated {
+    pub config: Pubkey,
+    pub signers: Vec<Pubkey>,
+    pub threshold: u8,
+}
+
+#[event]
+pub struct EscrowReleaseProposed {
+    pub config: Pubkey,
+    pub proposal: Pubkey,
+    pub escrow: Pubkey,
+    pub to: Pubkey,
+    pub amount: u64,
+    pub proposer: Pubkey,
+}
+
+#[event]
+pub struct EscrowReleaseApproved {
+    pub proposal: Pubkey,
+    pub approver: Pubkey,
+}
+
+#[event]
+pub struct EscrowReleaseScheduleSet {
+    pub escrow: Pubkey,
+    pub limit: ReleaseLimit,
+}
+
+#[event]
+pub struct EarlyReleasePolicySet {
+    pub escrow: Pubkey,
+    pub penalty_basis_points: u16,
+    pub penalty_account: Pubkey,
+}
+
+/**
+ * Emitted when a user's realm-wide preferences change, with the preferences afterwards.
+ */
+#[event]
+pub struct PreferencesChanged {
+    pub user: Pubkey,
+    pub realm: Pubkey,
+    pub preferred_mints: Vec<Pubkey>,
+    pub resolution_preference: ResolutionPreference,
+}
+
+#[event]
+pub struct DistributionPreferencesChanged {
+    pub user: Pubkey,
+    pub distribution: Pubkey,
+    pub preferred_mint: Option<Pubkey>,
+    pub resolution_preference: Option<ResolutionPreference>,
+}
+
+/**
+ * Emitted when an account created before versioning is upgraded to `version`.
+ */
+#[event]
+pub struct AccountMigrated {
+    pub account: Pubkey,
+    pub version: u8,
+}
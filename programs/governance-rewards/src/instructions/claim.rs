@@ -1,13 +1,24 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount};
+use anchor_spl::{
+    associated_token::{self, AssociatedToken},
+    token::{self, Mint, Token, TokenAccount},
+};
 
 use crate::{
-    distribution_payout_seeds,
     error::GovernanceRewardsError,
+    events::{Claimed, EscrowCreated},
+    instructions::{
+        crank_fee::pay_crank_fee,
+        escrow::{create_escrow_account, create_escrow_state},
+    },
     state::{
         claim_data::ClaimData,
         distribution::Distribution,
-        preferences::{ResolutionPreference, UserPreferences},
+        escrow::{find_escrow_owner, EscrowState},
+        preferences::{
+            find_escrow_address, find_timelocked_escrow_address, ResolutionPreference,
+            UserPreferences,
+        },
     },
 };
 
@@ -15,6 +26,10 @@ use crate::{
  * Instruction to redeem a claim.
  *
  * May only be called after the registration period ends.
+ *
+ * If `caller` is not the claimant, the distribution's crank fee is paid to `caller`.
+ * For token fees the remaining accounts must be the wallet of the fee option followed
+ * by a token account to receive the fee.
  */
 #[derive(Accounts)]
 pub struct Claim<'info> {
@@ -24,7 +39,7 @@ pub struct Claim<'info> {
     #[account(
         mut,
         seeds = [distribution.key().as_ref(), b"claim data".as_ref(), claimant.key().as_ref()],
-        bump
+        bump = claim_data.bump
     )]
     claim_data: Account<'info, ClaimData>,
 
@@ -36,12 +51,15 @@ pub struct Claim<'info> {
      */
     #[account(
         mut,
-        address = claim_data.chosen_option(&distribution).wallet
+        address = claim_data.chosen_option(&distribution)?.wallet
     )]
     rewards_account: Account<'info, TokenAccount>,
 
     /// CHECK: Not read
-    #[account(seeds = [b"payout authority".as_ref(), distribution.key().as_ref()], bump)]
+    #[account(
+        seeds = [b"payout authority".as_ref(), distribution.key().as_ref()],
+        bump = distribution.payout_authority_bump
+    )]
     payout_authority: AccountInfo<'info>,
 
     /**
@@ -52,9 +70,40 @@ pub struct Claim<'info> {
      *
      * If `UserPreferences.resolution_preference == Escrow`, this should be the user's
      * escrow wallet for the mint. See `assert_payout_is_escrow` for the PDA seeds.
+     *
+     * If `UserPreferences.resolution_preference == Timelock`, this should be the user's
+     * timelocked escrow wallet for the mint and unlock time.
+     *
+     * If the account does not exist yet it is created, paid for by `caller`.
      */
+    /// CHECK: Validated against the claimant's preferences
     #[account(mut)]
-    to_account: Account<'info, TokenAccount>,
+    to_account: AccountInfo<'info>,
+
+    /**
+     * State of the escrow in `to_account`, created alongside the escrow if it does not
+     * exist yet. Unused for wallet payouts.
+     */
+    /// CHECK: Only written when the escrow is created
+    #[account(
+        mut,
+        seeds = [to_account.key().as_ref(), b"escrow state".as_ref()],
+        bump = EscrowState::find_bump(&escrow_state, to_account.key())
+    )]
+    escrow_state: AccountInfo<'info>,
+
+    /**
+     * Mint of the user's chosen distribution option.
+     */
+    #[account(address = claim_data.chosen_option(&distribution)?.mint)]
+    payout_mint: Account<'info, Mint>,
+
+    /// CHECK: Not read
+    #[account(
+        seeds = [b"escrow owner".as_ref(), distribution.realm.as_ref()],
+        bump = EscrowState::find_escrow_owner_bump(&escrow_state, distribution.realm)
+    )]
+    escrow_owner: AccountInfo<'info>,
 
     /**
      * User claim preferences.
@@ -65,46 +114,62 @@ pub struct Claim<'info> {
     /// CHECK: Manually deserialized
     #[account(
         seeds = [distribution.realm.as_ref(), b"preferences".as_ref(), claimant.key().as_ref()],
-        bump
+        bump = UserPreferences::find_bump(&preferences, distribution.realm, claimant.key())
     )]
     preferences: AccountInfo<'info>,
 
+    /**
+     * User claim preferences for this distribution.
+     *
+     * This account does not have the be initialized when it is passed to the program.
+     * If initialized, it takes priority over the realm-wide preferences.
+     */
+    /// CHECK: Manually deserialized
+    #[account(
+        seeds = [distribution.key().as_ref(), b"distribution preferences".as_ref(), claimant.key().as_ref()],
+        bump
+    )]
+    distribution_preferences: AccountInfo<'info>,
+
     /**
      * User to receive rewards payout.
      */
     /// CHECK: Not read
     claimant: AccountInfo<'info>,
 
-    /// CHECK: Not read
+    /**
+     * Pays for the payout account if it has to be created, and receives lamport crank
+     * fees.
+     */
     #[account(mut)]
-    caller: AccountInfo<'info>,
+    caller: Signer<'info>,
 
     token_program: Program<'info, Token>,
+    associated_token_program: Program<'info, AssociatedToken>,
     system_program: Program<'info, System>,
+    rent: Sysvar<'info, Rent>,
 }
 
 impl<'info> Claim<'info> {
-    pub fn transfer_context(&self) -> CpiContext<'_, '_, '_, 'info, token::Transfer<'info>> {
-        let token_program = self.token_program.to_account_info();
-
-        let to = self.to_account.to_account_info();
-
-        let accounts = token::Transfer {
-            from: self.rewards_account.to_account_info(),
-            to,
-            authority: self.payout_authority.to_account_info(),
-        };
-        CpiContext::new(token_program, accounts)
-    }
-
-    pub fn payout_mint(&self) -> Pubkey {
-        self.claim_data.chosen_option(&self.distribution).mint
+    pub fn payout_mint(&self) -> Result<Pubkey> {
+        Ok(self.claim_data.chosen_option(&self.distribution)?.mint)
     }
 
+    /// Checks `to_account` is the claimant's ATA, creating it if it does not exist yet.
     pub fn assert_payout_is_ata(&self) -> Result<()> {
+        if !self.to_account.data_is_empty() {
+            return assert_payout_account(
+                &self.to_account,
+                ResolutionPreference::Wallet,
+                self.claimant.key(),
+                self.payout_mint()?,
+                self.distribution.realm,
+            );
+        }
+
         let expected_address = ResolutionPreference::Wallet.payout_address(
             self.claimant.key(),
-            self.payout_mint(),
+            self.payout_mint()?,
             self.distribution.realm,
         );
 
@@ -113,75 +178,297 @@ impl<'info> Claim<'info> {
             GovernanceRewardsError::WrongPayoutAccount
         );
 
-        require!(
-            self.to_account.owner == self.claimant.key(),
-            GovernanceRewardsError::WrongPayoutAccount
-        );
+        self.create_ata()
+    }
+
+    /**
+     * Checks `to_account` is the claimant's escrow, creating it if it does not exist yet.
+     *
+     * `unlock_ts` is only set for timelocked escrows, whose address also depends on it.
+     */
+    pub fn assert_payout_is_escrow(
+        &self,
+        escrow_admin: Pubkey,
+        unlock_ts: Option<u64>,
+    ) -> Result<()> {
+        let resolution_preference = match unlock_ts {
+            Some(unlock_ts) => ResolutionPreference::Timelock {
+                escrow_admin,
+                unlock_ts,
+            },
+            None => ResolutionPreference::Escrow { escrow_admin },
+        };
+
+        if !self.to_account.data_is_empty() {
+            return assert_payout_account(
+                &self.to_account,
+                resolution_preference,
+                self.claimant.key(),
+                self.payout_mint()?,
+                self.distribution.realm,
+            );
+        }
+
+        let realm = self.distribution.realm;
+        let claimant = self.claimant.key();
+        let mint = self.payout_mint()?;
+        let (expected_address, bump) = match unlock_ts {
+            Some(unlock_ts) => {
+                find_timelocked_escrow_address(realm, escrow_admin, claimant, mint, unlock_ts)
+            }
+            None => find_escrow_address(realm, escrow_admin, claimant, mint),
+        };
 
         require!(
-            self.to_account.mint == self.payout_mint(),
+            expected_address == self.to_account.key(),
             GovernanceRewardsError::WrongPayoutAccount
         );
 
+        let unlock_ts_bytes = unlock_ts.map(u64::to_le_bytes);
+        let bump_bytes = [bump];
+        let mut escrow_seeds: Vec<&[u8]> = vec![
+            realm.as_ref(),
+            escrow_admin.as_ref(),
+            b"escrow".as_ref(),
+            claimant.as_ref(),
+            mint.as_ref(),
+        ];
+        if let Some(unlock_ts_bytes) = &unlock_ts_bytes {
+            escrow_seeds.push(unlock_ts_bytes);
+        }
+        escrow_seeds.push(&bump_bytes);
+
+        create_escrow_account(
+            &self.to_account,
+            &escrow_seeds,
+            &self.escrow_owner,
+            &self.payout_mint.to_account_info(),
+            &self.caller.to_account_info(),
+            &self.rent.to_account_info(),
+            &self.system_program.to_account_info(),
+            &self.token_program.to_account_info(),
+        )?;
+
+        create_escrow_state(
+            &self.escrow_state,
+            self.to_account.key(),
+            EscrowState::find_address(self.to_account.key()).1,
+            find_escrow_owner(realm).1,
+            bump,
+            &self.caller.to_account_info(),
+            &self.system_program.to_account_info(),
+        )?;
+
+        emit!(EscrowCreated {
+            escrow: self.to_account.key(),
+            realm,
+            escrow_admin,
+            user: claimant,
+            mint,
+            unlock_ts,
+            payer: self.caller.key(),
+        });
+
         Ok(())
     }
 
-    pub fn assert_payout_is_escrow(&self, escrow_admin: Pubkey) -> Result<()> {
-        let expected_address = ResolutionPreference::Escrow { escrow_admin }.payout_address(
-            self.claimant.key(),
-            self.payout_mint(),
-            self.distribution.realm,
-        );
+    fn create_ata(&self) -> Result<()> {
+        associated_token::create(CpiContext::new(
+            self.associated_token_program.to_account_info(),
+            associated_token::Create {
+                payer: self.caller.to_account_info(),
+                associated_token: self.to_account.to_account_info(),
+                authority: self.claimant.to_account_info(),
+                mint: self.payout_mint.to_account_info(),
+                system_program: self.system_program.to_account_info(),
+                token_program: self.token_program.to_account_info(),
+                rent: self.rent.to_account_info(),
+            },
+        ))
+    }
+}
 
+/**
+ * Checks an existing `to_account` is the payout account for the claimant's resolution
+ * preference.
+ */
+pub(crate) fn assert_payout_account(
+    to_account: &AccountInfo,
+    resolution_preference: ResolutionPreference,
+    claimant: Pubkey,
+    mint: Pubkey,
+    realm: Pubkey,
+) -> Result<()> {
+    require!(
+        !matches!(
+            resolution_preference,
+            ResolutionPreference::GovernanceDeposit { .. }
+        ),
+        GovernanceRewardsError::GovernanceDepositRequired
+    );
+
+    let expected_address = resolution_preference.payout_address(claimant, mint, realm);
+
+    require!(
+        expected_address == to_account.key(),
+        GovernanceRewardsError::WrongPayoutAccount
+    );
+
+    let token_account = Account::<TokenAccount>::try_from(to_account)
+        .map_err(|_| GovernanceRewardsError::WrongPayoutAccount)?;
+
+    require!(
+        token_account.mint == mint,
+        GovernanceRewardsError::WrongPayoutAccount
+    );
+    require!(
+        !token_account.is_frozen(),
+        GovernanceRewardsError::PayoutAccountFrozen
+    );
+
+    if let ResolutionPreference::Wallet = resolution_preference {
         require!(
-            expected_address == self.to_account.key(),
+            token_account.owner == claimant,
             GovernanceRewardsError::WrongPayoutAccount
         );
-
-        Ok(())
     }
+
+    Ok(())
 }
 
-pub fn claim(ctx: Context<Claim>) -> Result<()> {
+/**
+ * Marks a claim as paid, returning the rewards owed to the claimant.
+ *
+ * The caller is responsible for moving the rewards out of `rewards_account`.
+ */
+pub(crate) fn record_claim(
+    distribution: &mut Account<Distribution>,
+    claim_data: &mut Account<ClaimData>,
+    rewards_account: &AccountInfo,
+) -> Result<u64> {
     require!(
-        !ctx.accounts.claim_data.has_claimed,
+        !claim_data.has_claimed,
         GovernanceRewardsError::AlreadyClaimed
     );
     require!(
-        ctx.accounts.distribution.can_claim(),
+        distribution.can_claim()?,
         GovernanceRewardsError::NotInClaimPeriod
     );
 
-    let rewards = ctx.accounts.distribution.calculate_rewards(
-        ctx.accounts
-            .claim_data
-            .chosen_option(&ctx.accounts.distribution),
-        ctx.accounts.claim_data.weight,
+    let option = claim_data.chosen_option(distribution)?;
+    require!(
+        option.wallet == rewards_account.key(),
+        GovernanceRewardsError::NoMatchingOption
     );
 
-    ctx.accounts.claim_data.has_claimed = true;
-    ctx.accounts.distribution.total_vote_weight_claimed = ctx
-        .accounts
-        .distribution
+    let rewards = distribution.calculate_rewards(option, claim_data.weight)?;
+    distribution
+        .distribution_options
+        .get_option_mut(claim_data.claim_option)?
+        .record_paid(rewards)?;
+
+    claim_data.has_claimed = true;
+    distribution.total_vote_weight_claimed = distribution
         .total_vote_weight_claimed
-        .checked_add(ctx.accounts.claim_data.weight)
-        .unwrap();
+        .checked_add(claim_data.weight)
+        .ok_or(GovernanceRewardsError::MathOverflow)?;
+
+    Ok(rewards)
+}
+
+/**
+ * Marks a claim as paid and transfers the claimant's rewards to `to_account`.
+ *
+ * `to_account` must already have been checked against the claimant's preferences.
+ * Returns the amount paid out.
+ */
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn pay_claim<'info>(
+    distribution: &mut Account<'info, Distribution>,
+    claim_data: &mut Account<'info, ClaimData>,
+    rewards_account: &AccountInfo<'info>,
+    to_account: &AccountInfo<'info>,
+    resolution: ResolutionPreference,
+    payout_authority: &AccountInfo<'info>,
+    payout_authority_bump: u8,
+    token_program: &AccountInfo<'info>,
+) -> Result<u64> {
+    let mint = claim_data.chosen_option(distribution)?.mint;
+    let rewards = record_claim(distribution, claim_data, rewards_account)?;
+
+    let distribution_key = distribution.key();
+    token::transfer(
+        CpiContext::new_with_signer(
+            token_program.clone(),
+            token::Transfer {
+                from: rewards_account.clone(),
+                to: to_account.clone(),
+                authority: payout_authority.clone(),
+            },
+            &[&[
+                b"payout authority".as_ref(),
+                distribution_key.as_ref(),
+                &[payout_authority_bump],
+            ]],
+        ),
+        rewards,
+    )?;
+
+    emit!(Claimed {
+        distribution: distribution_key,
+        claimant: claim_data.belongs_to,
+        amount: rewards,
+        mint,
+        destination: to_account.key(),
+        resolution,
+    });
+
+    Ok(rewards)
+}
 
-    let preferences = UserPreferences::get_or_default(&ctx.accounts.preferences);
+pub fn claim<'info>(ctx: Context<'_, '_, '_, 'info, Claim<'info>>) -> Result<()> {
+    let payout_authority_bump = ctx.accounts.distribution.payout_authority_bump;
+    let preferences = UserPreferences::get_for_distribution(
+        &ctx.accounts.preferences,
+        &ctx.accounts.distribution_preferences,
+    )?;
     match preferences.resolution_preference {
         ResolutionPreference::Wallet => ctx.accounts.assert_payout_is_ata()?,
         ResolutionPreference::Escrow { escrow_admin } => {
-            ctx.accounts.assert_payout_is_escrow(escrow_admin)?
+            ctx.accounts.assert_payout_is_escrow(escrow_admin, None)?
+        }
+        ResolutionPreference::Timelock {
+            escrow_admin,
+            unlock_ts,
+        } => ctx
+            .accounts
+            .assert_payout_is_escrow(escrow_admin, Some(unlock_ts))?,
+        ResolutionPreference::GovernanceDeposit { .. } => {
+            return Err(GovernanceRewardsError::GovernanceDepositRequired.into())
         }
     }
 
-    token::transfer(
-        ctx.accounts
-            .transfer_context()
-            .with_signer(distribution_payout_seeds!(
-                ctx.accounts.distribution,
-                ctx.bumps
-            )),
-        rewards,
-    )
+    pay_claim(
+        &mut ctx.accounts.distribution,
+        &mut ctx.accounts.claim_data,
+        &ctx.accounts.rewards_account.to_account_info(),
+        &ctx.accounts.to_account,
+        preferences.resolution_preference,
+        &ctx.accounts.payout_authority,
+        payout_authority_bump,
+        &ctx.accounts.token_program.to_account_info(),
+    )?;
+
+    if ctx.accounts.caller.key() != ctx.accounts.claimant.key() {
+        pay_crank_fee(
+            &mut ctx.accounts.distribution,
+            &ctx.accounts.caller.to_account_info(),
+            ctx.remaining_accounts,
+            &ctx.accounts.payout_authority,
+            payout_authority_bump,
+            &ctx.accounts.token_program.to_account_info(),
+        )?;
+    }
+
+    Ok(())
 }
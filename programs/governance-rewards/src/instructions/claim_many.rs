@@ -0,0 +1,160 @@
+use anchor_lang::{prelude::*, solana_program::program::set_return_data};
+use anchor_spl::token::Token;
+
+use crate::{
+    error::GovernanceRewardsError,
+    instructions::claim::{assert_payout_account, pay_claim},
+    state::{
+        claim_data::ClaimData, distribution::Distribution,
+        distribution_preferences::DistributionPreferences, preferences::UserPreferences,
+    },
+};
+
+/// Number of remaining accounts supplied for each distribution claimed against.
+pub const CLAIM_MANY_GROUP_SIZE: usize = 6;
+
+/**
+ * Instruction to redeem a user's claims on many distributions at once.
+ *
+ * All distributions must belong to `realm`. Each claim is described by a group of
+ * remaining accounts, in order:
+ *
+ * 0. `[writable]` Distribution
+ * 1. `[writable]` Claim data of the claimant on the distribution
+ * 2. `[]` Claimant preferences for the distribution (need not be initialized)
+ * 3. `[writable]` Rewards account of the claimant's chosen option
+ * 4. `[]` Payout authority of the distribution
+ * 5. `[writable]` Payout account, which must already exist
+ *
+ * The same checks as `claim` apply to every group, and any failure aborts the whole
+ * instruction. The amount paid on each distribution is returned as a borsh encoded
+ * `Vec<ClaimResult>` in the instruction's return data.
+ *
+ * Crank fees are not paid by this instruction. Use `claim` or `crank_claims` to claim
+ * on behalf of another user for a fee.
+ */
+#[derive(Accounts)]
+pub struct ClaimMany<'info> {
+    /**
+     * User claim preferences.
+     *
+     * This account does not have the be initialized when it is passed to the program.
+     * If an empty account is provided, default preferences will be used.
+     */
+    /// CHECK: Manually deserialized
+    #[account(
+        seeds = [realm.key().as_ref(), b"preferences".as_ref(), claimant.key().as_ref()],
+        bump = UserPreferences::find_bump(&preferences, realm.key(), claimant.key())
+    )]
+    preferences: AccountInfo<'info>,
+
+    /// CHECK: Not read
+    realm: AccountInfo<'info>,
+
+    /**
+     * User to receive rewards payout.
+     */
+    /// CHECK: Not read
+    claimant: AccountInfo<'info>,
+
+    caller: Signer<'info>,
+
+    token_program: Program<'info, Token>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ClaimResult {
+    pub distribution: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+}
+
+pub fn claim_many<'info>(ctx: Context<'_, '_, '_, 'info, ClaimMany<'info>>) -> Result<()> {
+    require!(
+        !ctx.remaining_accounts.is_empty()
+            && ctx.remaining_accounts.len() % CLAIM_MANY_GROUP_SIZE == 0,
+        GovernanceRewardsError::InvalidClaimGroups
+    );
+
+    let claimant = ctx.accounts.claimant.key();
+    let realm_preferences = UserPreferences::get_or_default(&ctx.accounts.preferences)?;
+    let token_program = ctx.accounts.token_program.to_account_info();
+
+    let mut results = Vec::with_capacity(ctx.remaining_accounts.len() / CLAIM_MANY_GROUP_SIZE);
+    for group in ctx.remaining_accounts.chunks(CLAIM_MANY_GROUP_SIZE) {
+        let distribution_info = &group[0];
+        let claim_data_info = &group[1];
+        let distribution_preferences = &group[2];
+        let rewards_account = &group[3];
+        let payout_authority = &group[4];
+        let to_account = &group[5];
+
+        let mut distribution = Box::new(Account::<Distribution>::try_from(distribution_info)?);
+        require!(
+            distribution.realm == ctx.accounts.realm.key(),
+            GovernanceRewardsError::WrongRealmForDistribution
+        );
+
+        let mut claim_data = Account::<ClaimData>::try_from(claim_data_info)?;
+        require!(
+            claim_data.distribution == distribution.key(),
+            GovernanceRewardsError::WrongDistributionForClaim
+        );
+        require!(
+            claim_data.belongs_to == claimant,
+            GovernanceRewardsError::WrongClaimant
+        );
+
+        require!(
+            distribution_preferences.key()
+                == DistributionPreferences::get_address(claimant, distribution.key()),
+            GovernanceRewardsError::WrongPreferencesAccount
+        );
+        let preferences =
+            realm_preferences
+                .clone()
+                .with_override(&DistributionPreferences::get_or_default(
+                    distribution_preferences,
+                ));
+
+        let payout_authority_bump = distribution.payout_authority_bump;
+        require!(
+            payout_authority.key() == distribution.payout_authority(distribution.key())?,
+            GovernanceRewardsError::WrongPayoutAuthority
+        );
+
+        let mint = claim_data.chosen_option(&distribution)?.mint;
+        assert_payout_account(
+            to_account,
+            preferences.resolution_preference,
+            claimant,
+            mint,
+            distribution.realm,
+        )?;
+
+        let amount = pay_claim(
+            &mut distribution,
+            &mut claim_data,
+            rewards_account,
+            to_account,
+            preferences.resolution_preference,
+            payout_authority,
+            payout_authority_bump,
+            &token_program,
+        )?;
+
+        distribution.exit(&crate::ID)?;
+        claim_data.exit(&crate::ID)?;
+
+        msg!("Claimed {} of {} from {}", amount, mint, distribution.key());
+        results.push(ClaimResult {
+            distribution: distribution.key(),
+            mint,
+            amount,
+        });
+    }
+
+    set_return_data(&results.try_to_vec()?);
+
+    Ok(())
+}
@@ -0,0 +1,158 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+use crate::{
+    error::GovernanceRewardsError,
+    events::Claimed,
+    instructions::{claim::record_claim, crank_fee::pay_crank_fee},
+    state::{
+        claim_data::ClaimData,
+        distribution::Distribution,
+        preferences::{ResolutionPreference, UserPreferences},
+    },
+    tools::deposit_governing_tokens,
+};
+
+/**
+ * Instruction to redeem a claim by depositing it into spl-governance.
+ *
+ * Used when the claimant's resolution preference is `GovernanceDeposit`. Rewards are
+ * deposited into the claimant's `TokenOwnerRecord`, so the chosen option must pay out
+ * in one of the realm's governing tokens. The record must already exist.
+ *
+ * If `caller` is not the claimant, the distribution's crank fee is paid to `caller`
+ * in the same way as `claim`.
+ */
+#[derive(Accounts)]
+pub struct ClaimToGovernance<'info> {
+    #[account(mut)]
+    distribution: Box<Account<'info, Distribution>>,
+
+    #[account(
+        mut,
+        seeds = [distribution.key().as_ref(), b"claim data".as_ref(), claimant.key().as_ref()],
+        bump = claim_data.bump
+    )]
+    claim_data: Account<'info, ClaimData>,
+
+    #[account(
+        mut,
+        address = claim_data.chosen_option(&distribution)?.wallet
+    )]
+    rewards_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Not read
+    #[account(
+        seeds = [b"payout authority".as_ref(), distribution.key().as_ref()],
+        bump = distribution.payout_authority_bump
+    )]
+    payout_authority: AccountInfo<'info>,
+
+    /// CHECK: Manually deserialized
+    #[account(
+        seeds = [distribution.realm.as_ref(), b"preferences".as_ref(), claimant.key().as_ref()],
+        bump = UserPreferences::find_bump(&preferences, distribution.realm, claimant.key())
+    )]
+    preferences: AccountInfo<'info>,
+
+    /// CHECK: Manually deserialized
+    #[account(
+        seeds = [distribution.key().as_ref(), b"distribution preferences".as_ref(), claimant.key().as_ref()],
+        bump
+    )]
+    distribution_preferences: AccountInfo<'info>,
+
+    /// CHECK: Not read
+    claimant: AccountInfo<'info>,
+
+    /// CHECK: Owner checked against the governance program
+    #[account(address = distribution.realm)]
+    realm: AccountInfo<'info>,
+
+    /// CHECK: Checked against the realm and the claimant's preferences
+    governance_program: AccountInfo<'info>,
+
+    /// CHECK: Checked against the realm and mint
+    #[account(mut)]
+    governing_token_holding: AccountInfo<'info>,
+
+    /// CHECK: Checked against the realm, mint and claimant
+    #[account(mut)]
+    token_owner_record: AccountInfo<'info>,
+
+    #[account(mut)]
+    caller: Signer<'info>,
+
+    token_program: Program<'info, Token>,
+    system_program: Program<'info, System>,
+    rent: Sysvar<'info, Rent>,
+}
+
+pub fn claim_to_governance<'info>(
+    ctx: Context<'_, '_, '_, 'info, ClaimToGovernance<'info>>,
+) -> Result<()> {
+    let preferences = UserPreferences::get_for_distribution(
+        &ctx.accounts.preferences,
+        &ctx.accounts.distribution_preferences,
+    )?;
+    match preferences.resolution_preference {
+        ResolutionPreference::GovernanceDeposit { governance_program } => require!(
+            governance_program == ctx.accounts.governance_program.key(),
+            GovernanceRewardsError::WrongGovernanceProgram
+        ),
+        _ => return Err(GovernanceRewardsError::WrongPayoutAccount.into()),
+    }
+
+    let resolution = preferences.resolution_preference;
+    let rewards_account = ctx.accounts.rewards_account.to_account_info();
+    let rewards = record_claim(
+        &mut ctx.accounts.distribution,
+        &mut ctx.accounts.claim_data,
+        &rewards_account,
+    )?;
+
+    let distribution_key = ctx.accounts.distribution.key();
+    let payout_authority_bump = ctx.accounts.distribution.payout_authority_bump;
+    deposit_governing_tokens(
+        &ctx.accounts.governance_program,
+        &ctx.accounts.realm,
+        &ctx.accounts.governing_token_holding,
+        &rewards_account,
+        &ctx.accounts.claimant,
+        &ctx.accounts.payout_authority,
+        &[
+            b"payout authority".as_ref(),
+            distribution_key.as_ref(),
+            &[payout_authority_bump],
+        ],
+        &ctx.accounts.token_owner_record,
+        &ctx.accounts.caller.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.rent.to_account_info(),
+        ctx.accounts.rewards_account.mint,
+        rewards,
+    )?;
+
+    emit!(Claimed {
+        distribution: distribution_key,
+        claimant: ctx.accounts.claimant.key(),
+        amount: rewards,
+        mint: ctx.accounts.rewards_account.mint,
+        destination: ctx.accounts.token_owner_record.key(),
+        resolution,
+    });
+
+    if ctx.accounts.caller.key() != ctx.accounts.claimant.key() {
+        pay_crank_fee(
+            &mut ctx.accounts.distribution,
+            &ctx.accounts.caller.to_account_info(),
+            ctx.remaining_accounts,
+            &ctx.accounts.payout_authority,
+            payout_authority_bump,
+            &ctx.accounts.token_program.to_account_info(),
+        )?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,173 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Token;
+
+use crate::{
+    error::GovernanceRewardsError,
+    instructions::{
+        claim::{assert_payout_account, pay_claim},
+        crank_fee::pay_crank_fee,
+    },
+    state::{
+        claim_data::ClaimData, crank_fee::CrankFee, distribution::Distribution,
+        distribution_preferences::DistributionPreferences, preferences::UserPreferences,
+    },
+};
+
+/// Number of remaining accounts supplied for each claimant paid out by the crank.
+pub const CRANK_CLAIMS_GROUP_SIZE: usize = 5;
+
+/**
+ * Permissionless instruction to pay out many claimants of a distribution at once.
+ *
+ * May only be called after the registration period ends. Each claimant is described
+ * by a group of remaining accounts, in order:
+ *
+ * 0. `[writable]` Claim data of the claimant
+ * 1. `[]` Claimant preferences for the realm (need not be initialized)
+ * 2. `[]` Claimant preferences for the distribution (need not be initialized)
+ * 3. `[writable]` Rewards account of the claimant's chosen option
+ * 4. `[writable]` Payout account, which must already exist
+ *
+ * Unlike `claim_many`, a group that fails validation is skipped and logged instead of
+ * failing the whole instruction. This covers payout accounts that do not exist yet,
+ * accounts that do not match the claimant's preferences and claims that have already
+ * been redeemed. Those claimants can still claim for themselves later.
+ *
+ * The distribution's crank fee is paid to `caller` for every claimant paid out on their
+ * behalf. For token fees the remaining accounts must be prefixed with the wallet of the
+ * fee option and a token account to receive the fees.
+ */
+#[derive(Accounts)]
+pub struct CrankClaims<'info> {
+    #[account(mut)]
+    distribution: Box<Account<'info, Distribution>>,
+
+    /// CHECK: Not read
+    #[account(
+        seeds = [b"payout authority".as_ref(), distribution.key().as_ref()],
+        bump = distribution.payout_authority_bump
+    )]
+    payout_authority: AccountInfo<'info>,
+
+    #[account(mut)]
+    caller: Signer<'info>,
+
+    token_program: Program<'info, Token>,
+}
+
+pub fn crank_claims<'info>(ctx: Context<'_, '_, '_, 'info, CrankClaims<'info>>) -> Result<()> {
+    require!(
+        ctx.accounts.distribution.can_claim()?,
+        GovernanceRewardsError::NotInClaimPeriod
+    );
+
+    let (fee_accounts, groups) = match ctx.accounts.distribution.crank_fee {
+        Some(CrankFee::Tokens { .. }) => {
+            require!(
+                ctx.remaining_accounts.len() >= 2,
+                GovernanceRewardsError::MissingCrankFeeAccounts
+            );
+            ctx.remaining_accounts.split_at(2)
+        }
+        _ => (&[][..], ctx.remaining_accounts),
+    };
+    require!(
+        !groups.is_empty() && groups.len() % CRANK_CLAIMS_GROUP_SIZE == 0,
+        GovernanceRewardsError::InvalidClaimGroups
+    );
+
+    let payout_authority_bump = ctx.accounts.distribution.payout_authority_bump;
+    let token_program = ctx.accounts.token_program.to_account_info();
+    let caller = ctx.accounts.caller.to_account_info();
+
+    for group in groups.chunks(CRANK_CLAIMS_GROUP_SIZE) {
+        match crank_claim(
+            &mut ctx.accounts.distribution,
+            group,
+            &ctx.accounts.payout_authority,
+            payout_authority_bump,
+            &token_program,
+        ) {
+            Ok((claimant, amount)) => {
+                msg!("Paid {} to {}", amount, group[4].key());
+                if claimant != caller.key() {
+                    pay_crank_fee(
+                        &mut ctx.accounts.distribution,
+                        &caller,
+                        fee_accounts,
+                        &ctx.accounts.payout_authority,
+                        payout_authority_bump,
+                        &token_program,
+                    )?;
+                }
+            }
+            Err(err) => msg!("Skipped {}: {}", group[0].key(), err),
+        }
+    }
+
+    Ok(())
+}
+
+/**
+ * Pays out a single claimant of the crank, returning the claimant and amount paid.
+ *
+ * A failed transfer aborts the whole crank, so everything which would make a claimant's
+ * transfer fail, including a frozen payout account, is checked before their claim is
+ * recorded. An error from these checks skips the claimant and leaves the distribution
+ * untouched.
+ */
+fn crank_claim<'info>(
+    distribution: &mut Account<'info, Distribution>,
+    group: &[AccountInfo<'info>],
+    payout_authority: &AccountInfo<'info>,
+    payout_authority_bump: u8,
+    token_program: &AccountInfo<'info>,
+) -> Result<(Pubkey, u64)> {
+    let claim_data_info = &group[0];
+    let preferences = &group[1];
+    let distribution_preferences = &group[2];
+    let rewards_account = &group[3];
+    let to_account = &group[4];
+
+    let mut claim_data = Account::<ClaimData>::try_from(claim_data_info)?;
+    require!(
+        claim_data.distribution == distribution.key(),
+        GovernanceRewardsError::WrongDistributionForClaim
+    );
+
+    let claimant = claim_data.belongs_to;
+    require!(
+        UserPreferences::has_address(preferences, distribution.realm, claimant),
+        GovernanceRewardsError::WrongPreferencesAccount
+    );
+    require!(
+        distribution_preferences.key()
+            == DistributionPreferences::get_address(claimant, distribution.key()),
+        GovernanceRewardsError::WrongPreferencesAccount
+    );
+    let preferences = UserPreferences::get_for_distribution(preferences, distribution_preferences)?;
+
+    let mint = claim_data.chosen_option(distribution)?.mint;
+    assert_payout_account(
+        to_account,
+        preferences.resolution_preference,
+        claimant,
+        mint,
+        distribution.realm,
+    )?;
+
+    let amount = pay_claim(
+        distribution,
+        &mut claim_data,
+        rewards_account,
+        to_account,
+        preferences.resolution_preference,
+        payout_authority,
+        payout_authority_bump,
+        token_program,
+    )?;
+
+    claim_data.exit(&crate::ID)?;
+
+    Ok((claimant, amount))
+}
@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token;
+
+use crate::{
+    error::GovernanceRewardsError,
+    events::CrankFeePaid,
+    state::{crank_fee::CrankFee, distribution::Distribution},
+};
+
+/**
+ * Pays the distribution's crank fee, if any, to `caller`.
+ *
+ * For `CrankFee::Tokens`, `fee_accounts` must start with the wallet of the fee option
+ * followed by a token account of the same mint to receive the fee. These are taken
+ * from the remaining accounts of the calling instruction.
+ *
+ * Callers are responsible for only paying the fee when acting on behalf of another user.
+ */
+pub(crate) fn pay_crank_fee<'info>(
+    distribution: &mut Account<'info, Distribution>,
+    caller: &AccountInfo<'info>,
+    fee_accounts: &[AccountInfo<'info>],
+    payout_authority: &AccountInfo<'info>,
+    payout_authority_bump: u8,
+    token_program: &AccountInfo<'info>,
+) -> Result<()> {
+    let crank_fee = match distribution.crank_fee {
+        Some(crank_fee) => crank_fee,
+        None => return Ok(()),
+    };
+
+    match crank_fee {
+        CrankFee::Lamports { amount } => {
+            let distribution_info = distribution.to_account_info();
+            let rent_exempt_minimum = Rent::get()?.minimum_balance(distribution_info.data_len());
+            let available = distribution_info
+                .lamports()
+                .saturating_sub(rent_exempt_minimum);
+            if available < amount {
+                msg!("Distribution cannot cover the crank fee, skipping it");
+                return Ok(());
+            }
+
+            **distribution_info.try_borrow_mut_lamports()? -= amount;
+            **caller.try_borrow_mut_lamports()? += amount;
+        }
+        CrankFee::Tokens { option, amount } => {
+            require!(
+                fee_accounts.len() >= 2,
+                GovernanceRewardsError::MissingCrankFeeAccounts
+            );
+            let fee_option = distribution.distribution_options.get_option(option)?;
+            require!(
+                fee_accounts[0].key() == fee_option.wallet,
+                GovernanceRewardsError::WrongCrankFeeAccount
+            );
+
+            let distribution_key = distribution.key();
+            token::transfer(
+                CpiContext::new_with_signer(
+                    token_program.clone(),
+                    token::Transfer {
+                        from: fee_accounts[0].clone(),
+                        to: fee_accounts[1].clone(),
+                        authority: payout_authority.clone(),
+                    },
+                    &[&[
+                        b"payout authority".as_ref(),
+                        distribution_key.as_ref(),
+                        &[payout_authority_bump],
+                    ]],
+                ),
+                amount,
+            )?;
+            distribution
+                .distribution_options
+                .get_option_mut(option)?
+                .record_paid(amount)?;
+        }
+    }
+
+    distribution.crank_fees_paid = distribution
+        .crank_fees_paid
+        .checked_add(crank_fee.amount())
+        .ok_or(GovernanceRewardsError::MathOverflow)?;
+
+    emit!(CrankFeePaid {
+        distribution: distribution.key(),
+        caller: caller.key(),
+        fee: crank_fee,
+    });
+
+    Ok(())
+}
@@ -1,9 +1,11 @@
 use anchor_lang::prelude::*;
-use std::mem::size_of;
 
 use crate::{
     error::GovernanceRewardsError,
-    state::{distribution::Distribution, distribution_option::DistributionOptions},
+    events::DistributionCreated,
+    state::{
+        crank_fee::CrankFee, distribution::Distribution, distribution_option::DistributionOptions,
+    },
 };
 
 /**
@@ -15,6 +17,9 @@ use crate::{
  * This instruction accepts up to 8 remaining accounts to be used to fund the
  * distribution. These accounts should be SPL Token Accounts owned by the payout
  * authority.
+ *
+ * An optional `crank_fee` tips callers who claim or clean up on behalf of other users.
+ * A token fee must name one of the provided funding accounts by index.
  */
 #[derive(Accounts)]
 pub struct CreateDistribution<'info> {
@@ -24,7 +29,7 @@ pub struct CreateDistribution<'info> {
     #[account(
         init,
         payer = payer,
-        space = 8 + size_of::<Distribution>()
+        space = Distribution::SPACE
     )]
     pub distribution: Box<Account<'info, Distribution>>,
 
@@ -69,24 +74,50 @@ pub fn create_distribution(
     ctx: Context<CreateDistribution>,
     registration_cutoff: u64,
     registrar: Option<Pubkey>,
+    crank_fee: Option<CrankFee>,
 ) -> Result<()> {
+    let now = Clock::get()
+        .map_err(|_| GovernanceRewardsError::ClockUnavailable)?
+        .unix_timestamp as u64;
     require!(
-        registration_cutoff > Clock::get().unwrap().unix_timestamp as u64,
+        registration_cutoff > now,
         GovernanceRewardsError::RegistrationCutoffInPast
     );
 
+    let distribution_options = DistributionOptions::from_accounts(
+        ctx.remaining_accounts,
+        ctx.accounts.payout_authority.key(),
+    )?;
+    if let Some(CrankFee::Tokens { option, .. }) = crank_fee {
+        require!(
+            matches!(distribution_options.get(option as usize), Some(Some(_))),
+            GovernanceRewardsError::InvalidCrankFee
+        );
+    }
+
     ctx.accounts.distribution.set_inner(Distribution {
         registration_period_end_ts: registration_cutoff,
         realm: ctx.accounts.realm.key(),
         total_vote_weight: 0,
         total_vote_weight_claimed: 0,
-        distribution_options: DistributionOptions::from_accounts(
-            ctx.remaining_accounts,
-            ctx.accounts.payout_authority.key(),
-        )?,
+        distribution_options,
         voter_weight_program: ctx.accounts.voter_weight_program.key(),
         admin: ctx.accounts.admin.key(),
         registrar,
+        crank_fee,
+        registrant_count: 0,
+        crank_fees_paid: 0,
+        version: Distribution::VERSION,
+        payout_authority_bump: ctx.bumps["payout_authority"],
+        reserved: [0; 63],
+    });
+
+    emit!(DistributionCreated {
+        distribution: ctx.accounts.distribution.key(),
+        realm: ctx.accounts.realm.key(),
+        admin: ctx.accounts.admin.key(),
+        registration_cutoff,
+        crank_fee,
     });
 
     Ok(())
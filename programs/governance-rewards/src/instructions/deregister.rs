@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::GovernanceRewardsError,
+    events::Deregistered,
+    state::{claim_data::ClaimData, distribution::Distribution},
+};
+
+/**
+ * Instruction to withdraw a registration from a distribution.
+ *
+ * May be called by the registrant, or by the distribution admin, while registration is
+ * open. The claim data is closed and its rent refunded to the account which paid it.
+ */
+#[derive(Accounts)]
+pub struct Deregister<'info> {
+    #[account(mut)]
+    distribution: Box<Account<'info, Distribution>>,
+
+    #[account(
+        mut,
+        seeds = [distribution.key().as_ref(), b"claim data".as_ref(), registrant.key().as_ref()],
+        bump = claim_data.bump,
+        has_one = payer @ GovernanceRewardsError::WrongRentPayer,
+        close = payer
+    )]
+    claim_data: Account<'info, ClaimData>,
+
+    /// CHECK: Not read
+    registrant: AccountInfo<'info>,
+
+    /**
+     * Account which paid rent for the claim data.
+     */
+    /// CHECK: Checked against claim data
+    #[account(mut)]
+    payer: AccountInfo<'info>,
+
+    /**
+     * Either the registrant or the distribution admin.
+     */
+    authority: Signer<'info>,
+}
+
+pub fn deregister(ctx: Context<Deregister>) -> Result<()> {
+    require!(
+        ctx.accounts.distribution.can_register()?,
+        GovernanceRewardsError::RegistrationOver
+    );
+
+    let authority = ctx.accounts.authority.key();
+    require!(
+        authority == ctx.accounts.registrant.key() || authority == ctx.accounts.distribution.admin,
+        GovernanceRewardsError::CannotDeregister
+    );
+
+    let weight = ctx.accounts.claim_data.weight;
+    let option = ctx.accounts.claim_data.claim_option;
+    ctx.accounts.distribution.remove_weight(option, weight)?;
+    // Migrated distributions do not count users who registered before versioning.
+    ctx.accounts.distribution.registrant_count =
+        ctx.accounts.distribution.registrant_count.saturating_sub(1);
+
+    emit!(Deregistered {
+        distribution: ctx.accounts.distribution.key(),
+        registrant: ctx.accounts.registrant.key(),
+        weight,
+        option,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::GovernanceRewardsError,
+    events::EscrowAdminConfigCreated,
+    state::escrow_admin::{EscrowAdminConfig, MAX_ESCROW_ADMIN_SIGNERS},
+};
+
+/**
+ * Instruction to create an M-of-N escrow release admin.
+ *
+ * Users who want their escrow released by several signers set the config's address as
+ * their `escrow_admin`.
+ */
+#[derive(Accounts)]
+#[instruction(signers: Vec<Pubkey>)]
+pub struct CreateEscrowAdminConfig<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = EscrowAdminConfig::space(signers.len())
+    )]
+    config: Account<'info, EscrowAdminConfig>,
+
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    system_program: Program<'info, System>,
+}
+
+pub fn create_escrow_admin_config(
+    ctx: Context<CreateEscrowAdminConfig>,
+    signers: Vec<Pubkey>,
+    threshold: u8,
+) -> Result<()> {
+    require!(
+        signers.len() <= MAX_ESCROW_ADMIN_SIGNERS,
+        GovernanceRewardsError::TooManyEscrowAdminSigners
+    );
+    require!(
+        threshold > 0 && threshold as usize <= signers.len(),
+        GovernanceRewardsError::InvalidEscrowAdminThreshold
+    );
+    for (i, signer) in signers.iter().enumerate() {
+        require!(
+            !signers[..i].contains(signer),
+            GovernanceRewardsError::DuplicateEscrowAdminSigner
+        );
+    }
+
+    let config = &mut ctx.accounts.config;
+    config.signers = signers;
+    config.threshold = threshold;
+    config.proposal_count = 0;
+
+    emit!(EscrowAdminConfigCreated {
+        config: config.key(),
+        signers: config.signers.clone(),
+        threshold,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,130 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, CloseAccount, Token, TokenAccount};
+
+use crate::{
+    error::GovernanceRewardsError,
+    events::EscrowClosed,
+    state::{
+        escrow::EscrowState,
+        preferences::{find_escrow_address, find_timelocked_escrow_address},
+    },
+    tools::close_account,
+};
+
+/**
+ * Instruction to close an empty escrow and refund its rent.
+ *
+ * May be called by the user or the escrow release admin. `unlock_ts` must be set when
+ * closing a timelocked escrow.
+ *
+ * Rent for the escrow and its state is refunded to the payer recorded in the state.
+ * Escrows created before escrow state was recorded refund the user instead.
+ */
+#[derive(Accounts)]
+pub struct CloseEscrow<'info> {
+    #[account(mut)]
+    escrow: Account<'info, TokenAccount>,
+
+    /// CHECK: Manually deserialized, may not exist for older escrows
+    #[account(
+        mut,
+        seeds = [escrow.key().as_ref(), b"escrow state".as_ref()],
+        bump = EscrowState::find_bump(&escrow_state, escrow.key())
+    )]
+    escrow_state: AccountInfo<'info>,
+
+    /// CHECK: Not read
+    #[account(
+        seeds = [b"escrow owner".as_ref(), realm.key().as_ref()],
+        bump = EscrowState::find_escrow_owner_bump(&escrow_state, realm.key())
+    )]
+    pub escrow_owner: AccountInfo<'info>,
+
+    /// CHECK: Not read
+    realm: AccountInfo<'info>,
+
+    /// CHECK: Not read
+    user: AccountInfo<'info>,
+
+    /// CHECK: Not read
+    escrow_release_admin: AccountInfo<'info>,
+
+    /**
+     * Receives the refunded rent.
+     */
+    /// CHECK: Checked against the escrow state
+    #[account(mut)]
+    rent_recipient: AccountInfo<'info>,
+
+    /**
+     * Either the user or the escrow release admin.
+     */
+    authority: Signer<'info>,
+
+    token_program: Program<'info, Token>,
+}
+
+pub fn close_escrow(ctx: Context<CloseEscrow>, unlock_ts: Option<u64>) -> Result<()> {
+    let realm = ctx.accounts.realm.key();
+    let escrow_admin = ctx.accounts.escrow_release_admin.key();
+    let user = ctx.accounts.user.key();
+    let mint = ctx.accounts.escrow.mint;
+    let (expected_address, _) = match unlock_ts {
+        Some(unlock_ts) => {
+            find_timelocked_escrow_address(realm, escrow_admin, user, mint, unlock_ts)
+        }
+        None => find_escrow_address(realm, escrow_admin, user, mint),
+    };
+    require!(
+        ctx.accounts.escrow.key() == expected_address,
+        GovernanceRewardsError::WrongEscrowAccount
+    );
+
+    let authority = ctx.accounts.authority.key();
+    require!(
+        authority == user || authority == escrow_admin,
+        GovernanceRewardsError::CannotCloseEscrow
+    );
+
+    require!(
+        ctx.accounts.escrow.amount == 0,
+        GovernanceRewardsError::EscrowNotEmpty
+    );
+
+    // Read before the state is closed below.
+    let escrow_owner_bump = EscrowState::find_escrow_owner_bump(&ctx.accounts.escrow_state, realm);
+    if ctx.accounts.escrow_state.data_is_empty() {
+        require!(
+            ctx.accounts.rent_recipient.key() == user,
+            GovernanceRewardsError::WrongRentPayer
+        );
+    } else {
+        let escrow_state = Account::<EscrowState>::try_from(&ctx.accounts.escrow_state)?;
+        require!(
+            ctx.accounts.rent_recipient.key() == escrow_state.payer,
+            GovernanceRewardsError::WrongRentPayer
+        );
+        close_account(&ctx.accounts.escrow_state, &ctx.accounts.rent_recipient)?;
+    }
+
+    token::close_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.escrow.to_account_info(),
+            destination: ctx.accounts.rent_recipient.to_account_info(),
+            authority: ctx.accounts.escrow_owner.to_account_info(),
+        },
+        &[&[
+            b"escrow owner".as_ref(),
+            realm.as_ref(),
+            &[escrow_owner_bump],
+        ]],
+    ))?;
+
+    emit!(EscrowClosed {
+        escrow: ctx.accounts.escrow.key(),
+        rent_recipient: ctx.accounts.rent_recipient.key(),
+    });
+
+    Ok(())
+}
@@ -1,6 +1,8 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{Token, TokenAccount};
 
+use crate::{events::EscrowCreated, state::escrow::EscrowState};
+
 #[derive(Accounts)]
 pub struct CreateEscrow<'info> {
     #[account(
@@ -19,6 +21,18 @@ pub struct CreateEscrow<'info> {
     )]
     escrow: Account<'info, TokenAccount>,
 
+    /**
+     * Records who paid for the escrow so their rent can be refunded by `close_escrow`.
+     */
+    #[account(
+        init,
+        payer = payer,
+        space = EscrowState::SPACE,
+        seeds = [escrow.key().as_ref(), b"escrow state".as_ref()],
+        bump
+    )]
+    escrow_state: Account<'info, EscrowState>,
+
     /// CHECK: Not read
     #[account(seeds = [b"escrow owner".as_ref(), realm.key().as_ref()], bump)]
     pub escrow_owner: AccountInfo<'info>,
@@ -46,6 +60,22 @@ pub struct CreateEscrow<'info> {
     rent: Sysvar<'info, Rent>,
 }
 
-pub fn create_escrow(_ctx: Context<CreateEscrow>) -> Result<()> {
+pub fn create_escrow(ctx: Context<CreateEscrow>) -> Result<()> {
+    ctx.accounts.escrow_state.set_inner(EscrowState {
+        payer: ctx.accounts.payer.key(),
+        bump: ctx.bumps["escrow_state"],
+        escrow_owner_bump: ctx.bumps["escrow_owner"],
+        escrow_bump: ctx.bumps["escrow"],
+    });
+
+    emit!(EscrowCreated {
+        escrow: ctx.accounts.escrow.key(),
+        realm: ctx.accounts.realm.key(),
+        escrow_admin: ctx.accounts.escrow_release_admin.key(),
+        user: ctx.accounts.user.key(),
+        mint: ctx.accounts.mint.key(),
+        unlock_ts: None,
+        payer: ctx.accounts.payer.key(),
+    });
     Ok(())
 }
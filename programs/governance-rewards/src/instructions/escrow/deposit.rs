@@ -0,0 +1,133 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+use super::enforce_release_schedule;
+use crate::{
+    events::{EscrowReleaseKind, EscrowReleased},
+    state::escrow::EscrowState,
+    tools::deposit_governing_tokens,
+};
+
+/**
+ * Instruction for the escrow release admin to release funds from escrow by depositing
+ * them into the user's spl-governance `TokenOwnerRecord`.
+ *
+ * The escrow's mint must be one of the realm's governing tokens and the record must
+ * already exist.
+ */
+#[derive(Accounts)]
+pub struct DepositFromEscrow<'info> {
+    #[account(
+        mut,
+        seeds = [
+            realm.key().as_ref(),
+            escrow_release_admin.key().as_ref(),
+            b"escrow".as_ref(),
+            user.key().as_ref(),
+            escrow.mint.as_ref(),
+        ],
+        bump = EscrowState::find_escrow_bump(
+            &escrow_state,
+            realm.key(),
+            escrow_release_admin.key(),
+            user.key(),
+            escrow.mint,
+            None
+        )
+    )]
+    escrow: Account<'info, TokenAccount>,
+
+    /// CHECK: Not read
+    #[account(
+        seeds = [b"escrow owner".as_ref(), realm.key().as_ref()],
+        bump = EscrowState::find_escrow_owner_bump(&escrow_state, realm.key())
+    )]
+    pub escrow_owner: AccountInfo<'info>,
+
+    /// CHECK: Owner checked against the governance program
+    realm: AccountInfo<'info>,
+
+    /// CHECK: Not read
+    user: AccountInfo<'info>,
+
+    escrow_release_admin: Signer<'info>,
+
+    /// CHECK: Checked against the realm
+    governance_program: AccountInfo<'info>,
+
+    /// CHECK: Checked against the realm and mint
+    #[account(mut)]
+    governing_token_holding: AccountInfo<'info>,
+
+    /// CHECK: Checked against the realm, mint and user
+    #[account(mut)]
+    token_owner_record: AccountInfo<'info>,
+
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    token_program: Program<'info, Token>,
+    system_program: Program<'info, System>,
+    rent: Sysvar<'info, Rent>,
+
+    /**
+     * Release schedule of the escrow, enforced if it exists. Kept last so the other
+     * accounts keep the positions they had before release schedules were added.
+     */
+    /// CHECK: Deserialized only if it exists
+    #[account(mut, seeds = [escrow.key().as_ref(), b"release schedule".as_ref()], bump)]
+    release_schedule: AccountInfo<'info>,
+
+    /**
+     * State of the escrow, holding its bumps. Escrows created before escrow state was
+     * recorded have none, and their bumps are derived instead.
+     */
+    /// CHECK: Manually deserialized, may not exist for older escrows
+    #[account(
+        seeds = [escrow.key().as_ref(), b"escrow state".as_ref()],
+        bump = EscrowState::find_bump(&escrow_state, escrow.key())
+    )]
+    escrow_state: AccountInfo<'info>,
+}
+
+pub fn deposit_from_escrow(ctx: Context<DepositFromEscrow>, amount: u64) -> Result<()> {
+    enforce_release_schedule(
+        &ctx.accounts.release_schedule,
+        ctx.accounts.escrow.amount,
+        amount,
+    )?;
+
+    let realm_key = ctx.accounts.realm.key();
+    let escrow_owner_bump =
+        EscrowState::find_escrow_owner_bump(&ctx.accounts.escrow_state, realm_key);
+    deposit_governing_tokens(
+        &ctx.accounts.governance_program,
+        &ctx.accounts.realm,
+        &ctx.accounts.governing_token_holding,
+        &ctx.accounts.escrow.to_account_info(),
+        &ctx.accounts.user,
+        &ctx.accounts.escrow_owner,
+        &[
+            b"escrow owner".as_ref(),
+            realm_key.as_ref(),
+            &[escrow_owner_bump],
+        ],
+        &ctx.accounts.token_owner_record,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.rent.to_account_info(),
+        ctx.accounts.escrow.mint,
+        amount,
+    )?;
+
+    emit!(EscrowReleased {
+        escrow: ctx.accounts.escrow.key(),
+        to: ctx.accounts.token_owner_record.key(),
+        amount,
+        penalty: 0,
+        kind: EscrowReleaseKind::GovernanceDeposit,
+    });
+
+    Ok(())
+}
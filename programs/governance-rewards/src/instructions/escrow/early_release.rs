@@ -0,0 +1,215 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use spl_governance::tools::spl_token;
+
+use crate::{
+    error::GovernanceRewardsError,
+    events::{EarlyReleasePolicySet, EscrowReleaseKind, EscrowReleased},
+    state::{
+        early_release::EarlyReleasePolicy, escrow::EscrowState, release_schedule::MAX_BASIS_POINTS,
+    },
+};
+
+/**
+ * Instruction for the escrow release admin to let the user release a timelocked escrow
+ * early, in exchange for a penalty paid to `penalty_account`.
+ */
+#[derive(Accounts)]
+#[instruction(unlock_ts: u64)]
+pub struct SetEarlyReleasePolicy<'info> {
+    #[account(
+        seeds = [
+            realm.key().as_ref(),
+            escrow_release_admin.key().as_ref(),
+            b"escrow".as_ref(),
+            user.key().as_ref(),
+            escrow.mint.as_ref(),
+            unlock_ts.to_le_bytes().as_ref(),
+        ],
+        bump = EscrowState::find_escrow_bump(
+            &escrow_state,
+            realm.key(),
+            escrow_release_admin.key(),
+            user.key(),
+            escrow.mint,
+            Some(unlock_ts)
+        )
+    )]
+    escrow: Account<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = EarlyReleasePolicy::SPACE,
+        seeds = [escrow.key().as_ref(), b"early release policy".as_ref()],
+        bump
+    )]
+    policy: Account<'info, EarlyReleasePolicy>,
+
+    #[account(constraint = penalty_account.mint == escrow.mint @ GovernanceRewardsError::EscrowMintMismatch)]
+    penalty_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Not read
+    realm: AccountInfo<'info>,
+
+    /// CHECK: Not read
+    user: AccountInfo<'info>,
+
+    escrow_release_admin: Signer<'info>,
+
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    system_program: Program<'info, System>,
+
+    /**
+     * State of the escrow, holding its bumps. Escrows created before escrow state was
+     * recorded have none, and their bumps are derived instead.
+     */
+    /// CHECK: Manually deserialized, may not exist for older escrows
+    #[account(
+        seeds = [escrow.key().as_ref(), b"escrow state".as_ref()],
+        bump = EscrowState::find_bump(&escrow_state, escrow.key())
+    )]
+    escrow_state: AccountInfo<'info>,
+}
+
+pub fn set_early_release_policy(
+    ctx: Context<SetEarlyReleasePolicy>,
+    _unlock_ts: u64,
+    penalty_basis_points: u16,
+) -> Result<()> {
+    require!(
+        penalty_basis_points <= MAX_BASIS_POINTS,
+        GovernanceRewardsError::InvalidEarlyReleasePenalty
+    );
+
+    let policy = &mut ctx.accounts.policy;
+    policy.penalty_basis_points = penalty_basis_points;
+    policy.penalty_account = ctx.accounts.penalty_account.key();
+
+    emit!(EarlyReleasePolicySet {
+        escrow: ctx.accounts.escrow.key(),
+        penalty_basis_points,
+        penalty_account: policy.penalty_account,
+    });
+
+    Ok(())
+}
+
+/**
+ * Instruction for the user to release funds from their timelocked escrow before it
+ * unlocks. The policy's penalty is paid to its penalty account and the rest to the
+ * user's wallet. Once the escrow has unlocked no penalty is charged.
+ */
+#[derive(Accounts)]
+#[instruction(unlock_ts: u64)]
+pub struct ReleaseTimelockedEscrowEarly<'info> {
+    #[account(
+        mut,
+        seeds = [
+            realm.key().as_ref(),
+            escrow_release_admin.key().as_ref(),
+            b"escrow".as_ref(),
+            user.key().as_ref(),
+            escrow.mint.as_ref(),
+            unlock_ts.to_le_bytes().as_ref(),
+        ],
+        bump = EscrowState::find_escrow_bump(
+            &escrow_state,
+            realm.key(),
+            escrow_release_admin.key(),
+            user.key(),
+            escrow.mint,
+            Some(unlock_ts)
+        )
+    )]
+    escrow: Account<'info, TokenAccount>,
+
+    #[account(
+        seeds = [escrow.key().as_ref(), b"early release policy".as_ref()],
+        bump,
+        has_one = penalty_account @ GovernanceRewardsError::WrongPenaltyAccount
+    )]
+    policy: Account<'info, EarlyReleasePolicy>,
+
+    #[account(mut)]
+    penalty_account: Account<'info, TokenAccount>,
+
+    #[account(mut, associated_token::mint = escrow.mint, associated_token::authority = user)]
+    to_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Not read
+    #[account(
+        seeds = [b"escrow owner".as_ref(), realm.key().as_ref()],
+        bump = EscrowState::find_escrow_owner_bump(&escrow_state, realm.key())
+    )]
+    pub escrow_owner: AccountInfo<'info>,
+
+    /// CHECK: Not read
+    realm: AccountInfo<'info>,
+
+    user: Signer<'info>,
+
+    /// CHECK: Not read
+    escrow_release_admin: AccountInfo<'info>,
+
+    token_program: Program<'info, Token>,
+
+    /**
+     * State of the escrow, holding its bumps. Escrows created before escrow state was
+     * recorded have none, and their bumps are derived instead.
+     */
+    /// CHECK: Manually deserialized, may not exist for older escrows
+    #[account(
+        seeds = [escrow.key().as_ref(), b"escrow state".as_ref()],
+        bump = EscrowState::find_bump(&escrow_state, escrow.key())
+    )]
+    escrow_state: AccountInfo<'info>,
+}
+
+pub fn release_timelocked_escrow_early(
+    ctx: Context<ReleaseTimelockedEscrowEarly>,
+    unlock_ts: u64,
+    amount: u64,
+) -> Result<()> {
+    let penalty = if (Clock::get()?.unix_timestamp as u64) < unlock_ts {
+        ctx.accounts.policy.penalty(amount)
+    } else {
+        0
+    };
+
+    let realm_key = ctx.accounts.realm.key();
+    let escrow_owner_seeds = &[b"escrow owner".as_ref(), realm_key.as_ref()];
+    if penalty > 0 {
+        spl_token::transfer_spl_tokens_signed(
+            &ctx.accounts.escrow.to_account_info(),
+            &ctx.accounts.penalty_account.to_account_info(),
+            &ctx.accounts.escrow_owner.to_account_info(),
+            escrow_owner_seeds,
+            &crate::ID,
+            penalty,
+            &ctx.accounts.token_program.to_account_info(),
+        )?;
+    }
+
+    spl_token::transfer_spl_tokens_signed(
+        &ctx.accounts.escrow.to_account_info(),
+        &ctx.accounts.to_account.to_account_info(),
+        &ctx.accounts.escrow_owner.to_account_info(),
+        escrow_owner_seeds,
+        &crate::ID,
+        amount - penalty,
+        &ctx.accounts.token_program.to_account_info(),
+    )?;
+
+    emit!(EscrowReleased {
+        escrow: ctx.accounts.escrow.key(),
+        to: ctx.accounts.to_account.key(),
+        amount: amount - penalty,
+        penalty,
+        kind: EscrowReleaseKind::Early,
+    });
+
+    Ok(())
+}
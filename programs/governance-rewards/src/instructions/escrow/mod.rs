@@ -1,10 +1,121 @@
+pub mod admin_config;
+pub mod close;
 pub mod create;
+pub mod deposit;
+pub mod early_release;
+pub mod release_proposal;
+pub mod release_schedule;
+pub mod timelock;
 pub mod transfer;
 
-use anchor_lang::prelude::Pubkey;
+use anchor_lang::{prelude::*, solana_program::program_pack::Pack};
+use anchor_spl::token::{self, spl_token, InitializeAccount};
+
+use crate::{
+    state::{
+        escrow::{find_escrow_owner, EscrowState},
+        release_schedule::EscrowReleaseSchedule,
+    },
+    tools::create_pda_account,
+};
+pub use admin_config::*;
+pub use close::*;
 pub use create::*;
+pub use deposit::*;
+pub use early_release::*;
+pub use release_proposal::*;
+pub use release_schedule::*;
+pub use timelock::*;
 pub use transfer::*;
 
 pub fn get_escrow_owner(realm: Pubkey) -> Pubkey {
-    Pubkey::find_program_address(&[b"escrow owner".as_ref(), realm.as_ref()], &crate::ID).0
+    find_escrow_owner(realm).0
+}
+
+/**
+ * Creates an escrow token account at its PDA, owned by the realm's escrow owner.
+ *
+ * `escrow_seeds` must be the full signer seeds of the escrow address, including the bump.
+ */
+#[allow(clippy::too_many_arguments)]
+pub fn create_escrow_account<'info>(
+    escrow: &AccountInfo<'info>,
+    escrow_seeds: &[&[u8]],
+    escrow_owner: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    rent: &AccountInfo<'info>,
+    system_program_info: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+) -> Result<()> {
+    create_pda_account(
+        escrow,
+        escrow_seeds,
+        payer,
+        system_program_info,
+        spl_token::state::Account::LEN,
+        token_program.key,
+    )?;
+
+    token::initialize_account(CpiContext::new(
+        token_program.clone(),
+        InitializeAccount {
+            account: escrow.clone(),
+            mint: mint.clone(),
+            authority: escrow_owner.clone(),
+            rent: rent.clone(),
+        },
+    ))
+}
+
+/**
+ * Creates the `EscrowState` of a newly created escrow, recording `payer` as the account
+ * to refund when the escrow is closed.
+ *
+ * `bump`, `escrow_owner_bump` and `escrow_bump` must be the canonical bumps of the
+ * state, the escrow owner and the escrow.
+ */
+pub fn create_escrow_state<'info>(
+    escrow_state: &AccountInfo<'info>,
+    escrow: Pubkey,
+    bump: u8,
+    escrow_owner_bump: u8,
+    escrow_bump: u8,
+    payer: &AccountInfo<'info>,
+    system_program_info: &AccountInfo<'info>,
+) -> Result<()> {
+    create_pda_account(
+        escrow_state,
+        &[escrow.as_ref(), b"escrow state".as_ref(), &[bump]],
+        payer,
+        system_program_info,
+        EscrowState::SPACE,
+        &crate::ID,
+    )?;
+
+    let mut data = escrow_state.try_borrow_mut_data()?;
+    EscrowState {
+        payer: payer.key(),
+        bump,
+        escrow_owner_bump,
+        escrow_bump,
+    }
+    .try_serialize(&mut &mut data[..])
+}
+
+/**
+ * Records a release from an escrow against its release schedule, if it has one.
+ */
+pub fn enforce_release_schedule<'info>(
+    release_schedule: &AccountInfo<'info>,
+    escrow_balance: u64,
+    amount: u64,
+) -> Result<()> {
+    if release_schedule.data_is_empty() {
+        return Ok(());
+    }
+
+    let mut schedule = Account::<EscrowReleaseSchedule>::try_from(release_schedule)?;
+    schedule.record_release(amount, escrow_balance, &Clock::get()?)?;
+    schedule.exit(&crate::ID)
 }
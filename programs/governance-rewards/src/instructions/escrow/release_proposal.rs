@@ -0,0 +1,224 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use spl_governance::tools::spl_token;
+
+use crate::{
+    error::GovernanceRewardsError,
+    events::{EscrowReleaseApproved, EscrowReleaseKind, EscrowReleaseProposed, EscrowReleased},
+    state::escrow_admin::{
+        EscrowAdminConfig, EscrowReleaseProposal, ESCROW_RELEASE_PROPOSAL_LIFETIME,
+    },
+};
+
+/**
+ * Instruction for a signer of an `EscrowAdminConfig` to propose releasing funds from an
+ * escrow it administers to the user's wallet. The proposer's approval is recorded, and
+ * the proposal expires after `ESCROW_RELEASE_PROPOSAL_LIFETIME`.
+ */
+#[derive(Accounts)]
+pub struct ProposeEscrowRelease<'info> {
+    #[account(mut)]
+    config: Account<'info, EscrowAdminConfig>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = EscrowReleaseProposal::space(config.signers.len()),
+        seeds = [
+            config.key().as_ref(),
+            b"escrow release proposal".as_ref(),
+            &config.proposal_count.to_le_bytes(),
+        ],
+        bump
+    )]
+    proposal: Account<'info, EscrowReleaseProposal>,
+
+    #[account(
+        seeds = [
+            realm.key().as_ref(),
+            config.key().as_ref(),
+            b"escrow".as_ref(),
+            user.key().as_ref(),
+            escrow.mint.as_ref(),
+        ],
+        bump
+    )]
+    escrow: Account<'info, TokenAccount>,
+
+    #[account(associated_token::mint = escrow.mint, associated_token::authority = user)]
+    to_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Not read
+    realm: AccountInfo<'info>,
+
+    /// CHECK: Not read
+    user: AccountInfo<'info>,
+
+    proposer: Signer<'info>,
+
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    system_program: Program<'info, System>,
+}
+
+pub fn propose_escrow_release(ctx: Context<ProposeEscrowRelease>, amount: u64) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    require!(
+        config.has_signer(ctx.accounts.proposer.key),
+        GovernanceRewardsError::NotEscrowAdminSigner
+    );
+    config.proposal_count = config
+        .proposal_count
+        .checked_add(1)
+        .ok_or(GovernanceRewardsError::MathOverflow)?;
+
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.config = config.key();
+    proposal.escrow = ctx.accounts.escrow.key();
+    proposal.to_account = ctx.accounts.to_account.key();
+    proposal.amount = amount;
+    proposal.payer = ctx.accounts.payer.key();
+    proposal.approvals = vec![ctx.accounts.proposer.key()];
+    proposal.expires_ts = (Clock::get()?.unix_timestamp as u64)
+        .checked_add(ESCROW_RELEASE_PROPOSAL_LIFETIME)
+        .ok_or(GovernanceRewardsError::MathOverflow)?;
+
+    emit!(EscrowReleaseProposed {
+        config: proposal.config,
+        proposal: proposal.key(),
+        escrow: proposal.escrow,
+        to: proposal.to_account,
+        amount,
+        proposer: ctx.accounts.proposer.key(),
+    });
+
+    Ok(())
+}
+
+/**
+ * Instruction for a signer of an `EscrowAdminConfig` to approve a proposed release which
+ * has not expired.
+ */
+#[derive(Accounts)]
+pub struct ApproveEscrowRelease<'info> {
+    config: Account<'info, EscrowAdminConfig>,
+
+    #[account(mut, has_one = config)]
+    proposal: Account<'info, EscrowReleaseProposal>,
+
+    approver: Signer<'info>,
+}
+
+pub fn approve_escrow_release(ctx: Context<ApproveEscrowRelease>) -> Result<()> {
+    let approver = ctx.accounts.approver.key();
+    require!(
+        ctx.accounts.config.has_signer(&approver),
+        GovernanceRewardsError::NotEscrowAdminSigner
+    );
+
+    let proposal = &mut ctx.accounts.proposal;
+    require!(
+        !proposal.is_expired(&Clock::get()?),
+        GovernanceRewardsError::EscrowReleaseProposalExpired
+    );
+    require!(
+        !proposal.approvals.contains(&approver),
+        GovernanceRewardsError::EscrowReleaseAlreadyApproved
+    );
+    proposal.approvals.push(approver);
+
+    emit!(EscrowReleaseApproved {
+        proposal: proposal.key(),
+        approver,
+    });
+
+    Ok(())
+}
+
+/**
+ * Instruction to execute a release once it has been approved by the config's threshold
+ * of signers, before it expires. Callable by anyone. The proposal is closed and its rent
+ * refunded.
+ *
+ * Escrows administered by an `EscrowAdminConfig` have no release schedule, since the
+ * config cannot sign `set_escrow_release_schedule`. The threshold of approvals limits
+ * their releases instead.
+ */
+#[derive(Accounts)]
+pub struct ExecuteEscrowRelease<'info> {
+    config: Account<'info, EscrowAdminConfig>,
+
+    #[account(
+        mut,
+        has_one = config,
+        has_one = escrow,
+        has_one = to_account,
+        has_one = payer,
+        close = payer
+    )]
+    proposal: Account<'info, EscrowReleaseProposal>,
+
+    #[account(
+        mut,
+        seeds = [
+            realm.key().as_ref(),
+            config.key().as_ref(),
+            b"escrow".as_ref(),
+            user.key().as_ref(),
+            escrow.mint.as_ref(),
+        ],
+        bump
+    )]
+    escrow: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    to_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Not read
+    #[account(seeds = [b"escrow owner".as_ref(), realm.key().as_ref()], bump)]
+    pub escrow_owner: AccountInfo<'info>,
+
+    /// CHECK: Not read
+    realm: AccountInfo<'info>,
+
+    /// CHECK: Not read
+    user: AccountInfo<'info>,
+
+    /// CHECK: Checked against the proposal
+    #[account(mut)]
+    payer: AccountInfo<'info>,
+
+    token_program: Program<'info, Token>,
+}
+
+pub fn execute_escrow_release(ctx: Context<ExecuteEscrowRelease>) -> Result<()> {
+    require!(
+        !ctx.accounts.proposal.is_expired(&Clock::get()?),
+        GovernanceRewardsError::EscrowReleaseProposalExpired
+    );
+    require!(
+        ctx.accounts.proposal.approvals.len() >= ctx.accounts.config.threshold as usize,
+        GovernanceRewardsError::EscrowReleaseNotApproved
+    );
+
+    spl_token::transfer_spl_tokens_signed(
+        &ctx.accounts.escrow.to_account_info(),
+        &ctx.accounts.to_account.to_account_info(),
+        &ctx.accounts.escrow_owner.to_account_info(),
+        &[b"escrow owner".as_ref(), ctx.accounts.realm.key().as_ref()],
+        &crate::ID,
+        ctx.accounts.proposal.amount,
+        &ctx.accounts.token_program.to_account_info(),
+    )?;
+
+    emit!(EscrowReleased {
+        escrow: ctx.accounts.escrow.key(),
+        to: ctx.accounts.to_account.key(),
+        amount: ctx.accounts.proposal.amount,
+        penalty: 0,
+        kind: EscrowReleaseKind::Proposal,
+    });
+
+    Ok(())
+}
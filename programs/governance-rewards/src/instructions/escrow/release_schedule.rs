@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use crate::{
+    error::GovernanceRewardsError,
+    events::EscrowReleaseScheduleSet,
+    state::release_schedule::{EscrowReleaseSchedule, ReleaseLimit},
+};
+
+/**
+ * Instruction for the escrow release admin to limit how quickly funds can be released
+ * from an escrow. Releases already made in the current period count against the new
+ * limit.
+ */
+#[derive(Accounts)]
+pub struct SetEscrowReleaseSchedule<'info> {
+    #[account(
+        seeds = [
+            realm.key().as_ref(),
+            escrow_release_admin.key().as_ref(),
+            b"escrow".as_ref(),
+            user.key().as_ref(),
+            escrow.mint.as_ref(),
+        ],
+        bump
+    )]
+    escrow: Account<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = EscrowReleaseSchedule::SPACE,
+        seeds = [escrow.key().as_ref(), b"release schedule".as_ref()],
+        bump
+    )]
+    release_schedule: Account<'info, EscrowReleaseSchedule>,
+
+    /// CHECK: Not read
+    realm: AccountInfo<'info>,
+
+    /// CHECK: Not read
+    user: AccountInfo<'info>,
+
+    escrow_release_admin: Signer<'info>,
+
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    system_program: Program<'info, System>,
+}
+
+pub fn set_escrow_release_schedule(
+    ctx: Context<SetEscrowReleaseSchedule>,
+    limit: ReleaseLimit,
+) -> Result<()> {
+    require!(
+        limit.is_valid(),
+        GovernanceRewardsError::InvalidReleaseSchedule
+    );
+
+    ctx.accounts
+        .release_schedule
+        .set_limit(limit, ctx.accounts.escrow.amount, &Clock::get()?);
+
+    emit!(EscrowReleaseScheduleSet {
+        escrow: ctx.accounts.escrow.key(),
+        limit,
+    });
+
+    Ok(())
+}
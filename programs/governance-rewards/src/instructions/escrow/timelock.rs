@@ -0,0 +1,207 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use spl_governance::tools::spl_token;
+
+use crate::{
+    error::GovernanceRewardsError,
+    events::{EscrowCreated, EscrowReleaseKind, EscrowReleased},
+    state::escrow::EscrowState,
+};
+
+/**
+ * Instruction to create a timelocked escrow payout account if required.
+ *
+ * Timelocked escrows are addressed like plain escrows with `unlock_ts` appended to the
+ * seeds, so each unlock time gets its own escrow.
+ */
+#[derive(Accounts)]
+#[instruction(unlock_ts: u64)]
+pub struct CreateTimelockedEscrow<'info> {
+    #[account(
+        init,
+        payer = payer,
+        seeds = [
+            realm.key().as_ref(),
+            escrow_release_admin.key().as_ref(),
+            b"escrow".as_ref(),
+            user.key().as_ref(),
+            mint.key().as_ref(),
+            unlock_ts.to_le_bytes().as_ref(),
+        ],
+        bump,
+        token::mint = mint,
+        token::authority = escrow_owner
+    )]
+    escrow: Account<'info, TokenAccount>,
+
+    /**
+     * Records who paid for the escrow so their rent can be refunded by `close_escrow`.
+     */
+    #[account(
+        init,
+        payer = payer,
+        space = EscrowState::SPACE,
+        seeds = [escrow.key().as_ref(), b"escrow state".as_ref()],
+        bump
+    )]
+    escrow_state: Account<'info, EscrowState>,
+
+    /// CHECK: Not read
+    #[account(seeds = [b"escrow owner".as_ref(), realm.key().as_ref()], bump)]
+    pub escrow_owner: AccountInfo<'info>,
+
+    /// CHECK: Not read
+    realm: AccountInfo<'info>,
+
+    /// CHECK: Not read
+    mint: AccountInfo<'info>,
+
+    /// CHECK: Not read
+    user: AccountInfo<'info>,
+
+    /// CHECK: Not read
+    escrow_release_admin: AccountInfo<'info>,
+
+    /// CHECK: Not read
+    #[account(mut)]
+    payer: AccountInfo<'info>,
+
+    token_program: Program<'info, Token>,
+
+    system_program: Program<'info, System>,
+
+    rent: Sysvar<'info, Rent>,
+}
+
+pub fn create_timelocked_escrow(
+    ctx: Context<CreateTimelockedEscrow>,
+    unlock_ts: u64,
+) -> Result<()> {
+    ctx.accounts.escrow_state.set_inner(EscrowState {
+        payer: ctx.accounts.payer.key(),
+        bump: ctx.bumps["escrow_state"],
+        escrow_owner_bump: ctx.bumps["escrow_owner"],
+        escrow_bump: ctx.bumps["escrow"],
+    });
+
+    emit!(EscrowCreated {
+        escrow: ctx.accounts.escrow.key(),
+        realm: ctx.accounts.realm.key(),
+        escrow_admin: ctx.accounts.escrow_release_admin.key(),
+        user: ctx.accounts.user.key(),
+        mint: ctx.accounts.mint.key(),
+        unlock_ts: Some(unlock_ts),
+        payer: ctx.accounts.payer.key(),
+    });
+    Ok(())
+}
+
+/**
+ * Instruction to release funds from a timelocked escrow to the user's wallet.
+ *
+ * The escrow release admin may release funds at any time. Once `unlock_ts` has passed
+ * the user may also release their own funds, so they are not stuck if the admin stops
+ * responding.
+ */
+#[derive(Accounts)]
+#[instruction(unlock_ts: u64)]
+pub struct ReleaseTimelockedEscrow<'info> {
+    #[account(
+        mut,
+        seeds = [
+            realm.key().as_ref(),
+            escrow_release_admin.key().as_ref(),
+            b"escrow".as_ref(),
+            user.key().as_ref(),
+            mint.key().as_ref(),
+            unlock_ts.to_le_bytes().as_ref(),
+        ],
+        bump = EscrowState::find_escrow_bump(
+            &escrow_state,
+            realm.key(),
+            escrow_release_admin.key(),
+            user.key(),
+            mint.key(),
+            Some(unlock_ts)
+        )
+    )]
+    escrow: Account<'info, TokenAccount>,
+
+    #[account(mut, associated_token::mint = escrow.mint, associated_token::authority = user)]
+    to_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Not read
+    #[account(
+        seeds = [b"escrow owner".as_ref(), realm.key().as_ref()],
+        bump = EscrowState::find_escrow_owner_bump(&escrow_state, realm.key())
+    )]
+    pub escrow_owner: AccountInfo<'info>,
+
+    /// CHECK: Not read
+    realm: AccountInfo<'info>,
+
+    /// CHECK: Not read
+    mint: AccountInfo<'info>,
+
+    /// CHECK: Not read
+    user: AccountInfo<'info>,
+
+    /// CHECK: Not read
+    escrow_release_admin: AccountInfo<'info>,
+
+    /**
+     * Either the escrow release admin, or the user once the escrow has unlocked.
+     */
+    authority: Signer<'info>,
+
+    token_program: Program<'info, Token>,
+
+    /**
+     * State of the escrow, holding its bumps. Escrows created before escrow state was
+     * recorded have none, and their bumps are derived instead.
+     */
+    /// CHECK: Manually deserialized, may not exist for older escrows
+    #[account(
+        seeds = [escrow.key().as_ref(), b"escrow state".as_ref()],
+        bump = EscrowState::find_bump(&escrow_state, escrow.key())
+    )]
+    escrow_state: AccountInfo<'info>,
+}
+
+pub fn release_timelocked_escrow(
+    ctx: Context<ReleaseTimelockedEscrow>,
+    unlock_ts: u64,
+    amount: u64,
+) -> Result<()> {
+    let authority = ctx.accounts.authority.key();
+    if authority != ctx.accounts.escrow_release_admin.key() {
+        require!(
+            authority == ctx.accounts.user.key(),
+            GovernanceRewardsError::WrongEscrowAuthority
+        );
+        require!(
+            Clock::get()?.unix_timestamp as u64 >= unlock_ts,
+            GovernanceRewardsError::EscrowLocked
+        );
+    }
+
+    spl_token::transfer_spl_tokens_signed(
+        &ctx.accounts.escrow.to_account_info(),
+        &ctx.accounts.to_account.to_account_info(),
+        &ctx.accounts.escrow_owner.to_account_info(),
+        &[b"escrow owner".as_ref(), ctx.accounts.realm.key().as_ref()],
+        &crate::ID,
+        amount,
+        &ctx.accounts.token_program.to_account_info(),
+    )?;
+
+    emit!(EscrowReleased {
+        escrow: ctx.accounts.escrow.key(),
+        to: ctx.accounts.to_account.key(),
+        amount,
+        penalty: 0,
+        kind: EscrowReleaseKind::Timelock,
+    });
+
+    Ok(())
+}
@@ -2,6 +2,19 @@ use anchor_lang::prelude::*;
 use anchor_spl::token::{Token, TokenAccount};
 use spl_governance::tools::spl_token;
 
+use super::enforce_release_schedule;
+use crate::{
+    error::GovernanceRewardsError,
+    events::{EscrowReleaseKind, EscrowReleased},
+    state::escrow::EscrowState,
+};
+
+/**
+ * Instruction for the escrow release admin to release funds to the user's wallet.
+ *
+ * The admin may be a PDA, such as an spl-governance `Governance`, which signs when a
+ * proposal containing this instruction is executed.
+ */
 #[derive(Accounts)]
 pub struct TransferFromEscrow<'info> {
     #[account(
@@ -13,7 +26,14 @@ pub struct TransferFromEscrow<'info> {
             user.key().as_ref(),
             mint.key().as_ref(),
         ],
-        bump
+        bump = EscrowState::find_escrow_bump(
+            &escrow_state,
+            realm.key(),
+            escrow_release_admin.key(),
+            user.key(),
+            mint.key(),
+            None
+        )
     )]
     escrow: Account<'info, TokenAccount>,
 
@@ -21,7 +41,10 @@ pub struct TransferFromEscrow<'info> {
     to_account: Account<'info, TokenAccount>,
 
     /// CHECK: Not read
-    #[account(seeds = [b"escrow owner".as_ref(), realm.key().as_ref()], bump)]
+    #[account(
+        seeds = [b"escrow owner".as_ref(), realm.key().as_ref()],
+        bump = EscrowState::find_escrow_owner_bump(&escrow_state, realm.key())
+    )]
     pub escrow_owner: AccountInfo<'info>,
 
     /// CHECK: Not read
@@ -36,9 +59,122 @@ pub struct TransferFromEscrow<'info> {
     escrow_release_admin: Signer<'info>,
 
     token_program: Program<'info, Token>,
+
+    /**
+     * Release schedule of the escrow, enforced if it exists. Kept last so the other
+     * accounts keep the positions they had before release schedules were added.
+     */
+    /// CHECK: Deserialized only if it exists
+    #[account(mut, seeds = [escrow.key().as_ref(), b"release schedule".as_ref()], bump)]
+    release_schedule: AccountInfo<'info>,
+
+    /**
+     * State of the escrow, holding its bumps. Escrows created before escrow state was
+     * recorded have none, and their bumps are derived instead.
+     */
+    /// CHECK: Manually deserialized, may not exist for older escrows
+    #[account(
+        seeds = [escrow.key().as_ref(), b"escrow state".as_ref()],
+        bump = EscrowState::find_bump(&escrow_state, escrow.key())
+    )]
+    escrow_state: AccountInfo<'info>,
 }
 
 pub fn transfer_from_escrow(ctx: Context<TransferFromEscrow>, amount: u64) -> Result<()> {
+    enforce_release_schedule(
+        &ctx.accounts.release_schedule,
+        ctx.accounts.escrow.amount,
+        amount,
+    )?;
+
+    spl_token::transfer_spl_tokens_signed(
+        &ctx.accounts.escrow.to_account_info(),
+        &ctx.accounts.to_account.to_account_info(),
+        &ctx.accounts.escrow_owner.to_account_info(),
+        &[b"escrow owner".as_ref(), ctx.accounts.realm.key().as_ref()],
+        &crate::ID,
+        amount,
+        &ctx.accounts.token_program.to_account_info(),
+    )?;
+
+    emit!(EscrowReleased {
+        escrow: ctx.accounts.escrow.key(),
+        to: ctx.accounts.to_account.key(),
+        amount,
+        penalty: 0,
+        kind: EscrowReleaseKind::Admin,
+    });
+
+    Ok(())
+}
+
+/**
+ * Instruction to release funds from escrow to any token account of the escrow's mint.
+ *
+ * Unlike `transfer_from_escrow`, the destination need not belong to the user, so both
+ * the escrow release admin and the user must sign. Since the user consents, the release
+ * is exempt from the escrow's release schedule and is not counted against it.
+ */
+#[derive(Accounts)]
+pub struct TransferFromEscrowWithConsent<'info> {
+    #[account(
+        mut,
+        seeds = [
+            realm.key().as_ref(),
+            escrow_release_admin.key().as_ref(),
+            b"escrow".as_ref(),
+            user.key().as_ref(),
+            mint.key().as_ref(),
+        ],
+        bump = EscrowState::find_escrow_bump(
+            &escrow_state,
+            realm.key(),
+            escrow_release_admin.key(),
+            user.key(),
+            mint.key(),
+            None
+        )
+    )]
+    escrow: Account<'info, TokenAccount>,
+
+    #[account(mut, constraint = to_account.mint == escrow.mint @ GovernanceRewardsError::EscrowMintMismatch)]
+    to_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Not read
+    #[account(
+        seeds = [b"escrow owner".as_ref(), realm.key().as_ref()],
+        bump = EscrowState::find_escrow_owner_bump(&escrow_state, realm.key())
+    )]
+    pub escrow_owner: AccountInfo<'info>,
+
+    /// CHECK: Not read
+    realm: AccountInfo<'info>,
+
+    /// CHECK: Not read
+    mint: AccountInfo<'info>,
+
+    user: Signer<'info>,
+
+    escrow_release_admin: Signer<'info>,
+
+    token_program: Program<'info, Token>,
+
+    /**
+     * State of the escrow, holding its bumps. Escrows created before escrow state was
+     * recorded have none, and their bumps are derived instead.
+     */
+    /// CHECK: Manually deserialized, may not exist for older escrows
+    #[account(
+        seeds = [escrow.key().as_ref(), b"escrow state".as_ref()],
+        bump = EscrowState::find_bump(&escrow_state, escrow.key())
+    )]
+    escrow_state: AccountInfo<'info>,
+}
+
+pub fn transfer_from_escrow_with_consent(
+    ctx: Context<TransferFromEscrowWithConsent>,
+    amount: u64,
+) -> Result<()> {
     spl_token::transfer_spl_tokens_signed(
         &ctx.accounts.escrow.to_account_info(),
         &ctx.accounts.to_account.to_account_info(),
@@ -47,6 +183,15 @@ pub fn transfer_from_escrow(ctx: Context<TransferFromEscrow>, amount: u64) -> Re
         &crate::ID,
         amount,
         &ctx.accounts.token_program.to_account_info(),
-    )
-    .map_err(Error::from)
+    )?;
+
+    emit!(EscrowReleased {
+        escrow: ctx.accounts.escrow.key(),
+        to: ctx.accounts.to_account.key(),
+        amount,
+        penalty: 0,
+        kind: EscrowReleaseKind::Consent,
+    });
+
+    Ok(())
 }
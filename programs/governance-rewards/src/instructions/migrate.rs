@@ -0,0 +1,243 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use crate::{
+    error::GovernanceRewardsError,
+    events::AccountMigrated,
+    state::{
+        claim_data::ClaimData,
+        distribution::Distribution,
+        legacy::{ClaimDataV0, DistributionV0, UserPreferencesV0},
+        preferences::UserPreferences,
+    },
+    tools::grow_account,
+};
+
+/**
+ * Instruction to upgrade a distribution created before accounts were versioned.
+ *
+ * Anyone may migrate a distribution, since the upgrade only fills in defaults. The
+ * account is grown to the current size, with any extra rent paid by `payer`.
+ *
+ * The wallet of every option must follow as remaining accounts, in option order, so the
+ * amounts already paid and reclaimed from each can be recorded.
+ */
+#[derive(Accounts)]
+pub struct MigrateDistribution<'info> {
+    /// CHECK: Read with the legacy layout
+    #[account(mut, owner = crate::id())]
+    distribution: AccountInfo<'info>,
+
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    system_program: Program<'info, System>,
+}
+
+/**
+ * Instruction to upgrade claim data created before accounts were versioned.
+ */
+#[derive(Accounts)]
+pub struct MigrateClaimData<'info> {
+    /// CHECK: Read with the legacy layout
+    #[account(mut, owner = crate::id())]
+    claim_data: AccountInfo<'info>,
+
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    system_program: Program<'info, System>,
+}
+
+/**
+ * Instruction to upgrade user preferences created before accounts were versioned.
+ */
+#[derive(Accounts)]
+pub struct MigrateUserPreferences<'info> {
+    /// CHECK: Read with the legacy layout
+    #[account(
+        mut,
+        owner = crate::id(),
+        seeds = [realm.key().as_ref(), b"preferences".as_ref(), user.key().as_ref()],
+        bump
+    )]
+    preferences: AccountInfo<'info>,
+
+    /**
+     * Realm and user the preferences belong to, needed to derive their bump.
+     */
+    /// CHECK: Not read
+    realm: AccountInfo<'info>,
+
+    /// CHECK: Not read
+    user: AccountInfo<'info>,
+
+    #[account(mut)]
+    payer: Signer<'info>,
+
+    system_program: Program<'info, System>,
+}
+
+pub fn migrate_distribution<'info>(
+    ctx: Context<'_, '_, '_, 'info, MigrateDistribution<'info>>,
+) -> Result<()> {
+    let account = &ctx.accounts.distribution;
+    require!(
+        !is_versioned::<Distribution>(account, |_| Distribution::SPACE),
+        GovernanceRewardsError::AccountAlreadyMigrated
+    );
+
+    let mut distribution = Distribution::from(DistributionV0::try_from_account(account)?);
+    record_legacy_payouts(&mut distribution, ctx.remaining_accounts)?;
+    distribution.payout_authority_bump = Distribution::find_payout_authority(account.key()).1;
+    write_migrated(
+        account,
+        &ctx.accounts.payer,
+        &ctx.accounts.system_program,
+        Distribution::SPACE,
+        &distribution,
+    )?;
+
+    emit!(AccountMigrated {
+        account: account.key(),
+        version: Distribution::VERSION,
+    });
+
+    Ok(())
+}
+
+pub fn migrate_claim_data(ctx: Context<MigrateClaimData>) -> Result<()> {
+    let account = &ctx.accounts.claim_data;
+    require!(
+        !is_versioned::<ClaimData>(account, |_| ClaimData::SPACE),
+        GovernanceRewardsError::AccountAlreadyMigrated
+    );
+
+    let mut claim_data = ClaimData::from(ClaimDataV0::try_from_account(account)?);
+    let (address, bump) = ClaimData::find_address(claim_data.belongs_to, claim_data.distribution);
+    require!(address == account.key(), ErrorCode::ConstraintSeeds);
+    claim_data.bump = bump;
+    write_migrated(
+        account,
+        &ctx.accounts.payer,
+        &ctx.accounts.system_program,
+        ClaimData::SPACE,
+        &claim_data,
+    )?;
+
+    emit!(AccountMigrated {
+        account: account.key(),
+        version: ClaimData::VERSION,
+    });
+
+    Ok(())
+}
+
+pub fn migrate_user_preferences(ctx: Context<MigrateUserPreferences>) -> Result<()> {
+    let account = &ctx.accounts.preferences;
+    require!(
+        !is_versioned::<UserPreferences>(account, |preferences| {
+            UserPreferences::space(preferences.preferred_mints.len())
+        }),
+        GovernanceRewardsError::AccountAlreadyMigrated
+    );
+
+    let mut preferences = UserPreferences::from(UserPreferencesV0::try_from_account(account)?);
+    preferences.bump = ctx.bumps["preferences"];
+    write_migrated(
+        account,
+        &ctx.accounts.payer,
+        &ctx.accounts.system_program,
+        UserPreferences::space(preferences.preferred_mints.len()),
+        &preferences,
+    )?;
+
+    emit!(AccountMigrated {
+        account: account.key(),
+        version: UserPreferences::VERSION,
+    });
+
+    Ok(())
+}
+
+/**
+ * Fills in the amounts paid and reclaimed from each option of a legacy distribution, from
+ * the balances of `wallets`.
+ *
+ * Legacy distributions could only reclaim an option's unused rewards, so every other
+ * token which left its wallet was paid out to a claimant.
+ */
+fn record_legacy_payouts<'info>(
+    distribution: &mut Distribution,
+    wallets: &[AccountInfo<'info>],
+) -> Result<()> {
+    let indexes: Vec<u8> = (0..distribution.distribution_options.len() as u8)
+        .filter(|index| distribution.distribution_options.get_option(*index).is_ok())
+        .collect();
+    require!(
+        wallets.len() == indexes.len(),
+        GovernanceRewardsError::MissingOptionWallets
+    );
+
+    for (index, wallet) in indexes.into_iter().zip(wallets) {
+        let option = distribution.distribution_options.get_option(index)?;
+        require!(
+            wallet.key() == option.wallet,
+            GovernanceRewardsError::MissingOptionWallets
+        );
+        let balance = Account::<TokenAccount>::try_from(wallet)?.amount;
+
+        let reclaimed = if option.extra_reclaimed {
+            distribution.calculate_unused_rewards(option)?
+        } else {
+            0
+        };
+        let paid = option
+            .total_amount
+            .saturating_sub(balance)
+            .saturating_sub(reclaimed);
+
+        let option = distribution.distribution_options.get_option_mut(index)?;
+        option.total_paid = paid;
+        option.total_reclaimed = reclaimed;
+    }
+    Ok(())
+}
+
+/**
+ * Whether `account` holds the current layout, i.e. was written since accounts were
+ * versioned.
+ *
+ * Legacy accounts are smaller than the current layout, so one which happens to parse
+ * with it is not mistaken for a versioned account.
+ */
+fn is_versioned<T: AccountSerialize + AccountDeserialize + Owner + Clone>(
+    account: &AccountInfo,
+    space: impl Fn(&T) -> usize,
+) -> bool {
+    Account::<T>::try_from(account)
+        .map_or(false, |versioned| account.data_len() >= space(&versioned))
+}
+
+/**
+ * Grows `account` to `space` and overwrites it with `migrated`.
+ */
+fn write_migrated<'info, T: AccountSerialize>(
+    account: &AccountInfo<'info>,
+    payer: &Signer<'info>,
+    system_program: &Program<'info, System>,
+    space: usize,
+    migrated: &T,
+) -> Result<()> {
+    grow_account(
+        account,
+        &payer.to_account_info(),
+        &system_program.to_account_info(),
+        space,
+    )?;
+
+    let mut data = account.try_borrow_mut_data()?;
+    data.fill(0);
+    let mut writer: &mut [u8] = &mut data;
+    migrated.try_serialize(&mut writer)
+}
@@ -1,13 +1,26 @@
 pub mod claim;
+pub mod claim_many;
+pub mod claim_to_governance;
+pub mod crank_claims;
+pub mod crank_fee;
 pub mod create_distribution;
+pub mod deregister;
 pub mod escrow;
+pub mod migrate;
 pub mod preferences;
 pub mod reclaim;
 pub mod register;
+pub mod switch_option;
 
 pub use claim::*;
+pub use claim_many::*;
+pub use claim_to_governance::*;
+pub use crank_claims::*;
 pub use create_distribution::*;
+pub use deregister::*;
 pub use escrow::*;
+pub use migrate::*;
 pub use preferences::*;
 pub use reclaim::*;
 pub use register::*;
+pub use switch_option::*;
@@ -1,5 +1,7 @@
+pub mod set_distribution_preferences;
 pub mod set_preferred_mint;
 pub mod set_resolution_preference;
 
+pub use set_distribution_preferences::*;
 pub use set_preferred_mint::*;
 pub use set_resolution_preference::*;
@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    events::DistributionPreferencesChanged,
+    state::{
+        distribution::Distribution, distribution_preferences::DistributionPreferences,
+        preferences::ResolutionPreference,
+    },
+    tools::resize_account,
+};
+
+/**
+ * Instruction to override the user's realm-wide preferences for a single distribution.
+ *
+ * Preferences left as `None` fall back to the realm-wide `UserPreferences`.
+ */
+#[derive(Accounts)]
+pub struct SetDistributionPreferences<'info> {
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = DistributionPreferences::SPACE,
+        seeds = [distribution.key().as_ref(), b"distribution preferences".as_ref(), user.key().as_ref()],
+        bump
+    )]
+    distribution_preferences: Account<'info, DistributionPreferences>,
+
+    distribution: Box<Account<'info, Distribution>>,
+
+    #[account(mut)]
+    user: Signer<'info>,
+
+    system_program: Program<'info, System>,
+}
+
+pub fn set_distribution_preferences(
+    ctx: Context<SetDistributionPreferences>,
+    preferred_mint: Option<Pubkey>,
+    resolution_preference: Option<ResolutionPreference>,
+) -> Result<()> {
+    // Accounts created before the largest preference variant existed may need to grow.
+    resize_account(
+        &ctx.accounts.distribution_preferences.to_account_info(),
+        &ctx.accounts.user.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        DistributionPreferences::SPACE,
+    )?;
+
+    ctx.accounts
+        .distribution_preferences
+        .set_inner(DistributionPreferences {
+            preferred_mint,
+            resolution_preference,
+        });
+
+    emit!(DistributionPreferencesChanged {
+        user: ctx.accounts.user.key(),
+        distribution: ctx.accounts.distribution.key(),
+        preferred_mint,
+        resolution_preference,
+    });
+    Ok(())
+}
@@ -1,13 +1,18 @@
 use anchor_lang::prelude::*;
 
-use crate::state::preferences::UserPreferences;
+use crate::{
+    error::GovernanceRewardsError,
+    events::PreferencesChanged,
+    state::preferences::{UserPreferences, MAX_PREFERRED_MINTS},
+    tools::resize_account,
+};
 
 #[derive(Accounts)]
 pub struct SetPreferredMint<'info> {
     #[account(
         init_if_needed,
         payer = user,
-        space = 8 + std::mem::size_of::<UserPreferences>(),
+        space = UserPreferences::space(0),
         seeds = [realm.key().as_ref(), b"preferences".as_ref(), user.key().as_ref()],
         bump
     )]
@@ -26,6 +31,40 @@ pub fn set_preferred_mint(
     ctx: Context<SetPreferredMint>,
     new_preference: Option<Pubkey>,
 ) -> Result<()> {
-    ctx.accounts.preferences.preferred_mint = new_preference;
+    set_preferred_mints(ctx, new_preference.into_iter().collect())
+}
+
+/**
+ * Sets the user's ranked list of preferred mints.
+ *
+ * When registering, the highest ranked mint offered by the distribution is chosen.
+ * The preferences account is resized to fit the new list.
+ */
+pub fn set_preferred_mints(
+    ctx: Context<SetPreferredMint>,
+    new_preferences: Vec<Pubkey>,
+) -> Result<()> {
+    require!(
+        new_preferences.len() <= MAX_PREFERRED_MINTS,
+        GovernanceRewardsError::TooManyPreferredMints
+    );
+
+    resize_account(
+        &ctx.accounts.preferences.to_account_info(),
+        &ctx.accounts.user.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        UserPreferences::space(new_preferences.len()),
+    )?;
+
+    ctx.accounts.preferences.preferred_mints = new_preferences;
+    ctx.accounts.preferences.version = UserPreferences::VERSION;
+    ctx.accounts.preferences.bump = ctx.bumps["preferences"];
+
+    emit!(PreferencesChanged {
+        user: ctx.accounts.user.key(),
+        realm: ctx.accounts.realm.key(),
+        preferred_mints: ctx.accounts.preferences.preferred_mints.clone(),
+        resolution_preference: ctx.accounts.preferences.resolution_preference,
+    });
     Ok(())
 }
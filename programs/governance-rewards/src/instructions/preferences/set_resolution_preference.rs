@@ -1,13 +1,17 @@
 use anchor_lang::prelude::*;
 
-use crate::state::preferences::{ResolutionPreference, UserPreferences};
+use crate::{
+    events::PreferencesChanged,
+    state::preferences::{ResolutionPreference, UserPreferences},
+    tools::resize_account,
+};
 
 #[derive(Accounts)]
 pub struct SetResolutionPreference<'info> {
     #[account(
         init_if_needed,
         payer = user,
-        space = 8 + std::mem::size_of::<UserPreferences>(),
+        space = UserPreferences::space(0),
         seeds = [realm.key().as_ref(), b"preferences".as_ref(), user.key().as_ref()],
         bump
     )]
@@ -26,6 +30,23 @@ pub fn set_resolution_preference(
     ctx: Context<SetResolutionPreference>,
     new_preference: ResolutionPreference,
 ) -> Result<()> {
+    // Accounts created before the largest preference variant existed may need to grow.
+    resize_account(
+        &ctx.accounts.preferences.to_account_info(),
+        &ctx.accounts.user.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        UserPreferences::space(ctx.accounts.preferences.preferred_mints.len()),
+    )?;
+
     ctx.accounts.preferences.resolution_preference = new_preference;
+    ctx.accounts.preferences.version = UserPreferences::VERSION;
+    ctx.accounts.preferences.bump = ctx.bumps["preferences"];
+
+    emit!(PreferencesChanged {
+        user: ctx.accounts.user.key(),
+        realm: ctx.accounts.realm.key(),
+        preferred_mints: ctx.accounts.preferences.preferred_mints.clone(),
+        resolution_preference: ctx.accounts.preferences.resolution_preference,
+    });
     Ok(())
 }
@@ -1,5 +1,7 @@
 pub mod reclaim_funds;
 pub mod reclaim_user_data;
+pub mod sweep_funds;
 
 pub use reclaim_funds::*;
 pub use reclaim_user_data::*;
+pub use sweep_funds::*;
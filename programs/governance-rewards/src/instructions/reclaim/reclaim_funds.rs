@@ -2,7 +2,8 @@ use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Token, TokenAccount};
 
 use crate::{
-    distribution_payout_seeds, error::GovernanceRewardsError, state::distribution::Distribution,
+    distribution_payout_seeds, error::GovernanceRewardsError, events::FundsReclaimed,
+    state::distribution::Distribution,
 };
 
 #[derive(Accounts)]
@@ -19,7 +20,10 @@ pub struct ReclaimFunds<'info> {
     distribution: Box<Account<'info, Distribution>>,
 
     /// CHECK: Not read
-    #[account(seeds = [b"payout authority".as_ref(), distribution.key().as_ref()], bump)]
+    #[account(
+        seeds = [b"payout authority".as_ref(), distribution.key().as_ref()],
+        bump = distribution.payout_authority_bump
+    )]
     pub payout_authority: AccountInfo<'info>,
 
     token_program: Program<'info, Token>,
@@ -42,7 +46,7 @@ impl<'info> ReclaimFunds<'info> {
 
 pub fn reclaim_funds(ctx: Context<ReclaimFunds>) -> Result<()> {
     require!(
-        !ctx.accounts.distribution.can_register(),
+        !ctx.accounts.distribution.can_register()?,
         GovernanceRewardsError::CannotReclaimFundsYet
     );
 
@@ -63,15 +67,27 @@ pub fn reclaim_funds(ctx: Context<ReclaimFunds>) -> Result<()> {
         *mut_option
     };
 
-    let reclaimable_funds = ctx.accounts.distribution.calculate_unused_rewards(option);
+    let reclaimable_funds = ctx.accounts.distribution.calculate_unused_rewards(option)?;
+    ctx.accounts
+        .distribution
+        .distribution_options
+        .by_wallet(option.wallet)
+        .ok_or(GovernanceRewardsError::NoMatchingOption)?
+        .record_reclaimed(reclaimable_funds)?;
 
     token::transfer(
         ctx.accounts
             .transfer_context()
-            .with_signer(distribution_payout_seeds!(
-                ctx.accounts.distribution,
-                ctx.bumps
-            )),
+            .with_signer(distribution_payout_seeds!(ctx.accounts.distribution)),
         reclaimable_funds,
-    )
+    )?;
+
+    emit!(FundsReclaimed {
+        distribution: ctx.accounts.distribution.key(),
+        from: ctx.accounts.from.key(),
+        to: ctx.accounts.to.key(),
+        amount: reclaimable_funds,
+    });
+
+    Ok(())
 }
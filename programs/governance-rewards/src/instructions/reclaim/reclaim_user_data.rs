@@ -1,39 +1,87 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token::Token;
 
 use crate::{
     error::GovernanceRewardsError,
+    events::UserDataReclaimed,
+    instructions::crank_fee::pay_crank_fee,
     state::{claim_data::ClaimData, distribution::Distribution},
 };
 
+/**
+ * Instruction to close a user's claim data once every claim on the distribution has
+ * been paid out.
+ *
+ * Anyone may call this. Rent is refunded to the account which paid for the claim
+ * data, and if `caller` is not the owner of the claim the distribution's crank fee is
+ * paid to `caller`. For token fees the remaining accounts must be the wallet of the fee
+ * option followed by a token account to receive the fee.
+ */
 #[derive(Accounts)]
 pub struct ReclaimUserData<'info> {
-    distribution: Account<'info, Distribution>,
+    #[account(mut)]
+    distribution: Box<Account<'info, Distribution>>,
 
     #[account(
         mut,
-        close = caller,
+        has_one = payer @ GovernanceRewardsError::WrongRentPayer,
+        close = payer,
     )]
     claim_data: Account<'info, ClaimData>,
 
+    /**
+     * Account which paid rent for the claim data.
+     */
+    /// CHECK: Checked against claim data
+    #[account(mut)]
+    payer: AccountInfo<'info>,
+
     /// CHECK: Not read
-    caller: AccountInfo<'info>,
+    #[account(
+        seeds = [b"payout authority".as_ref(), distribution.key().as_ref()],
+        bump = distribution.payout_authority_bump
+    )]
+    payout_authority: AccountInfo<'info>,
+
+    /**
+     * Receives lamport crank fees.
+     */
+    #[account(mut)]
+    caller: Signer<'info>,
+
+    token_program: Program<'info, Token>,
 }
 
-pub fn reclaim_user_data(ctx: Context<ReclaimUserData>) -> Result<()> {
+pub fn reclaim_user_data<'info>(
+    ctx: Context<'_, '_, '_, 'info, ReclaimUserData<'info>>,
+) -> Result<()> {
+    let payout_authority_bump = ctx.accounts.distribution.payout_authority_bump;
     require!(
         ctx.accounts.claim_data.distribution == ctx.accounts.distribution.key(),
         GovernanceRewardsError::WrongDistributionForClaim
     );
 
     require!(
-        ctx.accounts.distribution.fully_claimed(),
+        ctx.accounts.distribution.fully_claimed()?,
         GovernanceRewardsError::CannotCleanUpYet
     );
 
-    require!(
-        ctx.accounts.claim_data.belongs_to == ctx.accounts.caller.key(),
-        GovernanceRewardsError::CannotCleanUpAfterOtherUser,
-    );
+    if ctx.accounts.claim_data.belongs_to != ctx.accounts.caller.key() {
+        pay_crank_fee(
+            &mut ctx.accounts.distribution,
+            &ctx.accounts.caller.to_account_info(),
+            ctx.remaining_accounts,
+            &ctx.accounts.payout_authority,
+            payout_authority_bump,
+            &ctx.accounts.token_program.to_account_info(),
+        )?;
+    }
+
+    emit!(UserDataReclaimed {
+        distribution: ctx.accounts.distribution.key(),
+        user: ctx.accounts.claim_data.belongs_to,
+        rent_recipient: ctx.accounts.payer.key(),
+    });
 
     Ok(())
 }
@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount};
+
+use crate::{
+    distribution_payout_seeds, error::GovernanceRewardsError, events::FundsReclaimed,
+    state::distribution::Distribution,
+};
+
+/**
+ * Instruction to return what is left in an option wallet to the admin once every
+ * claim has been paid out.
+ *
+ * This collects the rounding dust left behind by `calculate_rewards`, along with any
+ * unused rewards, after checking the wallet balance reconciles with the option's
+ * funding, payouts and reclaims. The part of the crank fee reserve not yet paid out
+ * stays in the wallet, so crankers reclaiming user data can still be paid.
+ */
+#[derive(Accounts)]
+pub struct SweepFunds<'info> {
+    admin: Signer<'info>,
+
+    #[account(mut)]
+    from: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    to: Account<'info, TokenAccount>,
+
+    #[account(mut, has_one = admin @ GovernanceRewardsError::AdminOnly)]
+    distribution: Box<Account<'info, Distribution>>,
+
+    /// CHECK: Not read
+    #[account(
+        seeds = [b"payout authority".as_ref(), distribution.key().as_ref()],
+        bump = distribution.payout_authority_bump
+    )]
+    pub payout_authority: AccountInfo<'info>,
+
+    token_program: Program<'info, Token>,
+}
+
+impl<'info> SweepFunds<'info> {
+    pub fn transfer_context(&self) -> CpiContext<'_, '_, '_, 'info, token::Transfer<'info>> {
+        let token_program = self.token_program.to_account_info();
+
+        let accounts = token::Transfer {
+            from: self.from.to_account_info(),
+            to: self.to.to_account_info(),
+            authority: self.payout_authority.to_account_info(),
+        };
+        CpiContext::new(token_program, accounts)
+    }
+}
+
+pub fn sweep_funds(ctx: Context<SweepFunds>) -> Result<()> {
+    require!(
+        ctx.accounts.distribution.fully_claimed()?,
+        GovernanceRewardsError::CannotSweepYet
+    );
+
+    let balance = ctx.accounts.from.amount;
+    let amount = {
+        let distribution = &mut ctx.accounts.distribution;
+        let option = *distribution
+            .distribution_options
+            .by_wallet(ctx.accounts.from.key())
+            .ok_or(GovernanceRewardsError::NoMatchingOption)?;
+        let amount = balance.saturating_sub(distribution.unpaid_crank_fee_reserve(option)?);
+
+        let option = distribution
+            .distribution_options
+            .by_wallet(ctx.accounts.from.key())
+            .ok_or(GovernanceRewardsError::NoMatchingOption)?;
+        option.reconcile(balance)?;
+        option.record_reclaimed(amount)?;
+        option.extra_reclaimed = true;
+        amount
+    };
+
+    token::transfer(
+        ctx.accounts
+            .transfer_context()
+            .with_signer(distribution_payout_seeds!(ctx.accounts.distribution)),
+        amount,
+    )?;
+
+    emit!(FundsReclaimed {
+        distribution: ctx.accounts.distribution.key(),
+        from: ctx.accounts.from.key(),
+        to: ctx.accounts.to.key(),
+        amount,
+    });
+
+    Ok(())
+}
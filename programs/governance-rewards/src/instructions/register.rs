@@ -1,8 +1,9 @@
 use anchor_lang::prelude::*;
-use std::mem::size_of;
 
 use crate::state::{addin::VoterWeightRecord, claim_data::ClaimData, distribution::Distribution};
-use crate::{error::GovernanceRewardsError, state::preferences::UserPreferences};
+use crate::{
+    error::GovernanceRewardsError, events::Registered, state::preferences::UserPreferences,
+};
 
 /**
  * Instruction to register for rewards.
@@ -35,10 +36,23 @@ pub struct RegisterForRewards<'info> {
     /// CHECK: Manually deserialized
     #[account(
         seeds = [distribution.realm.as_ref(), b"preferences".as_ref(), registrant.key().as_ref()],
-        bump
+        bump = UserPreferences::find_bump(&preferences, distribution.realm, registrant.key())
     )]
     preferences: AccountInfo<'info>,
 
+    /**
+     * User claim preferences for this distribution.
+     *
+     * This account does not have the be initialized when it is passed to the program.
+     * If initialized, it takes priority over the realm-wide preferences.
+     */
+    /// CHECK: Manually deserialized
+    #[account(
+        seeds = [distribution.key().as_ref(), b"distribution preferences".as_ref(), registrant.key().as_ref()],
+        bump
+    )]
+    distribution_preferences: AccountInfo<'info>,
+
     /**
      * Claim data account.
      *
@@ -46,7 +60,7 @@ pub struct RegisterForRewards<'info> {
      */
     #[account(
         init_if_needed,
-        space = 8 + size_of::<ClaimData>(),
+        space = ClaimData::SPACE,
         payer = payer,
         seeds = [distribution.key().as_ref(), b"claim data".as_ref(), registrant.key().as_ref()],
         bump
@@ -71,58 +85,50 @@ pub fn register_for_rewards(ctx: Context<RegisterForRewards>) -> Result<()> {
         voter_weight_record.validate(&ctx.accounts.distribution, &ctx.accounts.registrant.key())?;
 
     require!(
-        ctx.accounts.distribution.can_register(),
+        ctx.accounts.distribution.can_register()?,
         GovernanceRewardsError::RegistrationOver
     );
 
     let weight = voter_weight_record.voter_weight;
     require!(weight > 0, GovernanceRewardsError::NoVoteWeight);
 
-    ctx.accounts.distribution.total_vote_weight = ctx
-        .accounts
-        .distribution
-        .total_vote_weight
-        .checked_add(weight)
-        .unwrap();
+    let preferences = UserPreferences::get_for_distribution(
+        &ctx.accounts.preferences,
+        &ctx.accounts.distribution_preferences,
+    )?;
 
-    let preferences = UserPreferences::get_or_default(&ctx.accounts.preferences);
-
-    let (index, preferred_distribution_option) = ctx
+    let (index, _) = ctx
         .accounts
         .distribution
         .distribution_options
-        .pick_by_mint(preferences.preferred_mint)?;
-
-    preferred_distribution_option.total_vote_weight = preferred_distribution_option
-        .total_vote_weight
-        .checked_add(weight)
-        .unwrap();
-
-    if ctx.accounts.claim_data.has_registered {
-        let old_weight = ctx.accounts.claim_data.weight;
-        msg!(&format!("{:}", ctx.accounts.claim_data.claim_option));
-        msg!("---------------------------------");
+        .pick_by_mint(&preferences.preferred_mints)?;
 
-        // Remove vote weight from total
-        ctx.accounts.distribution.total_vote_weight = ctx
+    let mut payer = ctx.accounts.payer.key();
+    let is_new = !ctx.accounts.claim_data.has_registered;
+    let (old_weight, old_option) = (
+        ctx.accounts.claim_data.weight,
+        ctx.accounts.claim_data.claim_option,
+    );
+    if is_new {
+        ctx.accounts.distribution.registrant_count = ctx
             .accounts
             .distribution
-            .total_vote_weight
-            .checked_sub(old_weight)
-            .unwrap();
-
-        // Remove vote weight from old distribution option
-        let old_option = &mut ctx.accounts.distribution.distribution_options
-            [ctx.accounts.claim_data.claim_option as usize]
-            .unwrap();
-        old_option.total_vote_weight = old_option
-            .total_vote_weight
-            .checked_sub(old_weight)
-            .unwrap();
-        ctx.accounts.distribution.distribution_options
-            [ctx.accounts.claim_data.claim_option as usize] = Some(*old_option);
+            .registrant_count
+            .checked_add(1)
+            .ok_or(GovernanceRewardsError::MathOverflow)?;
+        ctx.accounts
+            .distribution
+            .assert_crank_fee_reserve_funded()?;
+    } else {
+        payer = ctx.accounts.claim_data.payer;
+
+        ctx.accounts
+            .distribution
+            .remove_weight(old_option, old_weight)?;
     }
 
+    ctx.accounts.distribution.add_weight(index, weight)?;
+
     ctx.accounts.claim_data.set_inner(ClaimData {
         weight,
         distribution: ctx.accounts.distribution.key(),
@@ -130,6 +136,20 @@ pub fn register_for_rewards(ctx: Context<RegisterForRewards>) -> Result<()> {
         has_claimed: false,
         has_registered: true,
         belongs_to: ctx.accounts.registrant.key(),
+        payer,
+        version: ClaimData::VERSION,
+        bump: ctx.bumps["claim_data"],
+        reserved: [0; 31],
+    });
+
+    emit!(Registered {
+        distribution: ctx.accounts.distribution.key(),
+        registrant: ctx.accounts.registrant.key(),
+        is_new,
+        old_weight: if is_new { 0 } else { old_weight },
+        new_weight: weight,
+        old_option: if is_new { index } else { old_option },
+        new_option: index,
     });
 
     Ok(())
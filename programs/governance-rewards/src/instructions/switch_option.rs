@@ -0,0 +1,96 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::GovernanceRewardsError,
+    events::OptionSwitched,
+    state::{claim_data::ClaimData, distribution::Distribution, preferences::UserPreferences},
+};
+
+/**
+ * Instruction to re-pick a registrant's distribution option from their current preferences.
+ *
+ * The registered weight is moved between options without re-validating it, so no voter
+ * weight record is required. May only be called while registration is open.
+ */
+#[derive(Accounts)]
+pub struct SwitchOption<'info> {
+    #[account(mut)]
+    distribution: Box<Account<'info, Distribution>>,
+
+    #[account(
+        mut,
+        seeds = [distribution.key().as_ref(), b"claim data".as_ref(), registrant.key().as_ref()],
+        bump = claim_data.bump
+    )]
+    claim_data: Account<'info, ClaimData>,
+
+    /**
+     * User claim preferences.
+     *
+     * This account does not have the be initialized when it is passed to the program.
+     * If an empty account is provided, default preferences will be used.
+     */
+    /// CHECK: Manually deserialized
+    #[account(
+        seeds = [distribution.realm.as_ref(), b"preferences".as_ref(), registrant.key().as_ref()],
+        bump = UserPreferences::find_bump(&preferences, distribution.realm, registrant.key())
+    )]
+    preferences: AccountInfo<'info>,
+
+    /**
+     * User claim preferences for this distribution.
+     *
+     * This account does not have the be initialized when it is passed to the program.
+     * If initialized, it takes priority over the realm-wide preferences.
+     */
+    /// CHECK: Manually deserialized
+    #[account(
+        seeds = [distribution.key().as_ref(), b"distribution preferences".as_ref(), registrant.key().as_ref()],
+        bump
+    )]
+    distribution_preferences: AccountInfo<'info>,
+
+    /**
+     * User whose registration is updated.
+     *
+     * Anyone may call this instruction, as it only applies the registrant's own preferences.
+     */
+    /// CHECK: Not read
+    registrant: AccountInfo<'info>,
+}
+
+pub fn switch_option(ctx: Context<SwitchOption>) -> Result<()> {
+    require!(
+        ctx.accounts.distribution.can_register()?,
+        GovernanceRewardsError::RegistrationOver
+    );
+
+    let preferences = UserPreferences::get_for_distribution(
+        &ctx.accounts.preferences,
+        &ctx.accounts.distribution_preferences,
+    )?;
+
+    let (index, _) = ctx
+        .accounts
+        .distribution
+        .distribution_options
+        .pick_by_mint(&preferences.preferred_mints)?;
+
+    let old_option = ctx.accounts.claim_data.claim_option;
+    if index != old_option {
+        let weight = ctx.accounts.claim_data.weight;
+        ctx.accounts
+            .distribution
+            .move_weight(old_option, index, weight)?;
+        ctx.accounts.claim_data.claim_option = index;
+
+        emit!(OptionSwitched {
+            distribution: ctx.accounts.distribution.key(),
+            registrant: ctx.accounts.registrant.key(),
+            old_option,
+            new_option: index,
+        });
+    }
+
+    Ok(())
+}
@@ -1,9 +1,13 @@
-use crate::state::preferences::ResolutionPreference;
+use crate::state::{
+    crank_fee::CrankFee, preferences::ResolutionPreference, release_schedule::ReleaseLimit,
+};
 use instructions::*;
 
 pub mod error;
+pub mod events;
 pub mod instructions;
 pub mod state;
+pub mod tools;
 
 use anchor_lang::prelude::*;
 
@@ -13,30 +17,73 @@ declare_id!("Fg6PaFpoGXkYsidMpWTK6W2BeZ7FEfcYkg476zPFsLnS");
 pub mod governance_rewards {
     use super::*;
 
-    pub fn claim(ctx: Context<Claim>) -> Result<()> {
+    pub fn claim<'info>(ctx: Context<'_, '_, '_, 'info, Claim<'info>>) -> Result<()> {
         instructions::claim(ctx)
     }
 
+    pub fn claim_many<'info>(ctx: Context<'_, '_, '_, 'info, ClaimMany<'info>>) -> Result<()> {
+        instructions::claim_many(ctx)
+    }
+
+    pub fn claim_to_governance<'info>(
+        ctx: Context<'_, '_, '_, 'info, ClaimToGovernance<'info>>,
+    ) -> Result<()> {
+        instructions::claim_to_governance(ctx)
+    }
+
+    pub fn crank_claims<'info>(ctx: Context<'_, '_, '_, 'info, CrankClaims<'info>>) -> Result<()> {
+        instructions::crank_claims(ctx)
+    }
+
     pub fn create_distribution(
         ctx: Context<CreateDistribution>,
         registration_cutoff: u64,
         registrar: Option<Pubkey>,
+        crank_fee: Option<CrankFee>,
     ) -> Result<()> {
-        instructions::create_distribution(ctx, registration_cutoff, registrar)
+        instructions::create_distribution(ctx, registration_cutoff, registrar, crank_fee)
     }
 
     pub fn reclaim_funds(ctx: Context<ReclaimFunds>) -> Result<()> {
         instructions::reclaim_funds(ctx)
     }
 
-    pub fn reclaim_user_data(ctx: Context<ReclaimUserData>) -> Result<()> {
+    pub fn reclaim_user_data<'info>(
+        ctx: Context<'_, '_, '_, 'info, ReclaimUserData<'info>>,
+    ) -> Result<()> {
         instructions::reclaim_user_data(ctx)
     }
 
+    pub fn sweep_funds(ctx: Context<SweepFunds>) -> Result<()> {
+        instructions::sweep_funds(ctx)
+    }
+
+    pub fn migrate_distribution<'info>(
+        ctx: Context<'_, '_, '_, 'info, MigrateDistribution<'info>>,
+    ) -> Result<()> {
+        instructions::migrate_distribution(ctx)
+    }
+
+    pub fn migrate_claim_data(ctx: Context<MigrateClaimData>) -> Result<()> {
+        instructions::migrate_claim_data(ctx)
+    }
+
+    pub fn migrate_user_preferences(ctx: Context<MigrateUserPreferences>) -> Result<()> {
+        instructions::migrate_user_preferences(ctx)
+    }
+
     pub fn register(ctx: Context<RegisterForRewards>) -> Result<()> {
         instructions::register_for_rewards(ctx)
     }
 
+    pub fn deregister(ctx: Context<Deregister>) -> Result<()> {
+        instructions::deregister(ctx)
+    }
+
+    pub fn switch_option(ctx: Context<SwitchOption>) -> Result<()> {
+        instructions::switch_option(ctx)
+    }
+
     pub fn set_preferred_mint(
         ctx: Context<SetPreferredMint>,
         new_preference: Option<Pubkey>,
@@ -44,6 +91,13 @@ pub mod governance_rewards {
         instructions::set_preferred_mint(ctx, new_preference)
     }
 
+    pub fn set_preferred_mints(
+        ctx: Context<SetPreferredMint>,
+        new_preferences: Vec<Pubkey>,
+    ) -> Result<()> {
+        instructions::set_preferred_mints(ctx, new_preferences)
+    }
+
     pub fn set_resolution_preference(
         ctx: Context<SetResolutionPreference>,
         new_preference: ResolutionPreference,
@@ -51,11 +105,92 @@ pub mod governance_rewards {
         instructions::set_resolution_preference(ctx, new_preference)
     }
 
+    pub fn set_distribution_preferences(
+        ctx: Context<SetDistributionPreferences>,
+        preferred_mint: Option<Pubkey>,
+        resolution_preference: Option<ResolutionPreference>,
+    ) -> Result<()> {
+        instructions::set_distribution_preferences(ctx, preferred_mint, resolution_preference)
+    }
+
     pub fn create_escrow(ctx: Context<CreateEscrow>) -> Result<()> {
         instructions::create_escrow(ctx)
     }
 
+    pub fn deposit_from_escrow(ctx: Context<DepositFromEscrow>, amount: u64) -> Result<()> {
+        instructions::deposit_from_escrow(ctx, amount)
+    }
+
     pub fn transfer_from_escrow(ctx: Context<TransferFromEscrow>, amount: u64) -> Result<()> {
         instructions::transfer_from_escrow(ctx, amount)
     }
+
+    pub fn transfer_from_escrow_with_consent(
+        ctx: Context<TransferFromEscrowWithConsent>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::transfer_from_escrow_with_consent(ctx, amount)
+    }
+
+    pub fn close_escrow(ctx: Context<CloseEscrow>, unlock_ts: Option<u64>) -> Result<()> {
+        instructions::close_escrow(ctx, unlock_ts)
+    }
+
+    pub fn create_timelocked_escrow(
+        ctx: Context<CreateTimelockedEscrow>,
+        unlock_ts: u64,
+    ) -> Result<()> {
+        instructions::create_timelocked_escrow(ctx, unlock_ts)
+    }
+
+    pub fn release_timelocked_escrow(
+        ctx: Context<ReleaseTimelockedEscrow>,
+        unlock_ts: u64,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::release_timelocked_escrow(ctx, unlock_ts, amount)
+    }
+
+    pub fn set_early_release_policy(
+        ctx: Context<SetEarlyReleasePolicy>,
+        unlock_ts: u64,
+        penalty_basis_points: u16,
+    ) -> Result<()> {
+        instructions::set_early_release_policy(ctx, unlock_ts, penalty_basis_points)
+    }
+
+    pub fn release_timelocked_escrow_early(
+        ctx: Context<ReleaseTimelockedEscrowEarly>,
+        unlock_ts: u64,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::release_timelocked_escrow_early(ctx, unlock_ts, amount)
+    }
+
+    pub fn set_escrow_release_schedule(
+        ctx: Context<SetEscrowReleaseSchedule>,
+        limit: ReleaseLimit,
+    ) -> Result<()> {
+        instructions::set_escrow_release_schedule(ctx, limit)
+    }
+
+    pub fn create_escrow_admin_config(
+        ctx: Context<CreateEscrowAdminConfig>,
+        signers: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        instructions::create_escrow_admin_config(ctx, signers, threshold)
+    }
+
+    pub fn propose_escrow_release(ctx: Context<ProposeEscrowRelease>, amount: u64) -> Result<()> {
+        instructions::propose_escrow_release(ctx, amount)
+    }
+
+    pub fn approve_escrow_release(ctx: Context<ApproveEscrowRelease>) -> Result<()> {
+        instructions::approve_escrow_release(ctx)
+    }
+
+    pub fn execute_escrow_release(ctx: Context<ExecuteEscrowRelease>) -> Result<()> {
+        instructions::execute_escrow_release(ctx)
+    }
 }
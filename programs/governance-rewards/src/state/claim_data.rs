@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use std::mem::size_of;
 
 use super::{distribution::Distribution, distribution_option::DistributionOption};
 
@@ -11,18 +12,50 @@ pub struct ClaimData {
     pub has_claimed: bool,
     pub has_registered: bool,
     pub belongs_to: Pubkey,
+    /// Account which paid rent for this claim data, and is refunded when it is closed early.
+    pub payer: Pubkey,
+    /// Layout version. Accounts created before versioning read as 0.
+    pub version: u8,
+    /// Canonical bump of this account's address.
+    pub bump: u8,
+    /// Space for fields added in later versions.
+    pub reserved: [u8; 31],
 }
 
 impl ClaimData {
-    pub fn chosen_option(&self, distribution: &Distribution) -> DistributionOption {
-        distribution.distribution_options[self.claim_option as usize].unwrap()
+    /// Current layout version. Older accounts are upgraded with `migrate_claim_data`.
+    pub const VERSION: u8 = 1;
+
+    pub const SPACE: usize = 8 + size_of::<ClaimData>();
+
+    pub fn chosen_option(&self, distribution: &Distribution) -> Result<DistributionOption> {
+        distribution
+            .distribution_options
+            .get_option(self.claim_option)
     }
 
     pub fn get_address(user: Pubkey, distribution: Pubkey) -> Pubkey {
+        Self::find_address(user, distribution).0
+    }
+
+    pub fn find_address(user: Pubkey, distribution: Pubkey) -> (Pubkey, u8) {
         Pubkey::find_program_address(
             &[distribution.as_ref(), b"claim data".as_ref(), user.as_ref()],
             &crate::id(),
         )
-        .0
+    }
+
+    /// Address of this claim data, using the stored bump.
+    pub fn address(&self) -> Result<Pubkey> {
+        Pubkey::create_program_address(
+            &[
+                self.distribution.as_ref(),
+                b"claim data".as_ref(),
+                self.belongs_to.as_ref(),
+                &[self.bump],
+            ],
+            &crate::id(),
+        )
+        .map_err(|_| ErrorCode::ConstraintSeeds.into())
     }
 }
@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+/**
+ * Tip paid to a caller who cranks `claim` or `reclaim_user_data` on behalf of another
+ * user.
+ */
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CrankFee {
+    /**
+     * Paid from the lamports held by the distribution account above its rent exempt
+     * minimum. If the distribution cannot cover the fee, no fee is paid.
+     */
+    Lamports { amount: u64 },
+
+    /**
+     * Paid from the rewards wallet of the distribution option at index `option`.
+     *
+     * Enough tokens to pay every possible fee are held back from that option's rewards.
+     */
+    Tokens { option: u8, amount: u64 },
+}
+
+impl CrankFee {
+    /// Most fees that can be paid per registrant: one for `claim`, one for `reclaim_user_data`.
+    pub const MAX_FEES_PER_REGISTRANT: u64 = 2;
+
+    pub fn amount(&self) -> u64 {
+        match self {
+            CrankFee::Lamports { amount } | CrankFee::Tokens { amount, .. } => *amount,
+        }
+    }
+}
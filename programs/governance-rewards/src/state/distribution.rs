@@ -1,6 +1,12 @@
 use anchor_lang::prelude::*;
+use std::mem::size_of;
 
-use super::distribution_option::{DistributionOption, DistributionOptions};
+use crate::error::GovernanceRewardsError;
+
+use super::{
+    crank_fee::CrankFee,
+    distribution_option::{DistributionOption, DistributionOptions},
+};
 
 #[account]
 #[derive(Debug, PartialEq, Eq)]
@@ -13,61 +19,210 @@ pub struct Distribution {
     pub total_vote_weight_claimed: u64,
     pub distribution_options: DistributionOptions,
     pub admin: Pubkey,
+    pub crank_fee: Option<CrankFee>,
+    /// Number of claim data accounts registered, used to size the crank fee reserve.
+    pub registrant_count: u64,
+    /// Total crank fees paid so far, denominated in the units of `crank_fee`.
+    pub crank_fees_paid: u64,
+    /// Layout version. Accounts created before versioning read as 0.
+    pub version: u8,
+    /// Canonical bump of the payout authority.
+    pub payout_authority_bump: u8,
+    /// Space for fields added in later versions.
+    pub reserved: [u8; 63],
 }
 
 impl Distribution {
-    pub fn can_register(&self) -> bool {
-        let time = Clock::get().unwrap().unix_timestamp as u64;
+    /// Current layout version. Older accounts are upgraded with `migrate_distribution`.
+    pub const VERSION: u8 = 1;
+
+    pub const SPACE: usize = 8 + size_of::<Distribution>();
 
-        time < self.registration_period_end_ts
+    pub fn can_register(&self) -> Result<bool> {
+        Ok(self.can_register_at(now()?))
     }
 
-    pub fn can_claim(&self) -> bool {
-        !self.can_register() && !self.fully_claimed()
+    pub fn can_claim(&self) -> Result<bool> {
+        Ok(self.can_claim_at(now()?))
     }
 
-    pub fn fully_claimed(&self) -> bool {
-        self.total_vote_weight_claimed >= self.total_vote_weight && !self.can_register()
+    pub fn fully_claimed(&self) -> Result<bool> {
+        Ok(self.fully_claimed_at(now()?))
     }
 
-    pub fn calculate_rewards(&self, option: DistributionOption, weight: u64) -> u64 {
-        u64::try_from(
-            self.calculate_total_rewards(option)
-                .checked_mul(weight as u128)
-                .unwrap()
-                .checked_div(option.total_vote_weight as u128)
-                .unwrap(),
-        )
-        .unwrap()
+    /// Whether registration is open at unix time `now`.
+    pub fn can_register_at(&self, now: u64) -> bool {
+        now < self.registration_period_end_ts
+    }
+
+    pub fn can_claim_at(&self, now: u64) -> bool {
+        !self.can_register_at(now) && !self.fully_claimed_at(now)
+    }
+
+    pub fn fully_claimed_at(&self, now: u64) -> bool {
+        self.total_vote_weight_claimed >= self.total_vote_weight && !self.can_register_at(now)
+    }
+
+    /// Adds a registration's weight to the distribution and its chosen option.
+    pub fn add_weight(&mut self, option: u8, weight: u64) -> Result<()> {
+        self.total_vote_weight = self
+            .total_vote_weight
+            .checked_add(weight)
+            .ok_or(GovernanceRewardsError::MathOverflow)?;
+
+        let option = self.distribution_options.get_option_mut(option)?;
+        option.total_vote_weight = option
+            .total_vote_weight
+            .checked_add(weight)
+            .ok_or(GovernanceRewardsError::MathOverflow)?;
+        Ok(())
+    }
+
+    /// Removes a registration's weight from the distribution and its chosen option.
+    pub fn remove_weight(&mut self, option: u8, weight: u64) -> Result<()> {
+        self.total_vote_weight = self
+            .total_vote_weight
+            .checked_sub(weight)
+            .ok_or(GovernanceRewardsError::MathOverflow)?;
+
+        let option = self.distribution_options.get_option_mut(option)?;
+        option.total_vote_weight = option
+            .total_vote_weight
+            .checked_sub(weight)
+            .ok_or(GovernanceRewardsError::MathOverflow)?;
+        Ok(())
+    }
+
+    /// Moves a registration's weight between options without changing the total.
+    pub fn move_weight(&mut self, from: u8, to: u8, weight: u64) -> Result<()> {
+        self.remove_weight(from, weight)?;
+        self.add_weight(to, weight)
+    }
+
+    /**
+     * Tokens of `option` held back to pay crank fees, which are not distributed as
+     * rewards.
+     */
+    pub fn crank_fee_reserve(&self, option: DistributionOption) -> Result<u64> {
+        match self.crank_fee {
+            Some(CrankFee::Tokens {
+                option: fee_option,
+                amount,
+            }) if matches!(
+                self.distribution_options.get_option(fee_option),
+                Ok(fee) if fee.wallet == option.wallet
+            ) =>
+            {
+                amount
+                    .checked_mul(CrankFee::MAX_FEES_PER_REGISTRANT)
+                    .and_then(|reserve| reserve.checked_mul(self.registrant_count))
+                    .ok_or_else(|| GovernanceRewardsError::MathOverflow.into())
+            }
+            _ => Ok(0),
+        }
     }
 
-    pub fn calculate_unused_rewards(&self, option: DistributionOption) -> u64 {
-        option
+    /**
+     * Part of the crank fee reserve of `option` not yet paid out to crankers. This stays
+     * in the option wallet until every registrant's data has been reclaimed.
+     */
+    pub fn unpaid_crank_fee_reserve(&self, option: DistributionOption) -> Result<u64> {
+        Ok(self
+            .crank_fee_reserve(option)?
+            .saturating_sub(self.crank_fees_paid))
+    }
+
+    /**
+     * Checks the crank fee reserve is covered by the funding of the option paying crank
+     * fees, so that registrants are not left with no rewards.
+     */
+    pub fn assert_crank_fee_reserve_funded(&self) -> Result<()> {
+        if let Some(CrankFee::Tokens { option, .. }) = self.crank_fee {
+            let fee_option = self.distribution_options.get_option(option)?;
+            require!(
+                self.crank_fee_reserve(fee_option)? <= fee_option.total_amount,
+                GovernanceRewardsError::CrankFeeReserveExceedsFunding
+            );
+        }
+        Ok(())
+    }
+
+    pub fn calculate_rewards(&self, option: DistributionOption, weight: u64) -> Result<u64> {
+        if option.total_vote_weight == 0 {
+            return Ok(0);
+        }
+
+        self.calculate_total_rewards(option)?
+            .checked_mul(weight as u128)
+            .and_then(|rewards| rewards.checked_div(option.total_vote_weight as u128))
+            .and_then(|rewards| u64::try_from(rewards).ok())
+            .ok_or_else(|| GovernanceRewardsError::MathOverflow.into())
+    }
+
+    pub fn calculate_unused_rewards(&self, option: DistributionOption) -> Result<u64> {
+        let total_rewards = u64::try_from(self.calculate_total_rewards(option)?)
+            .map_err(|_| GovernanceRewardsError::MathOverflow)?;
+
+        Ok(option
             .total_amount
-            .checked_sub(u64::try_from(self.calculate_total_rewards(option)).unwrap())
-            .unwrap_or_default()
+            .saturating_sub(self.crank_fee_reserve(option)?)
+            .checked_sub(total_rewards)
+            .unwrap_or_default())
     }
 
-    fn calculate_total_rewards(&self, option: DistributionOption) -> u128 {
-        (option.total_amount as u128)
+    /**
+     * Tokens of `option` owed to its registrants. An option nobody picked, or a
+     * distribution nobody registered for, owes nothing, so all of its funds are
+     * reclaimable.
+     */
+    fn calculate_total_rewards(&self, option: DistributionOption) -> Result<u128> {
+        if self.total_vote_weight == 0 || option.total_vote_weight == 0 {
+            return Ok(0);
+        }
+
+        (option
+            .total_amount
+            .saturating_sub(self.crank_fee_reserve(option)?) as u128)
             .checked_mul(option.total_vote_weight as u128)
-            .unwrap()
-            .checked_div(self.total_vote_weight as u128)
-            .unwrap()
+            .and_then(|rewards| rewards.checked_div(self.total_vote_weight as u128))
+            .ok_or_else(|| GovernanceRewardsError::MathOverflow.into())
     }
 
     pub fn get_payout_authority(key: Pubkey) -> Pubkey {
-        Pubkey::find_program_address(&[b"payout authority".as_ref(), key.as_ref()], &crate::id()).0
+        Self::find_payout_authority(key).0
+    }
+
+    /// Payout authority of the distribution at `key`, using the stored bump.
+    pub fn payout_authority(&self, key: Pubkey) -> Result<Pubkey> {
+        Pubkey::create_program_address(
+            &[
+                b"payout authority".as_ref(),
+                key.as_ref(),
+                &[self.payout_authority_bump],
+            ],
+            &crate::id(),
+        )
+        .map_err(|_| ErrorCode::ConstraintSeeds.into())
     }
+
+    pub fn find_payout_authority(key: Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[b"payout authority".as_ref(), key.as_ref()], &crate::id())
+    }
+}
+
+fn now() -> Result<u64> {
+    Ok(Clock::get()
+        .map_err(|_| GovernanceRewardsError::ClockUnavailable)?
+        .unix_timestamp as u64)
 }
 
 #[macro_export]
 macro_rules! distribution_payout_seeds {
-    ( $distribution: expr, $bumps: expr ) => {
+    ( $distribution: expr ) => {
         &[&[
             b"payout authority".as_ref(),
             $distribution.key().as_ref(),
-            &[$bumps["payout_authority"]],
+            &[$distribution.payout_authority_bump],
         ]]
     };
 }
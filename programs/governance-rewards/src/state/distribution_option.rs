@@ -12,6 +12,10 @@ pub struct DistributionOption {
     pub extra_reclaimed: bool,
     pub mint: Pubkey,
     pub wallet: Pubkey,
+    /// Tokens paid out of `wallet` as rewards or crank fees.
+    pub total_paid: u64,
+    /// Tokens returned to the admin from `wallet` by `reclaim_funds` or `sweep_funds`.
+    pub total_reclaimed: u64,
 }
 
 impl DistributionOption {
@@ -27,29 +31,87 @@ impl DistributionOption {
             total_vote_weight: 0,
             total_amount: token_account.amount,
             extra_reclaimed: false,
+            total_paid: 0,
+            total_reclaimed: 0,
         })
     }
+
+    pub fn record_paid(&mut self, amount: u64) -> Result<()> {
+        self.total_paid = self
+            .total_paid
+            .checked_add(amount)
+            .ok_or(GovernanceRewardsError::MathOverflow)?;
+        Ok(())
+    }
+
+    pub fn record_reclaimed(&mut self, amount: u64) -> Result<()> {
+        self.total_reclaimed = self
+            .total_reclaimed
+            .checked_add(amount)
+            .ok_or(GovernanceRewardsError::MathOverflow)?;
+        Ok(())
+    }
+
+    /**
+     * Checks the wallet's `balance` against the option's accounting, so that
+     * `total_paid + total_reclaimed + balance == total_amount`.
+     *
+     * Tokens sent straight to the wallet after the distribution was created are counted
+     * as extra funding. A shortfall means tokens left the wallet without being recorded.
+     */
+    pub fn reconcile(&mut self, balance: u64) -> Result<()> {
+        let expected = self
+            .total_amount
+            .checked_sub(self.total_paid)
+            .and_then(|remaining| remaining.checked_sub(self.total_reclaimed))
+            .ok_or(GovernanceRewardsError::ReconciliationFailed)?;
+        require!(
+            balance >= expected,
+            GovernanceRewardsError::ReconciliationFailed
+        );
+
+        self.total_amount = self
+            .total_amount
+            .checked_add(balance - expected)
+            .ok_or(GovernanceRewardsError::MathOverflow)?;
+        Ok(())
+    }
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, Copy, Debug, PartialEq, Eq)]
 pub struct DistributionOptions([Option<DistributionOption>; 8]);
 
 impl DistributionOptions {
-    pub fn pick_by_mint(&mut self, key: Option<Pubkey>) -> Result<(u8, &mut DistributionOption)> {
-        let mut first_valid_mint: Option<(u8, &mut DistributionOption)> = None;
-        for (i, option) in self.iter_mut().enumerate() {
-            if let Some(option) = option {
-                if key.is_none() || key == Some(option.mint) {
-                    return Ok((i as u8, option));
-                }
-                first_valid_mint.get_or_insert((i as u8, option));
-            }
-        }
+    /**
+     * Picks the option for the highest ranked mint in `preferred_mints`.
+     *
+     * Falls back to the first available option if none of the preferred mints are offered.
+     */
+    pub fn pick_by_mint(&self, preferred_mints: &[Pubkey]) -> Result<(u8, DistributionOption)> {
+        let index = preferred_mints
+            .iter()
+            .find_map(|mint| {
+                self.iter()
+                    .position(|option| matches!(option, Some(option) if option.mint == *mint))
+            })
+            .or_else(|| self.iter().position(Option::is_some))
+            .ok_or(GovernanceRewardsError::NoDistributionOptions)?;
 
-        if let Some(default) = first_valid_mint {
-            return Ok(default);
-        }
-        Err(GovernanceRewardsError::NoDistributionOptions.into())
+        Ok((index as u8, self.get_option(index as u8)?))
+    }
+
+    /// Returns the option at `index`, if there is one.
+    pub fn get_option(&self, index: u8) -> Result<DistributionOption> {
+        self.get(index as usize)
+            .copied()
+            .flatten()
+            .ok_or_else(|| GovernanceRewardsError::InvalidOptionIndex.into())
+    }
+
+    pub fn get_option_mut(&mut self, index: u8) -> Result<&mut DistributionOption> {
+        self.get_mut(index as usize)
+            .and_then(Option::as_mut)
+            .ok_or_else(|| GovernanceRewardsError::InvalidOptionIndex.into())
     }
 
     pub fn by_wallet(&mut self, wallet: Pubkey) -> Option<&mut DistributionOption> {
@@ -64,7 +126,10 @@ impl DistributionOptions {
             .map(|acct| DistributionOption::try_from_account(acct, authority).map(Some))
             .collect::<Result<Vec<_>>>()?;
         options.resize(8, None);
-        Ok(DistributionOptions(options.try_into().unwrap()))
+        let options: [Option<DistributionOption>; 8] = options
+            .try_into()
+            .map_err(|_| GovernanceRewardsError::InvalidOptionIndex)?;
+        Ok(DistributionOptions(options))
     }
 
     pub fn empty() -> Self {
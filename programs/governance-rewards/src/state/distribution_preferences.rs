@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use super::preferences::ResolutionPreference;
+
+/**
+ * Preferences which apply to a single distribution.
+ *
+ * Any preference left unset falls back to the realm-wide `UserPreferences`.
+ */
+#[account]
+#[derive(Default, Debug)]
+pub struct DistributionPreferences {
+    pub preferred_mint: Option<Pubkey>,
+    pub resolution_preference: Option<ResolutionPreference>,
+}
+
+impl DistributionPreferences {
+    pub const SPACE: usize = 8 + (1 + 32) + (1 + ResolutionPreference::SPACE);
+
+    pub fn get_or_default(account: &AccountInfo) -> Self {
+        Account::<DistributionPreferences>::try_from(account)
+            .map(|acct| acct.into_inner())
+            .unwrap_or_default()
+    }
+
+    pub fn get_address(user: Pubkey, distribution: Pubkey) -> Pubkey {
+        Pubkey::find_program_address(
+            &[
+                distribution.as_ref(),
+                b"distribution preferences".as_ref(),
+                user.as_ref(),
+            ],
+            &crate::id(),
+        )
+        .0
+    }
+}
@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use super::release_schedule::MAX_BASIS_POINTS;
+
+/**
+ * Lets a user release their timelocked escrow before it unlocks, forfeiting
+ * `penalty_basis_points` of the release to `penalty_account`. Stored at a PDA of the
+ * escrow address and set by the escrow release admin.
+ */
+#[account]
+#[derive(Default, Debug)]
+pub struct EarlyReleasePolicy {
+    pub penalty_basis_points: u16,
+    /// Token account of the escrow's mint, designated by the DAO, receiving penalties.
+    pub penalty_account: Pubkey,
+}
+
+impl EarlyReleasePolicy {
+    pub const SPACE: usize = 8 + 2 + 32;
+
+    pub fn get_address(escrow: Pubkey) -> Pubkey {
+        Pubkey::find_program_address(
+            &[escrow.as_ref(), b"early release policy".as_ref()],
+            &crate::id(),
+        )
+        .0
+    }
+
+    /**
+     * Penalty forfeited by releasing `amount` early, rounded up so that splitting a
+     * release into small chunks cannot avoid it.
+     */
+    pub fn penalty(&self, amount: u64) -> u64 {
+        let max_basis_points = MAX_BASIS_POINTS as u128;
+        ((amount as u128 * self.penalty_basis_points as u128 + max_basis_points - 1)
+            / max_basis_points) as u64
+    }
+}
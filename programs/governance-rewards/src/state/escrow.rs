@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+
+use super::preferences::{find_escrow_address, find_timelocked_escrow_address};
+
+/**
+ * Bookkeeping for an escrow token account, stored at a PDA of the escrow address.
+ *
+ * Escrows created before this account existed have no state.
+ */
+#[account]
+#[derive(Default, Debug)]
+pub struct EscrowState {
+    /// Account which paid rent for the escrow, refunded when it is closed.
+    pub payer: Pubkey,
+    /// Canonical bump of this account's address.
+    pub bump: u8,
+    /// Canonical bump of the realm's escrow owner.
+    pub escrow_owner_bump: u8,
+    /// Canonical bump of the escrow's address.
+    pub escrow_bump: u8,
+}
+
+impl EscrowState {
+    pub const SPACE: usize = 8 + 32 + 1 + 1 + 1;
+
+    pub fn get_address(escrow: Pubkey) -> Pubkey {
+        Self::find_address(escrow).0
+    }
+
+    pub fn find_address(escrow: Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[escrow.as_ref(), b"escrow state".as_ref()], &crate::id())
+    }
+
+    /// Bump of the state of `escrow`, read from `account` when it stores one.
+    pub fn find_bump(account: &AccountInfo, escrow: Pubkey) -> u8 {
+        Account::<EscrowState>::try_from(account)
+            .map(|state| state.bump)
+            .unwrap_or_else(|_| Self::find_address(escrow).1)
+    }
+
+    /// Bump of the escrow owner of `realm`, read from `account` when it stores one.
+    pub fn find_escrow_owner_bump(account: &AccountInfo, realm: Pubkey) -> u8 {
+        Account::<EscrowState>::try_from(account)
+            .map(|state| state.escrow_owner_bump)
+            .unwrap_or_else(|_| find_escrow_owner(realm).1)
+    }
+
+    /**
+     * Bump of the escrow, read from `account` when it stores one. `unlock_ts` is only set
+     * for timelocked escrows, whose address also depends on it.
+     */
+    pub fn find_escrow_bump(
+        account: &AccountInfo,
+        realm: Pubkey,
+        escrow_admin: Pubkey,
+        user: Pubkey,
+        mint: Pubkey,
+        unlock_ts: Option<u64>,
+    ) -> u8 {
+        Account::<EscrowState>::try_from(account)
+            .map(|state| state.escrow_bump)
+            .unwrap_or_else(|_| match unlock_ts {
+                Some(unlock_ts) => {
+                    find_timelocked_escrow_address(realm, escrow_admin, user, mint, unlock_ts).1
+                }
+                None => find_escrow_address(realm, escrow_admin, user, mint).1,
+            })
+    }
+}
+
+pub fn find_escrow_owner(realm: Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"escrow owner".as_ref(), realm.as_ref()], &crate::ID)
+}
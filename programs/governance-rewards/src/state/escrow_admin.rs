@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+
+pub const MAX_ESCROW_ADMIN_SIGNERS: usize = 10;
+
+/// Seconds after which an escrow release proposal can no longer be approved or executed.
+pub const ESCROW_RELEASE_PROPOSAL_LIFETIME: u64 = 7 * 24 * 60 * 60;
+
+/**
+ * An M-of-N escrow release admin.
+ *
+ * Users choose the config's address as their `escrow_admin`. Since the config cannot
+ * sign, releases from its escrows go through `EscrowReleaseProposal`s instead of
+ * `transfer_from_escrow`.
+ */
+#[account]
+#[derive(Default, Debug)]
+pub struct EscrowAdminConfig {
+    pub signers: Vec<Pubkey>,
+    /// Number of signers who must approve a release.
+    pub threshold: u8,
+    /// Index of the next proposal, used to derive its address.
+    pub proposal_count: u64,
+}
+
+impl EscrowAdminConfig {
+    pub fn space(signers: usize) -> usize {
+        8 + 4 + 32 * signers + 1 + 8
+    }
+
+    pub fn has_signer(&self, key: &Pubkey) -> bool {
+        self.signers.contains(key)
+    }
+}
+
+/**
+ * A pending release of funds from an escrow administered by an `EscrowAdminConfig`.
+ *
+ * Closed when executed, refunding rent to `payer`. Expires after
+ * `ESCROW_RELEASE_PROPOSAL_LIFETIME`, so approvals gathered long ago cannot release funds.
+ */
+#[account]
+#[derive(Default, Debug)]
+pub struct EscrowReleaseProposal {
+    pub config: Pubkey,
+    pub escrow: Pubkey,
+    pub to_account: Pubkey,
+    pub amount: u64,
+    pub payer: Pubkey,
+    /// Signers of the config who have approved the release.
+    pub approvals: Vec<Pubkey>,
+    /// Unix time after which the release can no longer be approved or executed.
+    pub expires_ts: u64,
+}
+
+impl EscrowReleaseProposal {
+    pub fn space(signers: usize) -> usize {
+        8 + 32 + 32 + 32 + 8 + 32 + 4 + 32 * signers + 8
+    }
+
+    pub fn is_expired(&self, clock: &Clock) -> bool {
+        clock.unix_timestamp as u64 > self.expires_ts
+    }
+
+    pub fn get_address(config: Pubkey, index: u64) -> Pubkey {
+        Pubkey::find_program_address(
+            &[
+                config.as_ref(),
+                b"escrow release proposal".as_ref(),
+                &index.to_le_bytes(),
+            ],
+            &crate::id(),
+        )
+        .0
+    }
+}
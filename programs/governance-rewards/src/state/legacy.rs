@@ -0,0 +1,193 @@
+//! Layouts of accounts created before accounts were versioned.
+//!
+//! They match the accounts already on chain byte for byte, and share the discriminator
+//! of the current account type. The `migrate_*` instructions read them to upgrade old
+//! accounts in place, filling in fields added since.
+
+use anchor_lang::{prelude::*, Discriminator};
+use std::io::Write;
+
+use super::{
+    claim_data::ClaimData,
+    distribution::Distribution,
+    distribution_option::{DistributionOption, DistributionOptions},
+    preferences::{ResolutionPreference, UserPreferences},
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, Debug, PartialEq, Eq)]
+pub struct DistributionOptionV0 {
+    pub total_vote_weight: u64,
+    pub total_amount: u64,
+    pub extra_reclaimed: bool,
+    pub mint: Pubkey,
+    pub wallet: Pubkey,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct DistributionV0 {
+    pub registration_period_end_ts: u64,
+    pub voter_weight_program: Pubkey,
+    pub realm: Pubkey,
+    pub registrar: Option<Pubkey>,
+    pub total_vote_weight: u64,
+    pub total_vote_weight_claimed: u64,
+    pub distribution_options: [Option<DistributionOptionV0>; 8],
+    pub admin: Pubkey,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct ClaimDataV0 {
+    pub weight: u64,
+    pub distribution: Pubkey,
+    pub claim_option: u8,
+    pub has_claimed: bool,
+    pub has_registered: bool,
+    pub belongs_to: Pubkey,
+}
+
+/**
+ * Preferences with a single preferred mint. Only the `Wallet` and `Escrow` resolution
+ * preferences existed, which serialize the same as today.
+ */
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct UserPreferencesV0 {
+    pub preferred_mint: Option<Pubkey>,
+    pub resolution_preference: ResolutionPreference,
+}
+
+impl DistributionV0 {
+    pub fn try_from_account(account: &AccountInfo) -> Result<Self> {
+        read_legacy::<Distribution, _>(account)
+    }
+}
+
+impl ClaimDataV0 {
+    pub fn try_from_account(account: &AccountInfo) -> Result<Self> {
+        read_legacy::<ClaimData, _>(account)
+    }
+}
+
+impl UserPreferencesV0 {
+    pub fn try_from_account(account: &AccountInfo) -> Result<Self> {
+        read_legacy::<UserPreferences, _>(account)
+    }
+}
+
+impl From<DistributionOptionV0> for DistributionOption {
+    /**
+     * Amounts paid and reclaimed are left at 0, since only the option's wallet balance
+     * tells them apart. `migrate_distribution` fills them in.
+     */
+    fn from(legacy: DistributionOptionV0) -> Self {
+        Self {
+            total_vote_weight: legacy.total_vote_weight,
+            total_amount: legacy.total_amount,
+            extra_reclaimed: legacy.extra_reclaimed,
+            mint: legacy.mint,
+            wallet: legacy.wallet,
+            total_paid: 0,
+            total_reclaimed: 0,
+        }
+    }
+}
+
+impl From<DistributionV0> for Distribution {
+    fn from(legacy: DistributionV0) -> Self {
+        let mut distribution_options = DistributionOptions::empty();
+        for (option, legacy_option) in distribution_options
+            .iter_mut()
+            .zip(legacy.distribution_options)
+        {
+            *option = legacy_option.map(DistributionOption::from);
+        }
+
+        Self {
+            registration_period_end_ts: legacy.registration_period_end_ts,
+            voter_weight_program: legacy.voter_weight_program,
+            realm: legacy.realm,
+            registrar: legacy.registrar,
+            total_vote_weight: legacy.total_vote_weight,
+            total_vote_weight_claimed: legacy.total_vote_weight_claimed,
+            distribution_options,
+            admin: legacy.admin,
+            crank_fee: None,
+            registrant_count: 0,
+            crank_fees_paid: 0,
+            version: Distribution::VERSION,
+            payout_authority_bump: 0,
+            reserved: [0; 63],
+        }
+    }
+}
+
+impl From<ClaimDataV0> for ClaimData {
+    fn from(legacy: ClaimDataV0) -> Self {
+        Self {
+            weight: legacy.weight,
+            distribution: legacy.distribution,
+            claim_option: legacy.claim_option,
+            has_claimed: legacy.has_claimed,
+            has_registered: legacy.has_registered,
+            belongs_to: legacy.belongs_to,
+            // Legacy claim data did not record who paid its rent, so it is refunded to the user.
+            payer: legacy.belongs_to,
+            version: ClaimData::VERSION,
+            bump: 0,
+            reserved: [0; 31],
+        }
+    }
+}
+
+impl From<UserPreferencesV0> for UserPreferences {
+    fn from(legacy: UserPreferencesV0) -> Self {
+        Self {
+            preferred_mints: legacy.preferred_mint.into_iter().collect(),
+            resolution_preference: legacy.resolution_preference,
+            ..Default::default()
+        }
+    }
+}
+
+impl AccountSerialize for DistributionV0 {
+    fn try_serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        write_legacy::<Distribution, _, _>(self, writer)
+    }
+}
+
+impl AccountSerialize for ClaimDataV0 {
+    fn try_serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        write_legacy::<ClaimData, _, _>(self, writer)
+    }
+}
+
+impl AccountSerialize for UserPreferencesV0 {
+    fn try_serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        write_legacy::<UserPreferences, _, _>(self, writer)
+    }
+}
+
+fn read_legacy<T: Discriminator, V: AnchorDeserialize>(account: &AccountInfo) -> Result<V> {
+    if account.owner != &crate::id() {
+        return Err(ErrorCode::AccountOwnedByWrongProgram.into());
+    }
+
+    let data = account.try_borrow_data()?;
+    if data.len() < 8 || data[..8] != T::discriminator() {
+        return Err(ErrorCode::AccountDiscriminatorMismatch.into());
+    }
+
+    V::deserialize(&mut &data[8..]).map_err(|_| ErrorCode::AccountDidNotDeserialize.into())
+}
+
+fn write_legacy<T: Discriminator, V: AnchorSerialize, W: Write>(
+    legacy: &V,
+    writer: &mut W,
+) -> Result<()> {
+    writer
+        .write_all(&T::discriminator())
+        .map_err(|_| ErrorCode::AccountDidNotSerialize)?;
+    legacy
+        .serialize(writer)
+        .map_err(|_| ErrorCode::AccountDidNotSerialize)?;
+    Ok(())
+}
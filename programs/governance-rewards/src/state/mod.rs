@@ -1,6 +1,13 @@
 pub mod addin;
 pub mod claim_data;
+pub mod crank_fee;
 pub mod distribution;
 pub mod distribution_option;
+pub mod distribution_preferences;
+pub mod early_release;
+pub mod escrow;
+pub mod escrow_admin;
+pub mod legacy;
 pub mod preferences;
+pub mod release_schedule;
 pub mod total_rewards;
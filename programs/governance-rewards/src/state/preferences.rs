@@ -1,17 +1,57 @@
 use anchor_lang::prelude::*;
 use anchor_spl::associated_token::get_associated_token_address;
+use spl_governance::state::token_owner_record::get_token_owner_record_address;
+
+use super::{distribution_preferences::DistributionPreferences, legacy::UserPreferencesV0};
+
+/// Maximum number of mints a user may rank in their preferences.
+pub const MAX_PREFERRED_MINTS: usize = 8;
 
 #[account]
-#[derive(Default, Copy, Debug)]
+#[derive(Debug)]
 pub struct UserPreferences {
-    pub preferred_mint: Option<Pubkey>,
+    /// Mints the user would like to be paid in, from most to least preferred.
+    pub preferred_mints: Vec<Pubkey>,
     pub resolution_preference: ResolutionPreference,
+    /// Layout version. Accounts created before versioning read as 0.
+    pub version: u8,
+    /// Canonical bump of this account's address.
+    pub bump: u8,
+    /// Space for fields added in later versions.
+    pub reserved: [u8; 31],
+}
+
+impl Default for UserPreferences {
+    fn default() -> Self {
+        Self {
+            preferred_mints: vec![],
+            resolution_preference: ResolutionPreference::default(),
+            version: Self::VERSION,
+            bump: 0,
+            reserved: [0; 31],
+        }
+    }
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone, Debug)]
 pub enum ResolutionPreference {
     Wallet,
-    Escrow { escrow_admin: Pubkey },
+    Escrow {
+        escrow_admin: Pubkey,
+    },
+    /// Escrow which the user may also release themselves once `unlock_ts` has passed.
+    Timelock {
+        escrow_admin: Pubkey,
+        unlock_ts: u64,
+    },
+    /**
+     * Deposit into the user's `TokenOwnerRecord` with `governance_program`, so rewards
+     * in a realm's governing token count as voting power. Claimed with
+     * `claim_to_governance`.
+     */
+    GovernanceDeposit {
+        governance_program: Pubkey,
+    },
 }
 
 impl Default for ResolutionPreference {
@@ -21,38 +61,172 @@ impl Default for ResolutionPreference {
 }
 
 impl ResolutionPreference {
+    /// Serialized size of the largest variant.
+    pub const SPACE: usize = 1 + 32 + 8;
+
     pub fn payout_address(&self, user: Pubkey, mint: Pubkey, realm: Pubkey) -> Pubkey {
         match self {
             ResolutionPreference::Wallet => get_associated_token_address(&user, &mint),
             ResolutionPreference::Escrow { escrow_admin } => {
-                Pubkey::find_program_address(
-                    &[
-                        realm.as_ref(),
-                        escrow_admin.as_ref(),
-                        b"escrow".as_ref(),
-                        user.as_ref(),
-                        mint.as_ref(),
-                    ],
-                    &crate::ID,
-                )
-                .0
+                find_escrow_address(realm, *escrow_admin, user, mint).0
+            }
+            ResolutionPreference::Timelock {
+                escrow_admin,
+                unlock_ts,
+            } => find_timelocked_escrow_address(realm, *escrow_admin, user, mint, *unlock_ts).0,
+            ResolutionPreference::GovernanceDeposit { governance_program } => {
+                get_token_owner_record_address(governance_program, &realm, &mint, &user)
             }
         }
     }
 }
 
+/**
+ * Finds the escrow token account for a user and mint, along with its bump seed.
+ */
+pub fn find_escrow_address(
+    realm: Pubkey,
+    escrow_admin: Pubkey,
+    user: Pubkey,
+    mint: Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            realm.as_ref(),
+            escrow_admin.as_ref(),
+            b"escrow".as_ref(),
+            user.as_ref(),
+            mint.as_ref(),
+        ],
+        &crate::ID,
+    )
+}
+
+/**
+ * Finds the timelocked escrow token account for a user and mint, along with its bump
+ * seed. The seeds are those of the plain escrow followed by `unlock_ts` in little endian.
+ */
+pub fn find_timelocked_escrow_address(
+    realm: Pubkey,
+    escrow_admin: Pubkey,
+    user: Pubkey,
+    mint: Pubkey,
+    unlock_ts: u64,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            realm.as_ref(),
+            escrow_admin.as_ref(),
+            b"escrow".as_ref(),
+            user.as_ref(),
+            mint.as_ref(),
+            &unlock_ts.to_le_bytes(),
+        ],
+        &crate::ID,
+    )
+}
+
 impl UserPreferences {
-    pub fn get_or_default(account: &AccountInfo) -> Self {
+    /// Current layout version. Older accounts are upgraded with `migrate_user_preferences`.
+    pub const VERSION: u8 = 1;
+
+    /// Account size required to store `preferred_mints` ranked mints.
+    pub fn space(preferred_mints: usize) -> usize {
+        8 + 4 + 32 * preferred_mints + ResolutionPreference::SPACE + 1 + 1 + 31
+    }
+
+    /**
+     * Bump of the preferences PDA for `user` in `realm`.
+     *
+     * Read from the account when it holds current preferences, so that only users
+     * without preferences pay for `find_program_address`.
+     */
+    pub fn find_bump(account: &AccountInfo, realm: Pubkey, user: Pubkey) -> u8 {
         Account::<UserPreferences>::try_from(account)
-            .map(|acct| *acct)
-            .unwrap_or_default()
+            .ok()
+            .map(|preferences| preferences.bump)
+            .unwrap_or_else(|| Self::find_address(user, realm).1)
+    }
+
+    /// Checks `account` is the preferences PDA of `user` in `realm`.
+    pub fn has_address(account: &AccountInfo, realm: Pubkey, user: Pubkey) -> bool {
+        let bump = Self::find_bump(account, realm, user);
+        Pubkey::create_program_address(
+            &[
+                realm.as_ref(),
+                b"preferences".as_ref(),
+                user.as_ref(),
+                &[bump],
+            ],
+            &crate::id(),
+        )
+        .map_or(false, |address| address == account.key())
+    }
+
+    /**
+     * Loads the user's preferences, or the defaults if they have none.
+     *
+     * Preferences which have not been migrated yet are read with their legacy layout, so
+     * they keep applying to claims in the meantime. An initialized account holding
+     * neither layout is rejected, rather than silently treated as having no preferences.
+     */
+    pub fn get_or_default(account: &AccountInfo) -> Result<Self> {
+        if account.data_is_empty() {
+            return Ok(Self::default());
+        }
+
+        Account::<UserPreferences>::try_from(account)
+            .map(|acct| acct.into_inner())
+            .or_else(|_| UserPreferencesV0::try_from_account(account).map(Self::from))
+    }
+
+    /**
+     * Loads the preferences which apply to a distribution.
+     *
+     * Preferences set for the distribution take priority over realm-wide preferences.
+     * Either account may be uninitialized, in which case it contributes nothing.
+     */
+    pub fn get_for_distribution(
+        preferences: &AccountInfo,
+        distribution_preferences: &AccountInfo,
+    ) -> Result<Self> {
+        Ok(Self::get_or_default(preferences)?.with_override(
+            &DistributionPreferences::get_or_default(distribution_preferences),
+        ))
+    }
+
+    pub fn with_override(mut self, overrides: &DistributionPreferences) -> Self {
+        if let Some(mint) = overrides.preferred_mint {
+            self.preferred_mints.insert(0, mint);
+        }
+        if let Some(resolution_preference) = overrides.resolution_preference {
+            self.resolution_preference = resolution_preference;
+        }
+        self
     }
 
     pub fn get_address(user: Pubkey, realm: Pubkey) -> Pubkey {
+        Self::find_address(user, realm).0
+    }
+
+    pub fn find_address(user: Pubkey, realm: Pubkey) -> (Pubkey, u8) {
         Pubkey::find_program_address(
             &[realm.as_ref(), b"preferences".as_ref(), user.as_ref()],
             &crate::id(),
         )
-        .0
+    }
+
+    /// Address of these preferences for `user` in `realm`, using the stored bump.
+    pub fn address(&self, user: Pubkey, realm: Pubkey) -> Result<Pubkey> {
+        Pubkey::create_program_address(
+            &[
+                realm.as_ref(),
+                b"preferences".as_ref(),
+                user.as_ref(),
+                &[self.bump],
+            ],
+            &crate::id(),
+        )
+        .map_err(|_| ErrorCode::ConstraintSeeds.into())
     }
 }
@@ -0,0 +1,123 @@
+use anchor_lang::prelude::*;
+
+use crate::error::GovernanceRewardsError;
+
+pub const MAX_BASIS_POINTS: u16 = 10_000;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq)]
+pub enum ReleaseLimit {
+    /// Release at most `amount` tokens in each `period` seconds.
+    AmountPerPeriod { amount: u64, period: u64 },
+    /// Release at most `basis_points` of the escrow's balance at the start of each epoch.
+    PercentPerEpoch { basis_points: u16 },
+}
+
+impl Default for ReleaseLimit {
+    fn default() -> Self {
+        ReleaseLimit::PercentPerEpoch {
+            basis_points: MAX_BASIS_POINTS,
+        }
+    }
+}
+
+impl ReleaseLimit {
+    pub fn is_valid(&self) -> bool {
+        match *self {
+            ReleaseLimit::AmountPerPeriod { amount, period } => amount > 0 && period > 0,
+            ReleaseLimit::PercentPerEpoch { basis_points } => {
+                basis_points > 0 && basis_points <= MAX_BASIS_POINTS
+            }
+        }
+    }
+
+    fn period(&self, clock: &Clock) -> u64 {
+        match *self {
+            ReleaseLimit::AmountPerPeriod { period, .. } => clock.unix_timestamp as u64 / period,
+            ReleaseLimit::PercentPerEpoch { .. } => clock.epoch,
+        }
+    }
+
+    fn allowance(&self, period_start_balance: u64) -> u64 {
+        match *self {
+            ReleaseLimit::AmountPerPeriod { amount, .. } => amount,
+            ReleaseLimit::PercentPerEpoch { basis_points } => {
+                (period_start_balance as u128 * basis_points as u128 / MAX_BASIS_POINTS as u128)
+                    as u64
+            }
+        }
+    }
+}
+
+/**
+ * Limits how quickly `transfer_from_escrow` and `deposit_from_escrow` may drain an
+ * escrow, stored at a PDA of the escrow address. Escrows without a schedule are
+ * unlimited.
+ *
+ * `transfer_from_escrow_with_consent` is exempt, as the user signs for it. Escrows of an
+ * `EscrowAdminConfig` cannot have a schedule, so `execute_escrow_release` never
+ * checks one.
+ */
+#[account]
+#[derive(Default, Debug)]
+pub struct EscrowReleaseSchedule {
+    pub limit: ReleaseLimit,
+    /// Index of the current period, in periods since the unix epoch or in epochs.
+    pub current_period: u64,
+    /// Escrow balance when the current period began.
+    pub period_start_balance: u64,
+    /// Amount released so far in the current period.
+    pub released_in_period: u64,
+}
+
+impl EscrowReleaseSchedule {
+    pub const SPACE: usize = 8 + (1 + 8 + 8) + 8 + 8 + 8;
+
+    pub fn get_address(escrow: Pubkey) -> Pubkey {
+        Pubkey::find_program_address(
+            &[escrow.as_ref(), b"release schedule".as_ref()],
+            &crate::id(),
+        )
+        .0
+    }
+
+    /**
+     * Replaces the limit. Within the current period, releases already made still count
+     * against the new limit, so changing it cannot reset the allowance.
+     */
+    pub fn set_limit(&mut self, limit: ReleaseLimit, escrow_balance: u64, clock: &Clock) {
+        self.limit = limit;
+        if self.limit.period(clock) == self.current_period {
+            self.period_start_balance = escrow_balance.saturating_add(self.released_in_period);
+        } else {
+            self.reset(escrow_balance, clock);
+        }
+    }
+
+    /// Starts a new period from the escrow's current balance.
+    fn reset(&mut self, escrow_balance: u64, clock: &Clock) {
+        self.current_period = self.limit.period(clock);
+        self.period_start_balance = escrow_balance;
+        self.released_in_period = 0;
+    }
+
+    /// Records a release of `amount`, failing if it exceeds the current period's allowance.
+    pub fn record_release(
+        &mut self,
+        amount: u64,
+        escrow_balance: u64,
+        clock: &Clock,
+    ) -> Result<()> {
+        if self.limit.period(clock) != self.current_period {
+            self.reset(escrow_balance, clock);
+        }
+
+        let released = self.released_in_period.saturating_add(amount);
+        require!(
+            released <= self.limit.allowance(self.period_start_balance),
+            GovernanceRewardsError::EscrowReleaseLimitExceeded
+        );
+        self.released_in_period = released;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,242 @@
+use anchor_lang::{prelude::*, solana_program::program::invoke_signed, system_program};
+use spl_governance::state::{
+    realm::get_governing_token_holding_address, token_owner_record::get_token_owner_record_address,
+};
+
+use crate::error::GovernanceRewardsError;
+
+/**
+ * Resizes a program owned account.
+ *
+ * The account is topped up from `payer` when it needs more lamports to stay rent exempt
+ * at its new size, and any lamports it no longer needs are refunded to `payer`.
+ */
+pub fn resize_account<'info>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program_info: &AccountInfo<'info>,
+    new_len: usize,
+) -> Result<()> {
+    let rent_exempt_balance = Rent::get()?.minimum_balance(new_len);
+    let current_balance = account.lamports();
+
+    if rent_exempt_balance > current_balance {
+        system_program::transfer(
+            CpiContext::new(
+                system_program_info.clone(),
+                system_program::Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            rent_exempt_balance - current_balance,
+        )?;
+    } else if current_balance > rent_exempt_balance {
+        let refund = current_balance - rent_exempt_balance;
+        **account.try_borrow_mut_lamports()? -= refund;
+        **payer.try_borrow_mut_lamports()? += refund;
+    }
+
+    account.realloc(new_len, false)?;
+    Ok(())
+}
+
+/**
+ * Grows a program owned account, topping it up from `payer` to stay rent exempt.
+ *
+ * Unlike `resize_account`, lamports above the rent exempt minimum are left in the account,
+ * since distributions hold their lamport crank fees there.
+ */
+pub fn grow_account<'info>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program_info: &AccountInfo<'info>,
+    new_len: usize,
+) -> Result<()> {
+    let rent_exempt_balance = Rent::get()?.minimum_balance(new_len);
+    let current_balance = account.lamports();
+
+    if rent_exempt_balance > current_balance {
+        system_program::transfer(
+            CpiContext::new(
+                system_program_info.clone(),
+                system_program::Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            rent_exempt_balance - current_balance,
+        )?;
+    }
+
+    account.realloc(new_len.max(account.data_len()), false)?;
+    Ok(())
+}
+
+/**
+ * Creates the account at a PDA, signing for it with `seeds`, the way anchor's `init` does.
+ *
+ * Anyone can send lamports to an address before it is created, which makes
+ * `create_account` fail. An address which already holds lamports is topped up to be rent
+ * exempt from `payer` instead, then allocated and assigned to `owner`.
+ */
+pub fn create_pda_account<'info>(
+    account: &AccountInfo<'info>,
+    seeds: &[&[u8]],
+    payer: &AccountInfo<'info>,
+    system_program_info: &AccountInfo<'info>,
+    space: usize,
+    owner: &Pubkey,
+) -> Result<()> {
+    let rent_exempt_balance = Rent::get()?.minimum_balance(space).max(1);
+    let current_balance = account.lamports();
+
+    if current_balance == 0 {
+        return system_program::create_account(
+            CpiContext::new_with_signer(
+                system_program_info.clone(),
+                system_program::CreateAccount {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+                &[seeds],
+            ),
+            rent_exempt_balance,
+            space as u64,
+            owner,
+        );
+    }
+
+    if rent_exempt_balance > current_balance {
+        system_program::transfer(
+            CpiContext::new(
+                system_program_info.clone(),
+                system_program::Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            rent_exempt_balance - current_balance,
+        )?;
+    }
+    system_program::allocate(
+        CpiContext::new_with_signer(
+            system_program_info.clone(),
+            system_program::Allocate {
+                account_to_allocate: account.clone(),
+            },
+            &[seeds],
+        ),
+        space as u64,
+    )?;
+    system_program::assign(
+        CpiContext::new_with_signer(
+            system_program_info.clone(),
+            system_program::Assign {
+                account_to_assign: account.clone(),
+            },
+            &[seeds],
+        ),
+        owner,
+    )
+}
+
+/**
+ * Closes a program owned account which may not deserialize as its current type,
+ * sending its lamports to `destination`.
+ */
+pub fn close_account<'info>(
+    account: &AccountInfo<'info>,
+    destination: &AccountInfo<'info>,
+) -> Result<()> {
+    let lamports = account.lamports();
+    **account.try_borrow_mut_lamports()? = 0;
+    **destination.try_borrow_mut_lamports()? += lamports;
+
+    account.try_borrow_mut_data()?.fill(0);
+    Ok(())
+}
+
+/**
+ * Deposits tokens into a user's `TokenOwnerRecord` with spl-governance, signing for
+ * `transfer_authority` with `transfer_authority_seeds`.
+ *
+ * spl-governance only requires the owner's signature when the record has to be created,
+ * so the record must already exist.
+ */
+#[allow(clippy::too_many_arguments)]
+pub fn deposit_governing_tokens<'info>(
+    governance_program: &AccountInfo<'info>,
+    realm: &AccountInfo<'info>,
+    governing_token_holding: &AccountInfo<'info>,
+    governing_token_source: &AccountInfo<'info>,
+    governing_token_owner: &AccountInfo<'info>,
+    transfer_authority: &AccountInfo<'info>,
+    transfer_authority_seeds: &[&[u8]],
+    token_owner_record: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program_info: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    rent: &AccountInfo<'info>,
+    mint: Pubkey,
+    amount: u64,
+) -> Result<()> {
+    require!(
+        *realm.owner == governance_program.key(),
+        GovernanceRewardsError::WrongGovernanceProgram
+    );
+    require!(
+        governing_token_holding.key()
+            == get_governing_token_holding_address(governance_program.key, realm.key, &mint),
+        GovernanceRewardsError::WrongGovernanceAccount
+    );
+    require!(
+        token_owner_record.key()
+            == get_token_owner_record_address(
+                governance_program.key,
+                realm.key,
+                &mint,
+                governing_token_owner.key
+            ),
+        GovernanceRewardsError::WrongGovernanceAccount
+    );
+    require!(
+        !token_owner_record.data_is_empty(),
+        GovernanceRewardsError::MissingTokenOwnerRecord
+    );
+
+    let mut deposit_ix = spl_governance::instruction::deposit_governing_tokens(
+        governance_program.key,
+        realm.key,
+        governing_token_source.key,
+        governing_token_owner.key,
+        transfer_authority.key,
+        payer.key,
+        amount,
+        &mint,
+    );
+    for meta in deposit_ix.accounts.iter_mut().filter(|meta| {
+        meta.pubkey == governing_token_owner.key() && !governing_token_owner.is_signer
+    }) {
+        meta.is_signer = false;
+    }
+
+    invoke_signed(
+        &deposit_ix,
+        &[
+            realm.clone(),
+            governing_token_holding.clone(),
+            governing_token_source.clone(),
+            governing_token_owner.clone(),
+            transfer_authority.clone(),
+            token_owner_record.clone(),
+            payer.clone(),
+            system_program_info.clone(),
+            token_program.clone(),
+            rent.clone(),
+            governance_program.clone(),
+        ],
+        &[transfer_authority_seeds],
+    )
+    .map_err(Error::from)
+}
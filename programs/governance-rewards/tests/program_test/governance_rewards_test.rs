@@ -8,11 +8,15 @@ use anchor_spl::token::{Mint, Token};
 use borsh::BorshSerialize;
 use governance_rewards::state::{
     addin::VoterWeightRecord,
-    claim_data::ClaimData,
+    crank_fee::CrankFee,
     distribution::Distribution,
     distribution_option::DistributionOptions,
+    distribution_preferences::DistributionPreferences,
+    escrow_admin::EscrowAdminConfig,
     preferences::{ResolutionPreference, UserPreferences},
+    release_schedule::ReleaseLimit,
 };
+use governance_rewards_client::{pda, ClaimTarget, CrankTarget};
 use solana_program::instruction::Instruction;
 use solana_program_test::{processor, ProgramTest};
 use solana_sdk::{
@@ -133,6 +137,27 @@ impl GovernanceRewardsTest {
             key,
             registration_cutoff,
             funding,
+            None,
+            NopOverride,
+            None,
+        )
+        .await
+    }
+
+    pub async fn with_crank_fee_distribution(
+        &mut self,
+        realm_cookie: &RealmCookie,
+        key: &DistributionKeyCookie,
+        registration_cutoff: u64,
+        funding: &[&TokenAccountCookie],
+        crank_fee: CrankFee,
+    ) -> Result<DistributionCookie, TransportError> {
+        self.with_distribution_using_ix(
+            realm_cookie,
+            key,
+            registration_cutoff,
+            funding,
+            Some(crank_fee),
             NopOverride,
             None,
         )
@@ -145,40 +170,25 @@ impl GovernanceRewardsTest {
         key: &DistributionKeyCookie,
         registration_cutoff: u64,
         funding: &[&TokenAccountCookie],
+        crank_fee: Option<CrankFee>,
         instruction_override: F,
         signers_override: Option<&[&Keypair]>,
     ) -> Result<DistributionCookie, TransportError> {
-        let data = anchor_lang::InstructionData::data(
-            &governance_rewards::instruction::CreateDistribution {
-                registration_cutoff,
-                registrar: None,
-            },
-        );
         let admin = Keypair::new();
+        let funding_addresses: Vec<Pubkey> = funding.iter().map(|acct| acct.address).collect();
 
-        let mut accounts = anchor_lang::ToAccountMetas::to_account_metas(
-            &governance_rewards::accounts::CreateDistribution {
-                distribution: key.keypair.pubkey(),
-                payout_authority: Distribution::get_payout_authority(key.keypair.pubkey()),
-                realm: realm_cookie.address,
-                voter_weight_program: voter_weight_program(),
-                payer: self.bench.payer.pubkey(),
-                admin: admin.pubkey(),
-                system_program: solana_sdk::system_program::id(),
-            },
+        let mut create_distribution_ix = governance_rewards_client::create_distribution(
+            key.keypair.pubkey(),
+            realm_cookie.address,
+            voter_weight_program(),
+            admin.pubkey(),
+            self.bench.payer.pubkey(),
+            &funding_addresses,
+            registration_cutoff,
             None,
+            crank_fee,
         );
 
-        for funding_source in funding {
-            accounts.push(AccountMeta::new_readonly(funding_source.address, false));
-        }
-
-        let mut create_distribution_ix = Instruction {
-            program_id: governance_rewards::id(),
-            accounts,
-            data,
-        };
-
         instruction_override(&mut create_distribution_ix);
 
         let default_signers = &[&key.keypair, &admin];
@@ -197,6 +207,12 @@ impl GovernanceRewardsTest {
             distribution_options: DistributionOptions::empty(),
             admin: admin.pubkey(),
             registrar: None,
+            crank_fee,
+            registrant_count: 0,
+            crank_fees_paid: 0,
+            version: Distribution::VERSION,
+            payout_authority_bump: Distribution::find_payout_authority(key.keypair.pubkey()).1,
+            reserved: [0; 63],
         };
 
         Ok(DistributionCookie {
@@ -230,9 +246,13 @@ impl GovernanceRewardsTest {
         realm: &RealmCookie,
         user: Pubkey,
     ) -> Result<PreferenceCookie, TransportError> {
-        let address = UserPreferences::get_address(user, realm.address);
+        let (address, bump) = UserPreferences::find_address(user, realm.address);
+        let record = UserPreferences {
+            bump,
+            ..record.clone()
+        };
         self.bench
-            .set_anchor_account(record, address, self.program_id)
+            .set_anchor_account(&record, address, self.program_id)
             .await?;
 
         Ok(PreferenceCookie {
@@ -241,6 +261,79 @@ impl GovernanceRewardsTest {
         })
     }
 
+    pub async fn with_distribution_preferences(
+        &mut self,
+        record: &DistributionPreferences,
+        distribution: &DistributionCookie,
+        user: Pubkey,
+    ) -> Result<Pubkey, TransportError> {
+        let address = pda::distribution_preferences(user, distribution.address);
+        self.bench
+            .set_anchor_account(record, address, self.program_id)
+            .await?;
+
+        Ok(address)
+    }
+
+    pub async fn set_distribution_preferences(
+        &self,
+        user: &Keypair,
+        distribution: &DistributionCookie,
+        preferred_mint: Option<Pubkey>,
+        resolution_preference: Option<ResolutionPreference>,
+    ) -> Result<Pubkey, TransportError> {
+        let set_ix = governance_rewards_client::set_distribution_preferences(
+            user.pubkey(),
+            distribution.address,
+            preferred_mint,
+            resolution_preference,
+        );
+
+        self.bench
+            .process_transaction(&[set_ix], Some(&[user]))
+            .await?;
+        Ok(pda::distribution_preferences(
+            user.pubkey(),
+            distribution.address,
+        ))
+    }
+
+    pub async fn set_preferred_mints(
+        &self,
+        user: &Keypair,
+        realm: &RealmCookie,
+        new_preferences: Vec<Pubkey>,
+    ) -> Result<Pubkey, TransportError> {
+        let set_ix = governance_rewards_client::set_preferred_mints(
+            user.pubkey(),
+            realm.address,
+            new_preferences,
+        );
+
+        self.bench
+            .process_transaction(&[set_ix], Some(&[user]))
+            .await?;
+        Ok(pda::preferences(user.pubkey(), realm.address))
+    }
+
+    pub async fn set_resolution_preference(
+        &self,
+        user: &Keypair,
+        realm: &RealmCookie,
+        new_preference: ResolutionPreference,
+    ) -> Result<Pubkey, TransportError> {
+        let set_ix = governance_rewards_client::set_resolution_preference(
+            user.pubkey(),
+            realm.address,
+            new_preference,
+        );
+
+        self.bench
+            .process_transaction(&[set_ix], Some(&[user]))
+            .await?;
+        Ok(pda::preferences(user.pubkey(), realm.address))
+    }
+
     pub async fn with_dummy_voter_weight_record(
         &mut self,
         record: &VoterWeightRecord,
@@ -270,6 +363,35 @@ impl GovernanceRewardsTest {
         .await
     }
 
+    /**
+     * Registers `user` for a distribution with `vote_weight`, through a voter weight
+     * record of a new governing token mint.
+     */
+    pub async fn with_registered_user(
+        &mut self,
+        distribution_cookie: &DistributionCookie,
+        user: Pubkey,
+        vote_weight: u64,
+    ) -> Result<VoterWeightRecordCookie, TransportError> {
+        let token_mint = self.bench.with_mint().await?;
+        let vwr = self
+            .with_dummy_voter_weight_record(
+                &VoterWeightRecord::create_test(
+                    distribution_cookie.account.realm,
+                    token_mint.address,
+                    user,
+                    distribution_cookie.address,
+                    vote_weight,
+                    Some(u64::MAX),
+                ),
+                distribution_cookie.account.voter_weight_program,
+            )
+            .await?;
+        self.with_registrant(distribution_cookie, &vwr).await?;
+
+        Ok(vwr)
+    }
+
     pub async fn with_registrant_using_ix<F: Fn(&mut Instruction)>(
         &mut self,
         distribution_cookie: &DistributionCookie,
@@ -299,6 +421,38 @@ impl GovernanceRewardsTest {
         })
     }
 
+    pub async fn deregister(
+        &self,
+        distribution: &DistributionCookie,
+        user: Pubkey,
+        authority: &Keypair,
+    ) -> Result<(), TransportError> {
+        let deregister_ix = governance_rewards_client::deregister(
+            user,
+            distribution.address,
+            self.bench.payer.pubkey(),
+            authority.pubkey(),
+        );
+
+        self.bench
+            .process_transaction(&[deregister_ix], Some(&[authority]))
+            .await
+    }
+
+    pub async fn switch_option(
+        &self,
+        distribution: &DistributionCookie,
+        user: Pubkey,
+    ) -> Result<(), TransportError> {
+        let switch_ix = governance_rewards_client::switch_option(
+            user,
+            distribution.address,
+            distribution.account.realm,
+        );
+
+        self.bench.process_transaction(&[switch_ix], None).await
+    }
+
     pub async fn claim(
         &mut self,
         user: &Keypair,
@@ -337,6 +491,7 @@ impl GovernanceRewardsTest {
             preferences
                 .resolution
                 .payout_address(user.pubkey(), mint, distribution.account.realm),
+            mint,
             self.bench.payer.pubkey(),
         );
 
@@ -351,6 +506,67 @@ impl GovernanceRewardsTest {
         Ok(())
     }
 
+    pub async fn claim_many(
+        &self,
+        user: &Keypair,
+        realm: &RealmCookie,
+        targets: &[ClaimTarget],
+    ) -> Result<(), TransportError> {
+        let claim_many_ix = governance_rewards_client::claim_many(
+            user.pubkey(),
+            realm.address,
+            targets,
+            self.bench.payer.pubkey(),
+        );
+
+        let signers = &[&self.bench.payer];
+
+        self.bench
+            .process_transaction(&[claim_many_ix], Some(signers))
+            .await
+    }
+
+    pub async fn crank_claims(
+        &self,
+        distribution_cookie: &DistributionCookie,
+        targets: &[CrankTarget],
+    ) -> Result<(), TransportError> {
+        let crank_claims_ix = governance_rewards_client::crank_claims(
+            distribution_cookie.address,
+            distribution_cookie.account.realm,
+            targets,
+            &[],
+            self.bench.payer.pubkey(),
+        );
+
+        let signers = &[&self.bench.payer];
+
+        self.bench
+            .process_transaction(&[crank_claims_ix], Some(signers))
+            .await
+    }
+
+    pub async fn reclaim_user_data(
+        &self,
+        user: Pubkey,
+        distribution_cookie: &DistributionCookie,
+        fee_accounts: &[AccountMeta],
+    ) -> Result<(), TransportError> {
+        let mut reclaim_ix = governance_rewards_client::reclaim_user_data(
+            user,
+            distribution_cookie.address,
+            self.bench.payer.pubkey(),
+            self.bench.payer.pubkey(),
+        );
+        reclaim_ix.accounts.extend_from_slice(fee_accounts);
+
+        let signers = &[&self.bench.payer];
+
+        self.bench
+            .process_transaction(&[reclaim_ix], Some(signers))
+            .await
+    }
+
     pub async fn with_escrow(
         &self,
         user: &Pubkey,
@@ -358,33 +574,40 @@ impl GovernanceRewardsTest {
         realm: &RealmCookie,
         admin: &Pubkey,
     ) -> Result<Pubkey, TransportError> {
-        let data =
-            anchor_lang::InstructionData::data(&governance_rewards::instruction::CreateEscrow {});
-        let address = ResolutionPreference::Escrow {
-            escrow_admin: *admin,
-        }
-        .payout_address(*user, *mint, realm.address);
-        let accounts = anchor_lang::ToAccountMetas::to_account_metas(
-            &governance_rewards::accounts::CreateEscrow {
-                token_program: anchor_spl::token::ID,
-                system_program: solana_sdk::system_program::id(),
-                escrow: address,
-                escrow_owner: governance_rewards::instructions::get_escrow_owner(realm.address),
-                realm: realm.address,
-                mint: *mint,
-                user: *user,
-                payer: self.bench.payer.pubkey(),
-                rent: solana_sdk::sysvar::rent::id(),
-                escrow_release_admin: *admin,
-            },
-            None,
+        let create_ix = governance_rewards_client::create_escrow(
+            *user,
+            realm.address,
+            *mint,
+            *admin,
+            self.bench.payer.pubkey(),
         );
+        let address = pda::escrow(realm.address, *admin, *user, *mint);
 
-        let create_ix = Instruction {
-            program_id: governance_rewards::id(),
-            accounts,
-            data,
-        };
+        let signers = &[&self.bench.payer];
+
+        self.bench
+            .process_transaction(&[create_ix], Some(signers))
+            .await?;
+        Ok(address)
+    }
+
+    pub async fn with_timelocked_escrow(
+        &self,
+        user: &Pubkey,
+        mint: &Pubkey,
+        realm: &RealmCookie,
+        admin: &Pubkey,
+        unlock_ts: u64,
+    ) -> Result<Pubkey, TransportError> {
+        let create_ix = governance_rewards_client::create_timelocked_escrow(
+            *user,
+            realm.address,
+            *mint,
+            *admin,
+            self.bench.payer.pubkey(),
+            unlock_ts,
+        );
+        let address = pda::timelocked_escrow(realm.address, *admin, *user, *mint, unlock_ts);
 
         let signers = &[&self.bench.payer];
 
@@ -394,6 +617,65 @@ impl GovernanceRewardsTest {
         Ok(address)
     }
 
+    #[allow(clippy::too_many_arguments)]
+    pub async fn release_timelocked_escrow(
+        &self,
+        escrow: &Pubkey,
+        user: &Pubkey,
+        realm: &RealmCookie,
+        to: &TokenAccountCookie,
+        admin: &Pubkey,
+        authority: &Keypair,
+        unlock_ts: u64,
+        amount: u64,
+    ) -> Result<(), TransportError> {
+        let release_ix = governance_rewards_client::release_timelocked_escrow(
+            *escrow,
+            *user,
+            realm.address,
+            to.mint,
+            *admin,
+            to.address,
+            authority.pubkey(),
+            unlock_ts,
+            amount,
+        );
+
+        let signers = &[&self.bench.payer, authority];
+
+        self.bench
+            .process_transaction(&[release_ix], Some(signers))
+            .await
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn close_escrow(
+        &self,
+        escrow: &Pubkey,
+        user: &Pubkey,
+        realm: &RealmCookie,
+        admin: &Pubkey,
+        rent_recipient: &Pubkey,
+        authority: &Keypair,
+        unlock_ts: Option<u64>,
+    ) -> Result<(), TransportError> {
+        let close_ix = governance_rewards_client::close_escrow(
+            *escrow,
+            *user,
+            realm.address,
+            *admin,
+            *rent_recipient,
+            authority.pubkey(),
+            unlock_ts,
+        );
+
+        let signers = &[&self.bench.payer, authority];
+
+        self.bench
+            .process_transaction(&[close_ix], Some(signers))
+            .await
+    }
+
     pub async fn transfer_from_escrow(
         &self,
         escrow: &Pubkey,
@@ -403,29 +685,15 @@ impl GovernanceRewardsTest {
         admin: &Keypair,
         amount: u64,
     ) -> Result<(), TransportError> {
-        let data = anchor_lang::InstructionData::data(
-            &governance_rewards::instruction::TransferFromEscrow { amount },
-        );
-        let accounts = anchor_lang::ToAccountMetas::to_account_metas(
-            &governance_rewards::accounts::TransferFromEscrow {
-                token_program: anchor_spl::token::ID,
-                escrow: *escrow,
-                escrow_owner: governance_rewards::instructions::get_escrow_owner(realm.address),
-                realm: realm.address,
-                mint: to.mint,
-                to_account: to.address,
-                user: user.pubkey(),
-                escrow_release_admin: admin.pubkey(),
-            },
-            None,
+        let transfer_ix = Self::transfer_from_escrow_ix(
+            escrow,
+            &user.pubkey(),
+            realm,
+            to,
+            &admin.pubkey(),
+            amount,
         );
 
-        let transfer_ix = Instruction {
-            program_id: governance_rewards::id(),
-            accounts,
-            data,
-        };
-
         let signers = &[&self.bench.payer, admin];
 
         self.bench
@@ -435,6 +703,264 @@ impl GovernanceRewardsTest {
         Ok(())
     }
 
+    pub fn transfer_from_escrow_ix(
+        escrow: &Pubkey,
+        user: &Pubkey,
+        realm: &RealmCookie,
+        to: &TokenAccountCookie,
+        admin: &Pubkey,
+        amount: u64,
+    ) -> Instruction {
+        governance_rewards_client::transfer_from_escrow(
+            *escrow,
+            *user,
+            realm.address,
+            to.mint,
+            *admin,
+            to.address,
+            amount,
+        )
+    }
+
+    pub async fn set_early_release_policy(
+        &self,
+        escrow: &Pubkey,
+        user: &Pubkey,
+        realm: &RealmCookie,
+        admin: &Keypair,
+        unlock_ts: u64,
+        penalty_account: &TokenAccountCookie,
+        penalty_basis_points: u16,
+    ) -> Result<(), TransportError> {
+        let set_ix = governance_rewards_client::set_early_release_policy(
+            *escrow,
+            *user,
+            realm.address,
+            admin.pubkey(),
+            penalty_account.address,
+            self.bench.payer.pubkey(),
+            unlock_ts,
+            penalty_basis_points,
+        );
+
+        self.bench
+            .process_transaction(&[set_ix], Some(&[admin]))
+            .await
+    }
+
+    pub async fn release_timelocked_escrow_early(
+        &self,
+        escrow: &Pubkey,
+        user: &Keypair,
+        realm: &RealmCookie,
+        to: &TokenAccountCookie,
+        penalty_account: &TokenAccountCookie,
+        admin: &Pubkey,
+        unlock_ts: u64,
+        amount: u64,
+    ) -> Result<(), TransportError> {
+        let release_ix = governance_rewards_client::release_timelocked_escrow_early(
+            *escrow,
+            user.pubkey(),
+            realm.address,
+            *admin,
+            to.address,
+            penalty_account.address,
+            unlock_ts,
+            amount,
+        );
+
+        self.bench
+            .process_transaction(&[release_ix], Some(&[user]))
+            .await
+    }
+
+    pub async fn set_escrow_release_schedule(
+        &self,
+        escrow: &Pubkey,
+        user: &Pubkey,
+        realm: &RealmCookie,
+        admin: &Keypair,
+        limit: ReleaseLimit,
+    ) -> Result<(), TransportError> {
+        let set_ix = governance_rewards_client::set_escrow_release_schedule(
+            *escrow,
+            *user,
+            realm.address,
+            admin.pubkey(),
+            self.bench.payer.pubkey(),
+            limit,
+        );
+
+        self.bench
+            .process_transaction(&[set_ix], Some(&[admin]))
+            .await
+    }
+
+    pub async fn transfer_from_escrow_with_consent(
+        &self,
+        escrow: &Pubkey,
+        user: &Keypair,
+        realm: &RealmCookie,
+        to: &TokenAccountCookie,
+        admin: &Keypair,
+        amount: u64,
+    ) -> Result<(), TransportError> {
+        let mint = self.bench.get_token_account(escrow).await.unwrap().mint;
+        let transfer_ix = governance_rewards_client::transfer_from_escrow_with_consent(
+            *escrow,
+            user.pubkey(),
+            realm.address,
+            mint,
+            admin.pubkey(),
+            to.address,
+            amount,
+        );
+
+        let signers = &[&self.bench.payer, user, admin];
+
+        self.bench
+            .process_transaction(&[transfer_ix], Some(signers))
+            .await
+    }
+
+    pub async fn claim_to_governance(
+        &self,
+        user: &Keypair,
+        rewards_account: &TokenAccountCookie,
+        distribution: &DistributionCookie,
+    ) -> Result<(), TransportError> {
+        let claim_ix = governance_rewards_client::claim_to_governance(
+            user.pubkey(),
+            distribution.address,
+            distribution.account.realm,
+            rewards_account.address,
+            rewards_account.mint,
+            self.governance.program_id,
+            self.bench.payer.pubkey(),
+        );
+
+        self.bench.process_transaction(&[claim_ix], None).await
+    }
+
+    pub async fn deposit_from_escrow(
+        &self,
+        escrow: &Pubkey,
+        user: Pubkey,
+        realm: &RealmCookie,
+        admin: &Keypair,
+        amount: u64,
+    ) -> Result<(), TransportError> {
+        let mint = self.bench.get_token_account(escrow).await.unwrap().mint;
+        let deposit_ix = governance_rewards_client::deposit_from_escrow(
+            *escrow,
+            user,
+            realm.address,
+            mint,
+            admin.pubkey(),
+            self.governance.program_id,
+            self.bench.payer.pubkey(),
+            amount,
+        );
+
+        let signers = &[&self.bench.payer, admin];
+
+        self.bench
+            .process_transaction(&[deposit_ix], Some(signers))
+            .await
+    }
+
+    pub async fn with_escrow_admin_config(
+        &self,
+        signers: &[&Keypair],
+        threshold: u8,
+    ) -> Result<Pubkey, TransportError> {
+        let config = Keypair::new();
+        let create_ix = governance_rewards_client::create_escrow_admin_config(
+            config.pubkey(),
+            self.bench.payer.pubkey(),
+            signers.iter().map(|signer| signer.pubkey()).collect(),
+            threshold,
+        );
+
+        self.bench
+            .process_transaction(&[create_ix], Some(&[&config]))
+            .await?;
+        Ok(config.pubkey())
+    }
+
+    pub async fn propose_escrow_release(
+        &self,
+        config: &Pubkey,
+        escrow: &Pubkey,
+        user: &Pubkey,
+        realm: &RealmCookie,
+        to: &TokenAccountCookie,
+        proposer: &Keypair,
+        amount: u64,
+    ) -> Result<Pubkey, TransportError> {
+        let config_account = self
+            .bench
+            .get_anchor_account::<EscrowAdminConfig>(*config)
+            .await;
+        let proposal = pda::escrow_release_proposal(*config, config_account.proposal_count);
+        let propose_ix = governance_rewards_client::propose_escrow_release(
+            *config,
+            config_account.proposal_count,
+            *escrow,
+            *user,
+            realm.address,
+            to.address,
+            proposer.pubkey(),
+            self.bench.payer.pubkey(),
+            amount,
+        );
+
+        self.bench
+            .process_transaction(&[propose_ix], Some(&[proposer]))
+            .await?;
+        Ok(proposal)
+    }
+
+    pub async fn approve_escrow_release(
+        &self,
+        config: &Pubkey,
+        proposal: &Pubkey,
+        approver: &Keypair,
+    ) -> Result<(), TransportError> {
+        let approve_ix = governance_rewards_client::approve_escrow_release(
+            *config,
+            *proposal,
+            approver.pubkey(),
+        );
+
+        self.bench
+            .process_transaction(&[approve_ix], Some(&[approver]))
+            .await
+    }
+
+    pub async fn execute_escrow_release(
+        &self,
+        config: &Pubkey,
+        proposal: &Pubkey,
+        escrow: &Pubkey,
+        user: &Pubkey,
+        realm: &RealmCookie,
+        to: &TokenAccountCookie,
+    ) -> Result<(), TransportError> {
+        let execute_ix = governance_rewards_client::execute_escrow_release(
+            *config,
+            *proposal,
+            *escrow,
+            *user,
+            realm.address,
+            to.address,
+            self.bench.payer.pubkey(),
+        );
+
+        self.bench.process_transaction(&[execute_ix], None).await
+    }
+
     pub async fn reclaim_funds(
         &self,
         distribution: &DistributionCookie,
@@ -456,4 +982,72 @@ impl GovernanceRewardsTest {
 
         Ok(())
     }
+
+    pub async fn sweep_funds(
+        &self,
+        distribution: &DistributionCookie,
+        from: usize,
+        to: &TokenAccountCookie,
+    ) -> Result<(), TransportError> {
+        let transfer_ix = governance_rewards_client::sweep_funds(
+            distribution.address,
+            distribution.admin.pubkey(),
+            distribution.funding[from].address,
+            to.address,
+        );
+
+        let signers = &[&self.bench.payer, &distribution.admin];
+
+        self.bench
+            .process_transaction(&[transfer_ix], Some(signers))
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn migrate_distribution(
+        &self,
+        distribution: &DistributionCookie,
+    ) -> Result<(), TransportError> {
+        let wallets: Vec<Pubkey> = distribution
+            .funding
+            .iter()
+            .map(|funding| funding.address)
+            .collect();
+        let migrate_ix = governance_rewards_client::migrate_distribution(
+            distribution.address,
+            self.bench.payer.pubkey(),
+            &wallets,
+        );
+
+        self.bench.process_transaction(&[migrate_ix], None).await
+    }
+
+    pub async fn migrate_claim_data(
+        &self,
+        user: Pubkey,
+        distribution: Pubkey,
+    ) -> Result<(), TransportError> {
+        let migrate_ix = governance_rewards_client::migrate_claim_data(
+            user,
+            distribution,
+            self.bench.payer.pubkey(),
+        );
+
+        self.bench.process_transaction(&[migrate_ix], None).await
+    }
+
+    pub async fn migrate_user_preferences(
+        &self,
+        user: Pubkey,
+        realm: Pubkey,
+    ) -> Result<(), TransportError> {
+        let migrate_ix = governance_rewards_client::migrate_user_preferences(
+            user,
+            realm,
+            self.bench.payer.pubkey(),
+        );
+
+        self.bench.process_transaction(&[migrate_ix], None).await
+    }
 }
@@ -1,20 +1,23 @@
 use std::{str::FromStr, sync::Arc};
 
 use anchor_lang::prelude::Pubkey;
+use solana_program::instruction::Instruction;
 use solana_program_test::ProgramTest;
 use solana_sdk::{signature::Keypair, signer::Signer, transport::TransportError};
 use spl_governance::{
     instruction::{
-        create_governance, create_proposal, create_realm, create_token_owner_record,
-        deposit_governing_tokens, relinquish_vote, sign_off_proposal,
+        cast_vote, create_governance, create_proposal, create_realm, create_token_owner_record,
+        deposit_governing_tokens, execute_transaction, insert_transaction, relinquish_vote,
+        sign_off_proposal, Vote,
     },
     state::{
         enums::{
             GovernanceAccountType, MintMaxVoteWeightSource, ProposalState, VoteThresholdPercentage,
             VoteTipping,
         },
-        governance::get_governance_address,
-        proposal::{get_proposal_address, ProposalV2},
+        governance::{get_governance_address, GovernanceV2},
+        proposal::{get_proposal_address, ProposalV2, VoteChoice},
+        proposal_transaction::{get_proposal_transaction_address, InstructionData},
         realm::{get_realm_address, RealmConfig, RealmV2},
         token_owner_record::{get_token_owner_record_address, TokenOwnerRecordV2},
     },
@@ -49,6 +52,11 @@ pub struct TokenOwnerRecordCookie {
     pub account: TokenOwnerRecordV2,
 }
 
+pub struct GovernanceCookie {
+    pub address: Pubkey,
+    pub council_token_owner_record: Pubkey,
+}
+
 pub struct GovernanceTest {
     pub program_id: Pubkey,
     pub bench: Arc<ProgramTestBench>,
@@ -225,7 +233,7 @@ impl GovernanceTest {
             )
             .await?;
 
-        let proposal_index: u32 = 0;
+        let proposal_index = self.get_governance(&governance_key).await.proposals_count;
         let proposal_governing_token_mint = realm_cookie.account.community_mint;
 
         let proposal_key = get_proposal_address(
@@ -249,7 +257,7 @@ impl GovernanceTest {
             spl_governance::state::proposal::VoteType::SingleChoice,
             vec!["Yes".to_string()],
             true,
-            0_u32,
+            proposal_index,
         );
 
         let sign_off_proposal_ix = sign_off_proposal(
@@ -301,6 +309,208 @@ impl GovernanceTest {
         })
     }
 
+    /**
+     * Creates a governance whose proposals are decided by a single council member, the
+     * bench payer, so a proposal succeeds as soon as they vote for it.
+     */
+    #[allow(dead_code)]
+    pub async fn with_governance(
+        &mut self,
+        realm_cookie: &RealmCookie,
+    ) -> Result<GovernanceCookie, TransportError> {
+        let token_owner = self.bench.payer.pubkey();
+        let council_mint_cookie = realm_cookie.council_mint_cookie.as_ref().unwrap();
+        let governing_token_mint = council_mint_cookie.address;
+
+        let governing_token_account_cookie = self
+            .bench
+            .with_tokens(council_mint_cookie, &token_owner, 1)
+            .await?;
+
+        let council_token_owner_record = get_token_owner_record_address(
+            &self.program_id,
+            &realm_cookie.address,
+            &governing_token_mint,
+            &token_owner,
+        );
+
+        let deposit_ix = deposit_governing_tokens(
+            &self.program_id,
+            &realm_cookie.address,
+            &governing_token_account_cookie.address,
+            &token_owner,
+            &token_owner,
+            &self.bench.payer.pubkey(),
+            1,
+            &governing_token_mint,
+        );
+
+        self.bench.process_transaction(&[deposit_ix], None).await?;
+
+        let governed_account = Keypair::new().pubkey();
+        let governance_key =
+            get_governance_address(&self.program_id, &realm_cookie.address, &governed_account);
+
+        let create_governance_ix = create_governance(
+            &self.program_id,
+            &realm_cookie.address,
+            Some(&governed_account),
+            &council_token_owner_record,
+            &self.bench.payer.pubkey(),
+            &realm_cookie.realm_authority.pubkey(),
+            None,
+            spl_governance::state::governance::GovernanceConfig {
+                vote_threshold_percentage: VoteThresholdPercentage::YesVote(60),
+                min_community_weight_to_create_proposal: 1,
+                min_transaction_hold_up_time: 0,
+                max_voting_time: 600,
+                vote_tipping: VoteTipping::Strict,
+                proposal_cool_off_time: 0,
+                min_council_weight_to_create_proposal: 1,
+            },
+        );
+
+        self.bench
+            .process_transaction(
+                &[create_governance_ix],
+                Some(&[&realm_cookie.realm_authority]),
+            )
+            .await?;
+
+        Ok(GovernanceCookie {
+            address: governance_key,
+            council_token_owner_record,
+        })
+    }
+
+    /**
+     * Runs `instruction` with the governance as signer by passing a council proposal
+     * containing it and executing the proposal's transaction.
+     */
+    #[allow(dead_code)]
+    pub async fn execute_through_proposal(
+        &mut self,
+        realm_cookie: &RealmCookie,
+        governance_cookie: &GovernanceCookie,
+        instruction: Instruction,
+    ) -> Result<(), TransportError> {
+        let token_owner = self.bench.payer.pubkey();
+        let governing_token_mint = realm_cookie.council_mint_cookie.as_ref().unwrap().address;
+
+        self.next_id += 1;
+        let proposal_name = format!("Proposal #{}", self.next_id);
+        let proposal_index = self
+            .get_governance(&governance_cookie.address)
+            .await
+            .proposals_count;
+        let proposal_key = get_proposal_address(
+            &self.program_id,
+            &governance_cookie.address,
+            &governing_token_mint,
+            &proposal_index.to_le_bytes(),
+        );
+
+        let create_proposal_ix = create_proposal(
+            &self.program_id,
+            &governance_cookie.address,
+            &governance_cookie.council_token_owner_record,
+            &token_owner,
+            &self.bench.payer.pubkey(),
+            None,
+            &realm_cookie.address,
+            proposal_name.clone(),
+            proposal_name,
+            &governing_token_mint,
+            spl_governance::state::proposal::VoteType::SingleChoice,
+            vec!["Yes".to_string()],
+            true,
+            proposal_index,
+        );
+
+        let option_index: u8 = 0;
+        let transaction_index: u16 = 0;
+        let proposal_transaction_key = get_proposal_transaction_address(
+            &self.program_id,
+            &proposal_key,
+            &option_index.to_le_bytes(),
+            &transaction_index.to_le_bytes(),
+        );
+
+        let insert_transaction_ix = insert_transaction(
+            &self.program_id,
+            &governance_cookie.address,
+            &proposal_key,
+            &governance_cookie.council_token_owner_record,
+            &token_owner,
+            &self.bench.payer.pubkey(),
+            option_index,
+            transaction_index,
+            0,
+            vec![InstructionData::from(instruction.clone())],
+        );
+
+        let sign_off_proposal_ix = sign_off_proposal(
+            &self.program_id,
+            &realm_cookie.address,
+            &governance_cookie.address,
+            &proposal_key,
+            &token_owner,
+            Some(&governance_cookie.council_token_owner_record),
+        );
+
+        let cast_vote_ix = cast_vote(
+            &self.program_id,
+            &realm_cookie.address,
+            &governance_cookie.address,
+            &proposal_key,
+            &governance_cookie.council_token_owner_record,
+            &governance_cookie.council_token_owner_record,
+            &token_owner,
+            &governing_token_mint,
+            &self.bench.payer.pubkey(),
+            None,
+            None,
+            Vote::Approve(vec![VoteChoice {
+                rank: 0,
+                weight_percentage: 100,
+            }]),
+        );
+
+        self.bench
+            .process_transaction(
+                &[
+                    create_proposal_ix,
+                    insert_transaction_ix,
+                    sign_off_proposal_ix,
+                    cast_vote_ix,
+                ],
+                None,
+            )
+            .await?;
+
+        // The governance signs when the transaction is executed, not in the outer transaction
+        let mut instruction_accounts = instruction.accounts.clone();
+        for meta in instruction_accounts
+            .iter_mut()
+            .filter(|meta| meta.pubkey == governance_cookie.address)
+        {
+            meta.is_signer = false;
+        }
+
+        let execute_transaction_ix = execute_transaction(
+            &self.program_id,
+            &governance_cookie.address,
+            &proposal_key,
+            &proposal_transaction_key,
+            &instruction.program_id,
+            &instruction_accounts,
+        );
+
+        self.bench
+            .process_transaction(&[execute_transaction_ix], None)
+            .await
+    }
+
     #[allow(dead_code)]
     pub async fn with_token_owner_record(
         &mut self,
@@ -370,6 +580,13 @@ impl GovernanceTest {
         Ok(())
     }
 
+    #[allow(dead_code)]
+    pub async fn get_governance(&mut self, governance_key: &Pubkey) -> GovernanceV2 {
+        self.bench
+            .get_borsh_account::<GovernanceV2>(governance_key)
+            .await
+    }
+
     #[allow(dead_code)]
     pub async fn get_proposal(&mut self, proposal_key: &Pubkey) -> ProposalV2 {
         self.bench
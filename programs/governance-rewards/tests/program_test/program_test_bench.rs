@@ -10,16 +10,17 @@ use anchor_spl::{
     token::{spl_token, Token},
 };
 use solana_program::{borsh::try_from_slice_unchecked, system_program};
-use solana_program_test::{ProgramTest, ProgramTestContext};
+use solana_program_test::{BanksClient, ProgramTest, ProgramTestContext};
 use solana_sdk::{
     account::{Account, AccountSharedData, ReadableAccount},
+    compute_budget::ComputeBudgetInstruction,
     feature_set::spl_associated_token_account_v1_0_4,
-    instruction::Instruction,
+    instruction::{Instruction, InstructionError},
     program_pack::Pack,
     signature::Keypair,
     signer::Signer,
     system_instruction,
-    transaction::Transaction,
+    transaction::{Transaction, TransactionError},
     transport::TransportError,
 };
 
@@ -98,6 +99,62 @@ impl ProgramTestBench {
             .await
     }
 
+    /**
+     * Measures the compute units consumed by `instruction`, by searching for the smallest
+     * compute budget it succeeds with.
+     *
+     * Each attempt is followed by a transfer which always fails, so that no attempt changes
+     * any state. Programs added with `processor!` only run metered when loaded from
+     * `BPF_OUT_DIR` (e.g. with `cargo test-bpf`), so `None` is returned otherwise.
+     */
+    #[allow(dead_code)]
+    pub async fn get_compute_units(
+        &self,
+        instruction: &Instruction,
+        signers: Option<&[&Keypair]>,
+    ) -> Option<u32> {
+        if std::env::var("BPF_OUT_DIR").is_err() {
+            return None;
+        }
+
+        let failing_ix =
+            system_instruction::transfer(&self.payer.pubkey(), &Pubkey::new_unique(), u64::MAX);
+
+        // The instruction runs out of compute with `low` units, and fits in `high`.
+        let (mut low, mut high) = (0_u32, 1_400_000_u32);
+        while high - low > 1 {
+            let units = low + (high - low) / 2;
+            let result = self
+                .process_transaction(
+                    &[
+                        ComputeBudgetInstruction::request_units(units, 0),
+                        instruction.clone(),
+                        failing_ix.clone(),
+                    ],
+                    signers,
+                )
+                .await;
+
+            match result {
+                Err(TransportError::TransactionError(TransactionError::InstructionError(
+                    1,
+                    InstructionError::ComputationalBudgetExceeded,
+                ))) => low = units,
+                Err(TransportError::TransactionError(TransactionError::InstructionError(2, _))) => {
+                    high = units
+                }
+                result => panic!("Unexpected result measuring compute units: {:?}", result),
+            }
+        }
+
+        Some(high)
+    }
+
+    #[allow(dead_code)]
+    pub fn banks_client(&self) -> BanksClient {
+        self.context.borrow().banks_client.clone()
+    }
+
     pub async fn get_clock(&self) -> solana_program::clock::Clock {
         self.context
             .borrow_mut()
@@ -331,6 +388,13 @@ impl ProgramTestBench {
         }
     }
 
+    #[allow(dead_code)]
+    pub async fn with_lamports(&self, to: &Pubkey, lamports: u64) -> Result<(), TransportError> {
+        let transfer_ix = system_instruction::transfer(&self.payer.pubkey(), to, lamports);
+
+        self.process_transaction(&[transfer_ix], None).await
+    }
+
     #[allow(dead_code)]
     pub async fn get_account(&self, address: &Pubkey) -> Option<Account> {
         self.context
@@ -1,13 +1,23 @@
-use crate::program_test::governance_rewards_test::GovernanceRewardsTest;
+use crate::program_test::governance_rewards_test::{
+    DistributionCookie, GovernanceRewardsTest, PreferenceCookie,
+};
+use crate::program_test::governance_test::RealmCookie;
+use crate::program_test::program_test_bench::WalletCookie;
 use crate::program_test::tools::assert_governance_rewards_err;
+use anchor_spl::{associated_token::get_associated_token_address, token::spl_token};
 use governance_rewards::{
     error::GovernanceRewardsError,
+    instructions::get_escrow_owner,
     state::{
         addin::VoterWeightRecord,
         claim_data::ClaimData,
-        preferences::{ResolutionPreference, UserPreferences},
+        distribution_preferences::DistributionPreferences,
+        escrow::EscrowState,
+        preferences::{find_escrow_address, ResolutionPreference, UserPreferences},
     },
 };
+use governance_rewards_client::ClaimTarget;
+use solana_program::program_pack::Pack;
 use solana_program_test::tokio;
 use solana_sdk::{signature::Keypair, signer::Signer, transport::TransportError};
 
@@ -51,8 +61,9 @@ async fn test_claim() -> TestOutcome {
     let preferences = governance_rewards_test
         .with_preferences(
             &UserPreferences {
-                preferred_mint: None,
+                preferred_mints: vec![],
                 resolution_preference: ResolutionPreference::Wallet,
+                ..Default::default()
             },
             &realm_cookie,
             user.pubkey(),
@@ -142,8 +153,9 @@ async fn test_claim_early_err() -> TestOutcome {
     let preferences = governance_rewards_test
         .with_preferences(
             &UserPreferences {
-                preferred_mint: None,
+                preferred_mints: vec![],
                 resolution_preference: ResolutionPreference::Wallet,
+                ..Default::default()
             },
             &realm_cookie,
             user.pubkey(),
@@ -214,8 +226,9 @@ async fn test_claim_twice_err() -> TestOutcome {
     let preferences = governance_rewards_test
         .with_preferences(
             &UserPreferences {
-                preferred_mint: None,
+                preferred_mints: vec![],
                 resolution_preference: ResolutionPreference::Wallet,
+                ..Default::default()
             },
             &realm_cookie,
             user.pubkey(),
@@ -302,10 +315,11 @@ async fn test_claim_to_escrow() -> TestOutcome {
     let preferences = governance_rewards_test
         .with_preferences(
             &UserPreferences {
-                preferred_mint: None,
+                preferred_mints: vec![],
                 resolution_preference: ResolutionPreference::Escrow {
                     escrow_admin: escrow_admin.pubkey(),
                 },
+                ..Default::default()
             },
             &realm_cookie,
             user.pubkey(),
@@ -363,3 +377,832 @@ async fn test_claim_to_escrow() -> TestOutcome {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_claim_to_escrow_with_distribution_override() -> TestOutcome {
+    // Arrange
+    let mut governance_rewards_test = GovernanceRewardsTest::start_new().await;
+    let user = Keypair::new();
+    let realm_cookie = governance_rewards_test.governance.with_realm().await?;
+    let key_cookie = governance_rewards_test.with_distribution_keypair();
+
+    governance_rewards_test.bench.set_unix_time(5).await;
+    let distribution_cookie = governance_rewards_test
+        .with_funded_distribution(&realm_cookie, &key_cookie, 10)
+        .await?;
+    let token_mint = governance_rewards_test.bench.with_mint().await?;
+
+    let vote_weight = 10;
+    let vwr = governance_rewards_test
+        .with_dummy_voter_weight_record(
+            &VoterWeightRecord::create_test(
+                realm_cookie.address,
+                token_mint.address,
+                user.pubkey(),
+                distribution_cookie.address,
+                vote_weight,
+                Some(u64::MAX),
+            ),
+            distribution_cookie.account.voter_weight_program,
+        )
+        .await?;
+
+    let realm_preferences = governance_rewards_test
+        .with_preferences(
+            &UserPreferences {
+                preferred_mints: vec![],
+                resolution_preference: ResolutionPreference::Wallet,
+                ..Default::default()
+            },
+            &realm_cookie,
+            user.pubkey(),
+        )
+        .await?;
+
+    let escrow_admin = Keypair::new();
+    let resolution = ResolutionPreference::Escrow {
+        escrow_admin: escrow_admin.pubkey(),
+    };
+    governance_rewards_test
+        .with_distribution_preferences(
+            &DistributionPreferences {
+                preferred_mint: None,
+                resolution_preference: Some(resolution),
+            },
+            &distribution_cookie,
+            user.pubkey(),
+        )
+        .await?;
+    let preferences = PreferenceCookie {
+        address: realm_preferences.address,
+        resolution,
+    };
+
+    governance_rewards_test
+        .with_registrant(&distribution_cookie, &vwr)
+        .await?;
+
+    governance_rewards_test.bench.set_unix_time(11).await;
+
+    // Act
+    let target_payout = distribution_cookie.funding[0];
+    let escrow_address = governance_rewards_test
+        .with_escrow(
+            &user.pubkey(),
+            &target_payout.mint,
+            &realm_cookie,
+            &escrow_admin.pubkey(),
+        )
+        .await?;
+    governance_rewards_test
+        .claim(
+            &user,
+            target_payout.address,
+            target_payout.mint,
+            &distribution_cookie,
+            &preferences,
+        )
+        .await?;
+
+    // Assert
+    let escrow_account = governance_rewards_test
+        .bench
+        .get_token_account(&escrow_address)
+        .await
+        .unwrap();
+    assert_eq!(escrow_account.amount, 100);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_claim_creates_associated_token_account() -> TestOutcome {
+    // Arrange
+    let mut governance_rewards_test = GovernanceRewardsTest::start_new().await;
+    let user = Keypair::new();
+    let realm_cookie = governance_rewards_test.governance.with_realm().await?;
+    let key_cookie = governance_rewards_test.with_distribution_keypair();
+
+    governance_rewards_test.bench.set_unix_time(5).await;
+    let distribution_cookie = governance_rewards_test
+        .with_funded_distribution(&realm_cookie, &key_cookie, 10)
+        .await?;
+    let token_mint = governance_rewards_test.bench.with_mint().await?;
+
+    let vote_weight = 10;
+    let vwr = governance_rewards_test
+        .with_dummy_voter_weight_record(
+            &VoterWeightRecord::create_test(
+                realm_cookie.address,
+                token_mint.address,
+                user.pubkey(),
+                distribution_cookie.address,
+                vote_weight,
+                Some(u64::MAX),
+            ),
+            distribution_cookie.account.voter_weight_program,
+        )
+        .await?;
+
+    let preferences = governance_rewards_test
+        .with_preferences(
+            &UserPreferences {
+                preferred_mints: vec![],
+                resolution_preference: ResolutionPreference::Wallet,
+                ..Default::default()
+            },
+            &realm_cookie,
+            user.pubkey(),
+        )
+        .await?;
+
+    governance_rewards_test
+        .with_registrant(&distribution_cookie, &vwr)
+        .await?;
+
+    governance_rewards_test.bench.set_unix_time(11).await;
+
+    // Act
+    let target_payout = distribution_cookie.funding[0];
+    governance_rewards_test
+        .claim(
+            &user,
+            target_payout.address,
+            target_payout.mint,
+            &distribution_cookie,
+            &preferences,
+        )
+        .await?;
+
+    // Assert
+    let user_token_account = governance_rewards_test
+        .bench
+        .get_token_account(&get_associated_token_address(
+            &user.pubkey(),
+            &target_payout.mint,
+        ))
+        .await
+        .unwrap();
+    assert_eq!(user_token_account.owner, user.pubkey());
+    assert_eq!(user_token_account.mint, target_payout.mint);
+    assert_eq!(user_token_account.amount, 100);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_claim_creates_escrow() -> TestOutcome {
+    // Arrange
+    let mut governance_rewards_test = GovernanceRewardsTest::start_new().await;
+    let user = Keypair::new();
+    let realm_cookie = governance_rewards_test.governance.with_realm().await?;
+    let key_cookie = governance_rewards_test.with_distribution_keypair();
+
+    governance_rewards_test.bench.set_unix_time(5).await;
+    let distribution_cookie = governance_rewards_test
+        .with_funded_distribution(&realm_cookie, &key_cookie, 10)
+        .await?;
+    let token_mint = governance_rewards_test.bench.with_mint().await?;
+
+    let vote_weight = 10;
+    let vwr = governance_rewards_test
+        .with_dummy_voter_weight_record(
+            &VoterWeightRecord::create_test(
+                realm_cookie.address,
+                token_mint.address,
+                user.pubkey(),
+                distribution_cookie.address,
+                vote_weight,
+                Some(u64::MAX),
+            ),
+            distribution_cookie.account.voter_weight_program,
+        )
+        .await?;
+
+    let escrow_admin = Keypair::new();
+
+    let preferences = governance_rewards_test
+        .with_preferences(
+            &UserPreferences {
+                preferred_mints: vec![],
+                resolution_preference: ResolutionPreference::Escrow {
+                    escrow_admin: escrow_admin.pubkey(),
+                },
+                ..Default::default()
+            },
+            &realm_cookie,
+            user.pubkey(),
+        )
+        .await?;
+
+    governance_rewards_test
+        .with_registrant(&distribution_cookie, &vwr)
+        .await?;
+
+    governance_rewards_test.bench.set_unix_time(11).await;
+
+    // Act
+    let target_payout = distribution_cookie.funding[0];
+    governance_rewards_test
+        .claim(
+            &user,
+            target_payout.address,
+            target_payout.mint,
+            &distribution_cookie,
+            &preferences,
+        )
+        .await?;
+
+    // Assert
+    let escrow_address = preferences.resolution.payout_address(
+        user.pubkey(),
+        target_payout.mint,
+        realm_cookie.address,
+    );
+    let escrow_account = governance_rewards_test
+        .bench
+        .get_token_account(&escrow_address)
+        .await
+        .unwrap();
+    assert_eq!(escrow_account.owner, get_escrow_owner(realm_cookie.address));
+    assert_eq!(escrow_account.mint, target_payout.mint);
+    assert_eq!(escrow_account.amount, 100);
+
+    let escrow_state = governance_rewards_test
+        .bench
+        .get_anchor_account::<EscrowState>(EscrowState::get_address(escrow_address))
+        .await;
+    assert_eq!(
+        escrow_state.payer,
+        governance_rewards_test.bench.payer.pubkey()
+    );
+    assert_eq!(
+        escrow_state.bump,
+        EscrowState::find_address(escrow_address).1
+    );
+    assert_eq!(
+        escrow_state.escrow_bump,
+        find_escrow_address(
+            realm_cookie.address,
+            escrow_admin.pubkey(),
+            user.pubkey(),
+            target_payout.mint
+        )
+        .1
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_claim_creates_pre_funded_escrow() -> TestOutcome {
+    // Arrange
+    let mut governance_rewards_test = GovernanceRewardsTest::start_new().await;
+    let user = Keypair::new();
+    let realm_cookie = governance_rewards_test.governance.with_realm().await?;
+    let key_cookie = governance_rewards_test.with_distribution_keypair();
+
+    governance_rewards_test.bench.set_unix_time(5).await;
+    let distribution_cookie = governance_rewards_test
+        .with_funded_distribution(&realm_cookie, &key_cookie, 10)
+        .await?;
+    let token_mint = governance_rewards_test.bench.with_mint().await?;
+
+    let vote_weight = 10;
+    let vwr = governance_rewards_test
+        .with_dummy_voter_weight_record(
+            &VoterWeightRecord::create_test(
+                realm_cookie.address,
+                token_mint.address,
+                user.pubkey(),
+                distribution_cookie.address,
+                vote_weight,
+                Some(u64::MAX),
+            ),
+            distribution_cookie.account.voter_weight_program,
+        )
+        .await?;
+
+    let escrow_admin = Keypair::new();
+
+    let preferences = governance_rewards_test
+        .with_preferences(
+            &UserPreferences {
+                preferred_mints: vec![],
+                resolution_preference: ResolutionPreference::Escrow {
+                    escrow_admin: escrow_admin.pubkey(),
+                },
+                ..Default::default()
+            },
+            &realm_cookie,
+            user.pubkey(),
+        )
+        .await?;
+
+    governance_rewards_test
+        .with_registrant(&distribution_cookie, &vwr)
+        .await?;
+
+    governance_rewards_test.bench.set_unix_time(11).await;
+
+    // Anyone may send lamports to the escrow before it is created.
+    let target_payout = distribution_cookie.funding[0];
+    let escrow_address = preferences.resolution.payout_address(
+        user.pubkey(),
+        target_payout.mint,
+        realm_cookie.address,
+    );
+    governance_rewards_test
+        .bench
+        .with_lamports(&escrow_address, 1)
+        .await?;
+
+    // Act
+    governance_rewards_test
+        .claim(
+            &user,
+            target_payout.address,
+            target_payout.mint,
+            &distribution_cookie,
+            &preferences,
+        )
+        .await?;
+
+    // Assert
+    let escrow_account = governance_rewards_test
+        .bench
+        .get_token_account(&escrow_address)
+        .await
+        .unwrap();
+    assert_eq!(escrow_account.owner, get_escrow_owner(realm_cookie.address));
+    assert_eq!(escrow_account.mint, target_payout.mint);
+    assert_eq!(escrow_account.amount, 100);
+
+    let escrow = governance_rewards_test
+        .bench
+        .get_account(&escrow_address)
+        .await
+        .unwrap();
+    assert_eq!(
+        escrow.lamports,
+        governance_rewards_test
+            .bench
+            .rent
+            .minimum_balance(spl_token::state::Account::LEN)
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_claim_creates_pre_funded_escrow_state() -> TestOutcome {
+    // Arrange
+    let mut governance_rewards_test = GovernanceRewardsTest::start_new().await;
+    let user = Keypair::new();
+    let realm_cookie = governance_rewards_test.governance.with_realm().await?;
+    let key_cookie = governance_rewards_test.with_distribution_keypair();
+
+    governance_rewards_test.bench.set_unix_time(5).await;
+    let distribution_cookie = governance_rewards_test
+        .with_funded_distribution(&realm_cookie, &key_cookie, 10)
+        .await?;
+    let token_mint = governance_rewards_test.bench.with_mint().await?;
+
+    let vote_weight = 10;
+    let vwr = governance_rewards_test
+        .with_dummy_voter_weight_record(
+            &VoterWeightRecord::create_test(
+                realm_cookie.address,
+                token_mint.address,
+                user.pubkey(),
+                distribution_cookie.address,
+                vote_weight,
+                Some(u64::MAX),
+            ),
+            distribution_cookie.account.voter_weight_program,
+        )
+        .await?;
+
+    let escrow_admin = Keypair::new();
+
+    let preferences = governance_rewards_test
+        .with_preferences(
+            &UserPreferences {
+                preferred_mints: vec![],
+                resolution_preference: ResolutionPreference::Escrow {
+                    escrow_admin: escrow_admin.pubkey(),
+                },
+                ..Default::default()
+            },
+            &realm_cookie,
+            user.pubkey(),
+        )
+        .await?;
+
+    governance_rewards_test
+        .with_registrant(&distribution_cookie, &vwr)
+        .await?;
+
+    governance_rewards_test.bench.set_unix_time(11).await;
+
+    // Anyone may send lamports to the escrow state before it is created.
+    let target_payout = distribution_cookie.funding[0];
+    let escrow_address = preferences.resolution.payout_address(
+        user.pubkey(),
+        target_payout.mint,
+        realm_cookie.address,
+    );
+    governance_rewards_test
+        .bench
+        .with_lamports(&EscrowState::get_address(escrow_address), 1)
+        .await?;
+
+    // Act
+    governance_rewards_test
+        .claim(
+            &user,
+            target_payout.address,
+            target_payout.mint,
+            &distribution_cookie,
+            &preferences,
+        )
+        .await?;
+
+    // Assert
+    let escrow_account = governance_rewards_test
+        .bench
+        .get_token_account(&escrow_address)
+        .await
+        .unwrap();
+    assert_eq!(escrow_account.owner, get_escrow_owner(realm_cookie.address));
+    assert_eq!(escrow_account.mint, target_payout.mint);
+    assert_eq!(escrow_account.amount, 100);
+
+    let escrow_state = governance_rewards_test
+        .bench
+        .get_anchor_account::<EscrowState>(EscrowState::get_address(escrow_address))
+        .await;
+    assert_eq!(
+        escrow_state.payer,
+        governance_rewards_test.bench.payer.pubkey()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_claim_creates_timelocked_escrow() -> TestOutcome {
+    // Arrange
+    let mut governance_rewards_test = GovernanceRewardsTest::start_new().await;
+    let user = Keypair::new();
+    let realm_cookie = governance_rewards_test.governance.with_realm().await?;
+    let key_cookie = governance_rewards_test.with_distribution_keypair();
+
+    governance_rewards_test.bench.set_unix_time(5).await;
+    let distribution_cookie = governance_rewards_test
+        .with_funded_distribution(&realm_cookie, &key_cookie, 10)
+        .await?;
+    let token_mint = governance_rewards_test.bench.with_mint().await?;
+
+    let vote_weight = 10;
+    let vwr = governance_rewards_test
+        .with_dummy_voter_weight_record(
+            &VoterWeightRecord::create_test(
+                realm_cookie.address,
+                token_mint.address,
+                user.pubkey(),
+                distribution_cookie.address,
+                vote_weight,
+                Some(u64::MAX),
+            ),
+            distribution_cookie.account.voter_weight_program,
+        )
+        .await?;
+
+    let escrow_admin = Keypair::new();
+
+    let preferences = governance_rewards_test
+        .with_preferences(
+            &UserPreferences {
+                preferred_mints: vec![],
+                resolution_preference: ResolutionPreference::Timelock {
+                    escrow_admin: escrow_admin.pubkey(),
+                    unlock_ts: 100,
+                },
+                ..Default::default()
+            },
+            &realm_cookie,
+            user.pubkey(),
+        )
+        .await?;
+
+    governance_rewards_test
+        .with_registrant(&distribution_cookie, &vwr)
+        .await?;
+
+    governance_rewards_test.bench.set_unix_time(11).await;
+
+    // Act
+    let target_payout = distribution_cookie.funding[0];
+    governance_rewards_test
+        .claim(
+            &user,
+            target_payout.address,
+            target_payout.mint,
+            &distribution_cookie,
+            &preferences,
+        )
+        .await?;
+
+    // Assert
+    let escrow_address = preferences.resolution.payout_address(
+        user.pubkey(),
+        target_payout.mint,
+        realm_cookie.address,
+    );
+    let escrow_account = governance_rewards_test
+        .bench
+        .get_token_account(&escrow_address)
+        .await
+        .unwrap();
+    assert_eq!(escrow_account.owner, get_escrow_owner(realm_cookie.address));
+    assert_eq!(escrow_account.mint, target_payout.mint);
+    assert_eq!(escrow_account.amount, 100);
+
+    Ok(())
+}
+
+async fn with_governance_deposit_claimant(
+    governance_rewards_test: &mut GovernanceRewardsTest,
+) -> Result<
+    (
+        WalletCookie,
+        RealmCookie,
+        DistributionCookie,
+        PreferenceCookie,
+    ),
+    TransportError,
+> {
+    let user = governance_rewards_test.bench.with_wallet().await;
+    let realm_cookie = governance_rewards_test.governance.with_realm().await?;
+    let key_cookie = governance_rewards_test.with_distribution_keypair();
+
+    governance_rewards_test.bench.set_unix_time(5).await;
+    let funding = governance_rewards_test
+        .with_owned_tokens(&realm_cookie.community_mint_cookie, &key_cookie, 100)
+        .await?;
+    let distribution_cookie = governance_rewards_test
+        .with_distribution(&realm_cookie, &key_cookie, 10, &[&funding])
+        .await?;
+    let token_mint = governance_rewards_test.bench.with_mint().await?;
+
+    let vwr = governance_rewards_test
+        .with_dummy_voter_weight_record(
+            &VoterWeightRecord::create_test(
+                realm_cookie.address,
+                token_mint.address,
+                user.address,
+                distribution_cookie.address,
+                10,
+                Some(u64::MAX),
+            ),
+            distribution_cookie.account.voter_weight_program,
+        )
+        .await?;
+
+    let preferences = governance_rewards_test
+        .with_preferences(
+            &UserPreferences {
+                preferred_mints: vec![],
+                resolution_preference: ResolutionPreference::GovernanceDeposit {
+                    governance_program: governance_rewards_test.governance.program_id,
+                },
+                ..Default::default()
+            },
+            &realm_cookie,
+            user.address,
+        )
+        .await?;
+
+    governance_rewards_test
+        .with_registrant(&distribution_cookie, &vwr)
+        .await?;
+
+    governance_rewards_test.bench.set_unix_time(11).await;
+
+    Ok((user, realm_cookie, distribution_cookie, preferences))
+}
+
+#[tokio::test]
+async fn test_claim_to_governance() -> TestOutcome {
+    // Arrange
+    let mut governance_rewards_test = GovernanceRewardsTest::start_new().await;
+    let (user, realm_cookie, distribution_cookie, _) =
+        with_governance_deposit_claimant(&mut governance_rewards_test).await?;
+    let token_owner_record = governance_rewards_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &user)
+        .await?;
+
+    // Act
+    governance_rewards_test
+        .claim_to_governance(
+            &user.signer,
+            &distribution_cookie.funding[0],
+            &distribution_cookie,
+        )
+        .await?;
+
+    // Assert
+    let token_owner_record = governance_rewards_test
+        .governance
+        .get_token_owner_record(&token_owner_record.address)
+        .await;
+    assert_eq!(token_owner_record.governing_token_deposit_amount, 100);
+
+    let claim_data = governance_rewards_test
+        .bench
+        .get_anchor_account::<ClaimData>(ClaimData::get_address(
+            user.address,
+            distribution_cookie.address,
+        ))
+        .await;
+    assert!(claim_data.has_claimed);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_claim_with_governance_deposit_preference_err() -> TestOutcome {
+    // Arrange
+    let mut governance_rewards_test = GovernanceRewardsTest::start_new().await;
+    let (user, realm_cookie, distribution_cookie, preferences) =
+        with_governance_deposit_claimant(&mut governance_rewards_test).await?;
+    governance_rewards_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &user)
+        .await?;
+
+    // Act
+    let target_payout = distribution_cookie.funding[0];
+    let err = governance_rewards_test
+        .claim(
+            &user.signer,
+            target_payout.address,
+            target_payout.mint,
+            &distribution_cookie,
+            &preferences,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_governance_rewards_err(err, GovernanceRewardsError::GovernanceDepositRequired);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_claim_to_governance_without_token_owner_record_err() -> TestOutcome {
+    // Arrange
+    let mut governance_rewards_test = GovernanceRewardsTest::start_new().await;
+    let (user, _, distribution_cookie, _) =
+        with_governance_deposit_claimant(&mut governance_rewards_test).await?;
+
+    // Act
+    let err = governance_rewards_test
+        .claim_to_governance(
+            &user.signer,
+            &distribution_cookie.funding[0],
+            &distribution_cookie,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_governance_rewards_err(err, GovernanceRewardsError::MissingTokenOwnerRecord);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_claim_many() -> TestOutcome {
+    // Arrange
+    let mut governance_rewards_test = GovernanceRewardsTest::start_new().await;
+    let user = Keypair::new();
+    let realm_cookie = governance_rewards_test.governance.with_realm().await?;
+    let token_mint = governance_rewards_test.bench.with_mint().await?;
+
+    governance_rewards_test.bench.set_unix_time(5).await;
+
+    let mut distribution_cookies = vec![];
+    for _ in 0..2 {
+        let key_cookie = governance_rewards_test.with_distribution_keypair();
+        let distribution_cookie = governance_rewards_test
+            .with_funded_distribution(&realm_cookie, &key_cookie, 10)
+            .await?;
+
+        let vwr = governance_rewards_test
+            .with_dummy_voter_weight_record(
+                &VoterWeightRecord::create_test(
+                    realm_cookie.address,
+                    token_mint.address,
+                    user.pubkey(),
+                    distribution_cookie.address,
+                    10,
+                    Some(u64::MAX),
+                ),
+                distribution_cookie.account.voter_weight_program,
+            )
+            .await?;
+
+        governance_rewards_test
+            .with_registrant(&distribution_cookie, &vwr)
+            .await?;
+
+        distribution_cookies.push(distribution_cookie);
+    }
+
+    governance_rewards_test.bench.set_unix_time(11).await;
+
+    let mut targets = vec![];
+    for distribution_cookie in &distribution_cookies {
+        let target_payout = distribution_cookie.funding[0];
+        let user_token_account_cookie = governance_rewards_test
+            .bench
+            .create_associated_token_account(user.pubkey(), target_payout.mint)
+            .await?;
+
+        targets.push(ClaimTarget {
+            distribution: distribution_cookie.address,
+            rewards_account: target_payout.address,
+            to_account: user_token_account_cookie.address,
+        });
+    }
+
+    // Act
+    governance_rewards_test
+        .claim_many(&user, &realm_cookie, &targets)
+        .await?;
+
+    // Assert
+    for target in &targets {
+        let claim_record = governance_rewards_test
+            .bench
+            .get_anchor_account::<ClaimData>(ClaimData::get_address(
+                user.pubkey(),
+                target.distribution,
+            ))
+            .await;
+        assert!(claim_record.has_claimed);
+
+        let user_token_account = governance_rewards_test
+            .bench
+            .get_token_account(&target.to_account)
+            .await
+            .unwrap();
+        assert_eq!(user_token_account.amount, 100);
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_claim_many_with_incomplete_group_err() -> TestOutcome {
+    // Arrange
+    let mut governance_rewards_test = GovernanceRewardsTest::start_new().await;
+    let user = Keypair::new();
+    let realm_cookie = governance_rewards_test.governance.with_realm().await?;
+    let key_cookie = governance_rewards_test.with_distribution_keypair();
+    let distribution_cookie = governance_rewards_test
+        .with_funded_distribution(&realm_cookie, &key_cookie, u64::MAX)
+        .await?;
+
+    let target_payout = distribution_cookie.funding[0];
+    let mut claim_many_ix = governance_rewards_client::claim_many(
+        user.pubkey(),
+        realm_cookie.address,
+        &[ClaimTarget {
+            distribution: distribution_cookie.address,
+            rewards_account: target_payout.address,
+            to_account: target_payout.address,
+        }],
+        governance_rewards_test.bench.payer.pubkey(),
+    );
+    claim_many_ix.accounts.pop();
+
+    // Act
+    let err = governance_rewards_test
+        .bench
+        .process_transaction(&[claim_many_ix], None)
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_governance_rewards_err(err, GovernanceRewardsError::InvalidClaimGroups);
+
+    Ok(())
+}
@@ -0,0 +1,155 @@
+use crate::program_test::governance_rewards_test::{
+    DistributionCookie, GovernanceRewardsTest, VoterWeightRecordCookie,
+};
+use crate::program_test::governance_test::RealmCookie;
+use crate::program_test::program_test_bench::TokenAccountCookie;
+use governance_rewards::state::{addin::VoterWeightRecord, preferences::ResolutionPreference};
+use solana_program_test::tokio;
+use solana_sdk::{
+    instruction::Instruction, signature::Keypair, signer::Signer, transport::TransportError,
+};
+
+mod program_test;
+
+type TestOutcome = Result<(), TransportError>;
+
+/**
+ * Compute budget of `register`. Reading the stored preferences bump keeps it well under
+ * this, whatever attempts deriving the user's addresses would take.
+ */
+const MAX_REGISTER_COMPUTE_UNITS: u32 = 100_000;
+
+/**
+ * Compute budget of `claim`, which reads the stored claim data and payout authority
+ * bumps.
+ */
+const MAX_CLAIM_COMPUTE_UNITS: u32 = 150_000;
+
+/**
+ * A user with preferences set by instruction, eligible for a distribution paying `payout`.
+ */
+struct UserCookie {
+    user: Keypair,
+    realm_cookie: RealmCookie,
+    distribution_cookie: DistributionCookie,
+    payout: TokenAccountCookie,
+    vwr: VoterWeightRecordCookie,
+}
+
+async fn with_user(
+    governance_rewards_test: &mut GovernanceRewardsTest,
+) -> Result<UserCookie, TransportError> {
+    let realm_cookie = governance_rewards_test.governance.with_realm().await?;
+    let key_cookie = governance_rewards_test.with_distribution_keypair();
+
+    governance_rewards_test.bench.set_unix_time(5).await;
+    let distribution_cookie = governance_rewards_test
+        .with_funded_distribution(&realm_cookie, &key_cookie, 10)
+        .await?;
+    let payout = distribution_cookie.funding[0];
+    let token_mint = governance_rewards_test.bench.with_mint().await?;
+
+    let user = Keypair::new();
+    let vwr = governance_rewards_test
+        .with_dummy_voter_weight_record(
+            &VoterWeightRecord::create_test(
+                realm_cookie.address,
+                token_mint.address,
+                user.pubkey(),
+                distribution_cookie.address,
+                10,
+                Some(u64::MAX),
+            ),
+            distribution_cookie.account.voter_weight_program,
+        )
+        .await?;
+    governance_rewards_test
+        .set_resolution_preference(&user, &realm_cookie, ResolutionPreference::Wallet)
+        .await?;
+
+    Ok(UserCookie {
+        user,
+        realm_cookie,
+        distribution_cookie,
+        payout,
+        vwr,
+    })
+}
+
+async fn get_compute_units(
+    governance_rewards_test: &GovernanceRewardsTest,
+    instruction: &Instruction,
+) -> u32 {
+    governance_rewards_test
+        .bench
+        .get_compute_units(instruction, None)
+        .await
+        .expect("compute is only metered when BPF_OUT_DIR points at the program's BPF build")
+}
+
+#[tokio::test]
+async fn test_register_within_compute_budget() -> TestOutcome {
+    // Arrange
+    let mut governance_rewards_test = GovernanceRewardsTest::start_new().await;
+    let cookie = with_user(&mut governance_rewards_test).await?;
+    let register_ix = governance_rewards_client::register(
+        cookie.vwr.user,
+        cookie.distribution_cookie.address,
+        cookie.realm_cookie.address,
+        cookie.vwr.address,
+        governance_rewards_test.bench.payer.pubkey(),
+    );
+
+    // Act
+    let units = get_compute_units(&governance_rewards_test, &register_ix).await;
+
+    // Assert
+    assert!(
+        units <= MAX_REGISTER_COMPUTE_UNITS,
+        "register used {} units, over its budget of {}",
+        units,
+        MAX_REGISTER_COMPUTE_UNITS
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_claim_within_compute_budget() -> TestOutcome {
+    // Arrange
+    let mut governance_rewards_test = GovernanceRewardsTest::start_new().await;
+    let cookie = with_user(&mut governance_rewards_test).await?;
+    let user = cookie.user.pubkey();
+    governance_rewards_test
+        .with_registrant(&cookie.distribution_cookie, &cookie.vwr)
+        .await?;
+    governance_rewards_test
+        .bench
+        .create_associated_token_account(user, cookie.payout.mint)
+        .await?;
+    governance_rewards_test.bench.set_unix_time(11).await;
+
+    let realm = cookie.realm_cookie.address;
+    let claim_ix = governance_rewards_client::claim(
+        user,
+        cookie.distribution_cookie.address,
+        realm,
+        cookie.payout.address,
+        ResolutionPreference::Wallet.payout_address(user, cookie.payout.mint, realm),
+        cookie.payout.mint,
+        governance_rewards_test.bench.payer.pubkey(),
+    );
+
+    // Act
+    let units = get_compute_units(&governance_rewards_test, &claim_ix).await;
+
+    // Assert
+    assert!(
+        units <= MAX_CLAIM_COMPUTE_UNITS,
+        "claim used {} units, over its budget of {}",
+        units,
+        MAX_CLAIM_COMPUTE_UNITS
+    );
+
+    Ok(())
+}
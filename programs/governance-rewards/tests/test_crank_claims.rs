@@ -0,0 +1,228 @@
+use crate::program_test::governance_rewards_test::GovernanceRewardsTest;
+use crate::program_test::tools::assert_governance_rewards_err;
+use anchor_spl::{
+    associated_token::get_associated_token_address,
+    token::spl_token::{self, state::AccountState},
+};
+use governance_rewards::{
+    error::GovernanceRewardsError,
+    state::{addin::VoterWeightRecord, claim_data::ClaimData},
+};
+use governance_rewards_client::CrankTarget;
+use solana_program::program_pack::Pack;
+use solana_program_test::tokio;
+use solana_sdk::{signature::Keypair, signer::Signer, transport::TransportError};
+
+mod program_test;
+
+type TestOutcome = Result<(), TransportError>;
+
+#[tokio::test]
+async fn test_crank_claims() -> TestOutcome {
+    // Arrange
+    let mut governance_rewards_test = GovernanceRewardsTest::start_new().await;
+    let users = [Keypair::new(), Keypair::new()];
+    let realm_cookie = governance_rewards_test.governance.with_realm().await?;
+    let token_mint = governance_rewards_test.bench.with_mint().await?;
+
+    governance_rewards_test.bench.set_unix_time(5).await;
+
+    let key_cookie = governance_rewards_test.with_distribution_keypair();
+    let distribution_cookie = governance_rewards_test
+        .with_funded_distribution(&realm_cookie, &key_cookie, 10)
+        .await?;
+    let target_payout = distribution_cookie.funding[0];
+
+    for user in &users {
+        let vwr = governance_rewards_test
+            .with_dummy_voter_weight_record(
+                &VoterWeightRecord::create_test(
+                    realm_cookie.address,
+                    token_mint.address,
+                    user.pubkey(),
+                    distribution_cookie.address,
+                    10,
+                    Some(u64::MAX),
+                ),
+                distribution_cookie.account.voter_weight_program,
+            )
+            .await?;
+
+        governance_rewards_test
+            .with_registrant(&distribution_cookie, &vwr)
+            .await?;
+    }
+
+    governance_rewards_test.bench.set_unix_time(11).await;
+
+    let user_token_account_cookie = governance_rewards_test
+        .bench
+        .create_associated_token_account(users[0].pubkey(), target_payout.mint)
+        .await?;
+
+    let targets: Vec<CrankTarget> = users
+        .iter()
+        .map(|user| CrankTarget {
+            claimant: user.pubkey(),
+            rewards_account: target_payout.address,
+            to_account: get_associated_token_address(&user.pubkey(), &target_payout.mint),
+        })
+        .collect();
+
+    // Act
+    governance_rewards_test
+        .crank_claims(&distribution_cookie, &targets)
+        .await?;
+
+    // Assert
+    let user_token_account = governance_rewards_test
+        .bench
+        .get_token_account(&user_token_account_cookie.address)
+        .await
+        .unwrap();
+    assert_eq!(user_token_account.amount, 50);
+
+    let paid_claim_record = governance_rewards_test
+        .bench
+        .get_anchor_account::<ClaimData>(ClaimData::get_address(
+            users[0].pubkey(),
+            distribution_cookie.address,
+        ))
+        .await;
+    assert!(paid_claim_record.has_claimed);
+
+    // The second user has no payout account yet, so they are skipped
+    let skipped_claim_record = governance_rewards_test
+        .bench
+        .get_anchor_account::<ClaimData>(ClaimData::get_address(
+            users[1].pubkey(),
+            distribution_cookie.address,
+        ))
+        .await;
+    assert!(!skipped_claim_record.has_claimed);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_crank_claims_skips_frozen_payout_account() -> TestOutcome {
+    // Arrange
+    let mut governance_rewards_test = GovernanceRewardsTest::start_new().await;
+    let users = [Keypair::new(), Keypair::new()];
+    let realm_cookie = governance_rewards_test.governance.with_realm().await?;
+    let token_mint = governance_rewards_test.bench.with_mint().await?;
+
+    governance_rewards_test.bench.set_unix_time(5).await;
+
+    let key_cookie = governance_rewards_test.with_distribution_keypair();
+    let distribution_cookie = governance_rewards_test
+        .with_funded_distribution(&realm_cookie, &key_cookie, 10)
+        .await?;
+    let target_payout = distribution_cookie.funding[0];
+
+    let mut token_accounts = vec![];
+    for user in &users {
+        let vwr = governance_rewards_test
+            .with_dummy_voter_weight_record(
+                &VoterWeightRecord::create_test(
+                    realm_cookie.address,
+                    token_mint.address,
+                    user.pubkey(),
+                    distribution_cookie.address,
+                    10,
+                    Some(u64::MAX),
+                ),
+                distribution_cookie.account.voter_weight_program,
+            )
+            .await?;
+
+        governance_rewards_test
+            .with_registrant(&distribution_cookie, &vwr)
+            .await?;
+        let token_account = governance_rewards_test
+            .bench
+            .create_associated_token_account(user.pubkey(), target_payout.mint)
+            .await?;
+        token_accounts.push(token_account.address);
+    }
+
+    governance_rewards_test.bench.set_unix_time(11).await;
+
+    let mut frozen = governance_rewards_test
+        .bench
+        .get_token_account(&token_accounts[0])
+        .await
+        .unwrap();
+    frozen.state = AccountState::Frozen;
+    let mut data = vec![0; spl_token::state::Account::LEN];
+    frozen.pack_into_slice(&mut data);
+    governance_rewards_test
+        .bench
+        .set_account(data, token_accounts[0], spl_token::id())
+        .await?;
+
+    let targets: Vec<CrankTarget> = users
+        .iter()
+        .map(|user| CrankTarget {
+            claimant: user.pubkey(),
+            rewards_account: target_payout.address,
+            to_account: get_associated_token_address(&user.pubkey(), &target_payout.mint),
+        })
+        .collect();
+
+    // Act
+    governance_rewards_test
+        .crank_claims(&distribution_cookie, &targets)
+        .await?;
+
+    // Assert
+    let skipped_claim_record = governance_rewards_test
+        .bench
+        .get_anchor_account::<ClaimData>(ClaimData::get_address(
+            users[0].pubkey(),
+            distribution_cookie.address,
+        ))
+        .await;
+    assert!(!skipped_claim_record.has_claimed);
+
+    let paid_token_account = governance_rewards_test
+        .bench
+        .get_token_account(&token_accounts[1])
+        .await
+        .unwrap();
+    assert_eq!(paid_token_account.amount, 50);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_crank_claims_early_err() -> TestOutcome {
+    // Arrange
+    let mut governance_rewards_test = GovernanceRewardsTest::start_new().await;
+    let user = Keypair::new();
+    let realm_cookie = governance_rewards_test.governance.with_realm().await?;
+    let key_cookie = governance_rewards_test.with_distribution_keypair();
+    let distribution_cookie = governance_rewards_test
+        .with_funded_distribution(&realm_cookie, &key_cookie, u64::MAX)
+        .await?;
+    let target_payout = distribution_cookie.funding[0];
+
+    // Act
+    let err = governance_rewards_test
+        .crank_claims(
+            &distribution_cookie,
+            &[CrankTarget {
+                claimant: user.pubkey(),
+                rewards_account: target_payout.address,
+                to_account: get_associated_token_address(&user.pubkey(), &target_payout.mint),
+            }],
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_governance_rewards_err(err, GovernanceRewardsError::NotInClaimPeriod);
+
+    Ok(())
+}
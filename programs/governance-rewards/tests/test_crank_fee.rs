@@ -0,0 +1,437 @@
+use crate::program_test::governance_rewards_test::{
+    DistributionCookie, DistributionKeyCookie, GovernanceRewardsTest, PreferenceCookie,
+};
+use crate::program_test::governance_test::RealmCookie;
+use crate::program_test::tools::assert_governance_rewards_err;
+use governance_rewards::{
+    error::GovernanceRewardsError,
+    state::{
+        claim_data::ClaimData,
+        crank_fee::CrankFee,
+        preferences::{ResolutionPreference, UserPreferences},
+    },
+};
+use solana_program_test::tokio;
+use solana_sdk::{signature::Keypair, signer::Signer, transport::TransportError};
+
+mod program_test;
+
+type TestOutcome = Result<(), TransportError>;
+
+/**
+ * Creates a distribution funded with 100 tokens and `crank_fee`, registering `user` with
+ * wallet preferences.
+ */
+async fn with_crank_fee_user(
+    governance_rewards_test: &mut GovernanceRewardsTest,
+    realm_cookie: &RealmCookie,
+    key_cookie: &DistributionKeyCookie,
+    crank_fee: CrankFee,
+    user: &Keypair,
+) -> Result<(DistributionCookie, PreferenceCookie), TransportError> {
+    governance_rewards_test.bench.set_unix_time(5).await;
+
+    let funding_mint = governance_rewards_test.bench.with_mint().await?;
+    let funding_account = governance_rewards_test
+        .with_owned_tokens(&funding_mint, key_cookie, 100)
+        .await?;
+    let distribution_cookie = governance_rewards_test
+        .with_crank_fee_distribution(realm_cookie, key_cookie, 10, &[&funding_account], crank_fee)
+        .await?;
+
+    let preferences = governance_rewards_test
+        .with_preferences(
+            &UserPreferences {
+                preferred_mints: vec![],
+                resolution_preference: ResolutionPreference::Wallet,
+                ..Default::default()
+            },
+            realm_cookie,
+            user.pubkey(),
+        )
+        .await?;
+
+    governance_rewards_test
+        .with_registered_user(&distribution_cookie, user.pubkey(), 10)
+        .await?;
+
+    governance_rewards_test.bench.set_unix_time(11).await;
+
+    governance_rewards_test
+        .bench
+        .create_associated_token_account(user.pubkey(), funding_mint.address)
+        .await?;
+
+    Ok((distribution_cookie, preferences))
+}
+
+#[tokio::test]
+async fn test_claim_pays_token_crank_fee() -> TestOutcome {
+    // Arrange
+    let mut governance_rewards_test = GovernanceRewardsTest::start_new().await;
+    let user = Keypair::new();
+    let realm_cookie = governance_rewards_test.governance.with_realm().await?;
+    let key_cookie = governance_rewards_test.with_distribution_keypair();
+
+    let (distribution_cookie, preferences) = with_crank_fee_user(
+        &mut governance_rewards_test,
+        &realm_cookie,
+        &key_cookie,
+        CrankFee::Tokens {
+            option: 0,
+            amount: 5,
+        },
+        &user,
+    )
+    .await?;
+    let target_payout = distribution_cookie.funding[0];
+    let caller_token_account = governance_rewards_test
+        .bench
+        .with_token_account(&target_payout.mint)
+        .await?;
+
+    // Act
+    governance_rewards_test
+        .claim_using_ix(
+            &user,
+            target_payout.address,
+            target_payout.mint,
+            &distribution_cookie,
+            &preferences,
+            |ix| {
+                ix.accounts
+                    .extend(governance_rewards_client::crank_fee_accounts(
+                        target_payout.address,
+                        caller_token_account.address,
+                    ))
+            },
+            None,
+        )
+        .await?;
+
+    // Assert
+    // One registrant reserves two fees of 5, leaving 90 to distribute
+    let user_token_account = governance_rewards_test
+        .bench
+        .get_token_account(&preferences.resolution.payout_address(
+            user.pubkey(),
+            target_payout.mint,
+            realm_cookie.address,
+        ))
+        .await
+        .unwrap();
+    assert_eq!(user_token_account.amount, 90);
+
+    let caller_token_account = governance_rewards_test
+        .bench
+        .get_token_account(&caller_token_account.address)
+        .await
+        .unwrap();
+    assert_eq!(caller_token_account.amount, 5);
+
+    let distribution = governance_rewards_test
+        .get_distribution_account(distribution_cookie.address)
+        .await;
+    assert_eq!(distribution.registrant_count, 1);
+    assert_eq!(distribution.crank_fees_paid, 5);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_register_with_unfunded_crank_fee_reserve_err() -> TestOutcome {
+    // Arrange
+    let mut governance_rewards_test = GovernanceRewardsTest::start_new().await;
+    let user = Keypair::new();
+    let realm_cookie = governance_rewards_test.governance.with_realm().await?;
+    let key_cookie = governance_rewards_test.with_distribution_keypair();
+
+    // Act
+    // One registrant reserves two fees of 60, more than the 100 funding the option
+    let err = with_crank_fee_user(
+        &mut governance_rewards_test,
+        &realm_cookie,
+        &key_cookie,
+        CrankFee::Tokens {
+            option: 0,
+            amount: 60,
+        },
+        &user,
+    )
+    .await
+    .err()
+    .unwrap();
+
+    // Assert
+    assert_governance_rewards_err(err, GovernanceRewardsError::CrankFeeReserveExceedsFunding);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_claim_without_crank_fee_accounts_err() -> TestOutcome {
+    // Arrange
+    let mut governance_rewards_test = GovernanceRewardsTest::start_new().await;
+    let user = Keypair::new();
+    let realm_cookie = governance_rewards_test.governance.with_realm().await?;
+    let key_cookie = governance_rewards_test.with_distribution_keypair();
+
+    let (distribution_cookie, preferences) = with_crank_fee_user(
+        &mut governance_rewards_test,
+        &realm_cookie,
+        &key_cookie,
+        CrankFee::Tokens {
+            option: 0,
+            amount: 5,
+        },
+        &user,
+    )
+    .await?;
+    let target_payout = distribution_cookie.funding[0];
+
+    // Act
+    let err = governance_rewards_test
+        .claim(
+            &user,
+            target_payout.address,
+            target_payout.mint,
+            &distribution_cookie,
+            &preferences,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_governance_rewards_err(err, GovernanceRewardsError::MissingCrankFeeAccounts);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_reclaim_user_data_pays_lamport_crank_fee() -> TestOutcome {
+    // Arrange
+    let mut governance_rewards_test = GovernanceRewardsTest::start_new().await;
+    let user = Keypair::new();
+    let realm_cookie = governance_rewards_test.governance.with_realm().await?;
+    let key_cookie = governance_rewards_test.with_distribution_keypair();
+
+    let (distribution_cookie, preferences) = with_crank_fee_user(
+        &mut governance_rewards_test,
+        &realm_cookie,
+        &key_cookie,
+        CrankFee::Lamports { amount: 1000 },
+        &user,
+    )
+    .await?;
+    governance_rewards_test
+        .bench
+        .with_lamports(&distribution_cookie.address, 5000)
+        .await?;
+    let funded_lamports = governance_rewards_test
+        .bench
+        .get_account(&distribution_cookie.address)
+        .await
+        .unwrap()
+        .lamports;
+
+    let target_payout = distribution_cookie.funding[0];
+    governance_rewards_test
+        .claim(
+            &user,
+            target_payout.address,
+            target_payout.mint,
+            &distribution_cookie,
+            &preferences,
+        )
+        .await?;
+
+    // Act
+    governance_rewards_test
+        .reclaim_user_data(user.pubkey(), &distribution_cookie, &[])
+        .await?;
+
+    // Assert
+    let claim_data = governance_rewards_test
+        .bench
+        .get_account(&ClaimData::get_address(
+            user.pubkey(),
+            distribution_cookie.address,
+        ))
+        .await;
+    assert!(claim_data.is_none());
+
+    let distribution_lamports = governance_rewards_test
+        .bench
+        .get_account(&distribution_cookie.address)
+        .await
+        .unwrap()
+        .lamports;
+    assert_eq!(distribution_lamports, funded_lamports - 2000);
+
+    let distribution = governance_rewards_test
+        .get_distribution_account(distribution_cookie.address)
+        .await;
+    assert_eq!(distribution.crank_fees_paid, 2000);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_reclaim_user_data_pays_token_crank_fee_after_sweep() -> TestOutcome {
+    // Arrange
+    let mut governance_rewards_test = GovernanceRewardsTest::start_new().await;
+    let user = Keypair::new();
+    let realm_cookie = governance_rewards_test.governance.with_realm().await?;
+    let key_cookie = governance_rewards_test.with_distribution_keypair();
+
+    let (distribution_cookie, preferences) = with_crank_fee_user(
+        &mut governance_rewards_test,
+        &realm_cookie,
+        &key_cookie,
+        CrankFee::Tokens {
+            option: 0,
+            amount: 5,
+        },
+        &user,
+    )
+    .await?;
+    let target_payout = distribution_cookie.funding[0];
+    let caller_token_account = governance_rewards_test
+        .bench
+        .with_token_account(&target_payout.mint)
+        .await?;
+    let fee_accounts = governance_rewards_client::crank_fee_accounts(
+        target_payout.address,
+        caller_token_account.address,
+    );
+
+    governance_rewards_test
+        .claim_using_ix(
+            &user,
+            target_payout.address,
+            target_payout.mint,
+            &distribution_cookie,
+            &preferences,
+            |ix| ix.accounts.extend(fee_accounts.clone()),
+            None,
+        )
+        .await?;
+
+    let to_receive = governance_rewards_test
+        .bench
+        .with_token_account(&target_payout.mint)
+        .await?;
+    governance_rewards_test
+        .sweep_funds(&distribution_cookie, 0, &to_receive)
+        .await?;
+
+    // Act
+    governance_rewards_test
+        .reclaim_user_data(user.pubkey(), &distribution_cookie, &fee_accounts)
+        .await?;
+
+    // Assert
+    // The sweep leaves the second fee of 5 reserved for reclaiming the user's data
+    let to_receive = governance_rewards_test
+        .bench
+        .get_token_account(&to_receive.address)
+        .await
+        .unwrap();
+    assert_eq!(to_receive.amount, 0);
+
+    let caller_token_account = governance_rewards_test
+        .bench
+        .get_token_account(&caller_token_account.address)
+        .await
+        .unwrap();
+    assert_eq!(caller_token_account.amount, 10);
+
+    let wallet = governance_rewards_test
+        .bench
+        .get_token_account(&target_payout.address)
+        .await
+        .unwrap();
+    assert_eq!(wallet.amount, 0);
+
+    let distribution = governance_rewards_test
+        .get_distribution_account(distribution_cookie.address)
+        .await;
+    assert_eq!(distribution.crank_fees_paid, 10);
+    let option = distribution.distribution_options[0].unwrap();
+    assert_eq!(option.total_paid, 100);
+    assert_eq!(option.total_reclaimed, 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_lamport_crank_fee_skipped_when_unfunded() -> TestOutcome {
+    // Arrange
+    let mut governance_rewards_test = GovernanceRewardsTest::start_new().await;
+    let user = Keypair::new();
+    let realm_cookie = governance_rewards_test.governance.with_realm().await?;
+    let key_cookie = governance_rewards_test.with_distribution_keypair();
+
+    let (distribution_cookie, preferences) = with_crank_fee_user(
+        &mut governance_rewards_test,
+        &realm_cookie,
+        &key_cookie,
+        CrankFee::Lamports { amount: 1000 },
+        &user,
+    )
+    .await?;
+    let target_payout = distribution_cookie.funding[0];
+
+    // Act
+    governance_rewards_test
+        .claim(
+            &user,
+            target_payout.address,
+            target_payout.mint,
+            &distribution_cookie,
+            &preferences,
+        )
+        .await?;
+
+    // Assert
+    let distribution = governance_rewards_test
+        .get_distribution_account(distribution_cookie.address)
+        .await;
+    assert_eq!(
+        distribution.total_vote_weight_claimed,
+        distribution.total_vote_weight
+    );
+    assert_eq!(distribution.crank_fees_paid, 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_create_distribution_with_invalid_crank_fee_err() -> TestOutcome {
+    // Arrange
+    let mut governance_rewards_test = GovernanceRewardsTest::start_new().await;
+    let realm_cookie = governance_rewards_test.governance.with_realm().await?;
+    let key_cookie = governance_rewards_test.with_distribution_keypair();
+
+    // Act
+    let err = governance_rewards_test
+        .with_crank_fee_distribution(
+            &realm_cookie,
+            &key_cookie,
+            u64::MAX,
+            &[],
+            CrankFee::Tokens {
+                option: 0,
+                amount: 5,
+            },
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_governance_rewards_err(err, GovernanceRewardsError::InvalidCrankFee);
+
+    Ok(())
+}
@@ -0,0 +1,169 @@
+use crate::program_test::governance_rewards_test::{
+    DistributionCookie, GovernanceRewardsTest, VoterWeightRecordCookie,
+};
+use crate::program_test::tools::assert_governance_rewards_err;
+use governance_rewards::{error::GovernanceRewardsError, state::claim_data::ClaimData};
+use solana_program_test::tokio;
+use solana_sdk::{signature::Keypair, signer::Signer, transport::TransportError};
+
+mod program_test;
+
+type TestOutcome = Result<(), TransportError>;
+
+/**
+ * Creates a distribution closing registration at `registration_cutoff`, registering
+ * `user` with a vote weight of 10.
+ */
+async fn with_user_distribution(
+    governance_rewards_test: &mut GovernanceRewardsTest,
+    registration_cutoff: u64,
+    user: &Keypair,
+) -> Result<(DistributionCookie, VoterWeightRecordCookie), TransportError> {
+    let realm_cookie = governance_rewards_test.governance.with_realm().await?;
+    let key_cookie = governance_rewards_test.with_distribution_keypair();
+    let distribution_cookie = governance_rewards_test
+        .with_funded_distribution(&realm_cookie, &key_cookie, registration_cutoff)
+        .await?;
+    let vwr = governance_rewards_test
+        .with_registered_user(&distribution_cookie, user.pubkey(), 10)
+        .await?;
+
+    Ok((distribution_cookie, vwr))
+}
+
+#[tokio::test]
+async fn test_deregister() -> TestOutcome {
+    // Arrange
+    let mut governance_rewards_test = GovernanceRewardsTest::start_new().await;
+    let user = Keypair::new();
+    let (distribution_cookie, vwr) =
+        with_user_distribution(&mut governance_rewards_test, u64::max_value(), &user).await?;
+
+    // Act
+    governance_rewards_test
+        .deregister(&distribution_cookie, vwr.user, &user)
+        .await?;
+
+    // Assert
+    let distribution_record = governance_rewards_test
+        .get_distribution_account(distribution_cookie.address)
+        .await;
+
+    assert_eq!(distribution_record.total_vote_weight, 0);
+    assert_eq!(
+        distribution_record.distribution_options[0]
+            .unwrap()
+            .total_vote_weight,
+        0
+    );
+
+    let claim_data = governance_rewards_test
+        .bench
+        .get_account(&ClaimData::get_address(
+            vwr.user,
+            distribution_cookie.address,
+        ))
+        .await;
+    assert!(claim_data.is_none());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_deregister_by_admin() -> TestOutcome {
+    // Arrange
+    let mut governance_rewards_test = GovernanceRewardsTest::start_new().await;
+    let user = Keypair::new();
+    let (distribution_cookie, vwr) =
+        with_user_distribution(&mut governance_rewards_test, u64::max_value(), &user).await?;
+
+    // Act
+    governance_rewards_test
+        .deregister(&distribution_cookie, vwr.user, &distribution_cookie.admin)
+        .await?;
+
+    // Assert
+    let distribution_record = governance_rewards_test
+        .get_distribution_account(distribution_cookie.address)
+        .await;
+
+    assert_eq!(distribution_record.total_vote_weight, 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_deregister_by_other_user_err() -> TestOutcome {
+    // Arrange
+    let mut governance_rewards_test = GovernanceRewardsTest::start_new().await;
+    let user = Keypair::new();
+    let (distribution_cookie, vwr) =
+        with_user_distribution(&mut governance_rewards_test, u64::max_value(), &user).await?;
+
+    // Act
+    let err = governance_rewards_test
+        .deregister(&distribution_cookie, vwr.user, &Keypair::new())
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_governance_rewards_err(err, GovernanceRewardsError::CannotDeregister);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_deregister_after_registration_err() -> TestOutcome {
+    // Arrange
+    let mut governance_rewards_test = GovernanceRewardsTest::start_new().await;
+    let user = Keypair::new();
+    governance_rewards_test.bench.set_unix_time(5).await;
+    let (distribution_cookie, vwr) =
+        with_user_distribution(&mut governance_rewards_test, 10, &user).await?;
+    governance_rewards_test.bench.set_unix_time(11).await;
+
+    // Act
+    let err = governance_rewards_test
+        .deregister(&distribution_cookie, vwr.user, &user)
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_governance_rewards_err(err, GovernanceRewardsError::RegistrationOver);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_deregister_with_invalid_claim_option_err() -> TestOutcome {
+    // Arrange
+    let mut governance_rewards_test = GovernanceRewardsTest::start_new().await;
+    let user = Keypair::new();
+    let (distribution_cookie, vwr) =
+        with_user_distribution(&mut governance_rewards_test, u64::max_value(), &user).await?;
+
+    let claim_data_address = ClaimData::get_address(vwr.user, distribution_cookie.address);
+    let mut claim_data = governance_rewards_test
+        .bench
+        .get_anchor_account::<ClaimData>(claim_data_address)
+        .await;
+    claim_data.claim_option = 7;
+    governance_rewards_test
+        .bench
+        .set_anchor_account(&claim_data, claim_data_address, governance_rewards::id())
+        .await?;
+
+    // Act
+    let err = governance_rewards_test
+        .deregister(&distribution_cookie, vwr.user, &user)
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_governance_rewards_err(err, GovernanceRewardsError::InvalidOptionIndex);
+
+    Ok(())
+}
@@ -1,5 +1,17 @@
 use crate::program_test::governance_rewards_test::GovernanceRewardsTest;
-use solana_program::program_pack::Pack;
+use crate::program_test::governance_test::RealmCookie;
+use crate::program_test::program_test_bench::TokenAccountCookie;
+use crate::program_test::tools::{assert_anchor_err, assert_governance_rewards_err};
+use governance_rewards::{
+    error::GovernanceRewardsError,
+    state::{
+        escrow::{find_escrow_owner, EscrowState},
+        escrow_admin::ESCROW_RELEASE_PROPOSAL_LIFETIME,
+        preferences::find_escrow_address,
+        release_schedule::ReleaseLimit,
+    },
+};
+use solana_program::{program_pack::Pack, pubkey::Pubkey, system_program};
 use solana_program_test::tokio;
 use solana_sdk::{signature::Keypair, signer::Signer, transport::TransportError};
 
@@ -75,3 +87,1479 @@ async fn test_claim_from_escrow() -> TestOutcome {
 
     Ok(())
 }
+
+async fn with_escrow_balance(
+    governance_rewards_test: &GovernanceRewardsTest,
+    escrow_address: Pubkey,
+    amount: u64,
+) -> Result<(), TransportError> {
+    let account_info = governance_rewards_test
+        .bench
+        .get_account(&escrow_address)
+        .await
+        .unwrap();
+    let mut data = governance_rewards_test
+        .bench
+        .get_token_account(&escrow_address)
+        .await
+        .unwrap();
+    data.amount = amount;
+    let mut data_pack = Vec::new();
+    data_pack.resize(anchor_spl::token::spl_token::state::Account::LEN, 0);
+    data.pack_into_slice(&mut data_pack);
+    governance_rewards_test
+        .bench
+        .set_account(data_pack, escrow_address, account_info.owner)
+        .await
+}
+
+#[tokio::test]
+async fn test_release_timelocked_escrow_after_unlock() -> TestOutcome {
+    // Arrange
+    let mut governance_rewards_test = GovernanceRewardsTest::start_new().await;
+    let user = Keypair::new();
+    let realm_cookie = governance_rewards_test.governance.with_realm().await?;
+    let mint = governance_rewards_test.bench.with_mint().await?;
+    let admin = Keypair::new();
+    let unlock_ts = 100;
+    let escrow_address = governance_rewards_test
+        .with_timelocked_escrow(
+            &user.pubkey(),
+            &mint.address,
+            &realm_cookie,
+            &admin.pubkey(),
+            unlock_ts,
+        )
+        .await?;
+    with_escrow_balance(&governance_rewards_test, escrow_address, 100).await?;
+
+    let recipient = governance_rewards_test
+        .bench
+        .create_associated_token_account(user.pubkey(), mint.address)
+        .await?;
+
+    governance_rewards_test
+        .bench
+        .set_unix_time(unlock_ts as i64)
+        .await;
+
+    // Act
+    governance_rewards_test
+        .release_timelocked_escrow(
+            &escrow_address,
+            &user.pubkey(),
+            &realm_cookie,
+            &recipient,
+            &admin.pubkey(),
+            &user,
+            unlock_ts,
+            100,
+        )
+        .await?;
+
+    // Assert
+    let token_account = governance_rewards_test
+        .bench
+        .get_token_account(&recipient.address)
+        .await
+        .unwrap();
+    assert_eq!(token_account.amount, 100);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_release_timelocked_escrow_before_unlock_err() -> TestOutcome {
+    // Arrange
+    let mut governance_rewards_test = GovernanceRewardsTest::start_new().await;
+    let user = Keypair::new();
+    let realm_cookie = governance_rewards_test.governance.with_realm().await?;
+    let mint = governance_rewards_test.bench.with_mint().await?;
+    let admin = Keypair::new();
+    let unlock_ts = 100;
+    let escrow_address = governance_rewards_test
+        .with_timelocked_escrow(
+            &user.pubkey(),
+            &mint.address,
+            &realm_cookie,
+            &admin.pubkey(),
+            unlock_ts,
+        )
+        .await?;
+    with_escrow_balance(&governance_rewards_test, escrow_address, 100).await?;
+
+    let recipient = governance_rewards_test
+        .bench
+        .create_associated_token_account(user.pubkey(), mint.address)
+        .await?;
+
+    governance_rewards_test.bench.set_unix_time(50).await;
+
+    // Act
+    let err = governance_rewards_test
+        .release_timelocked_escrow(
+            &escrow_address,
+            &user.pubkey(),
+            &realm_cookie,
+            &recipient,
+            &admin.pubkey(),
+            &user,
+            unlock_ts,
+            100,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_governance_rewards_err(err, GovernanceRewardsError::EscrowLocked);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_admin_releases_timelocked_escrow_before_unlock() -> TestOutcome {
+    // Arrange
+    let mut governance_rewards_test = GovernanceRewardsTest::start_new().await;
+    let user = Keypair::new();
+    let realm_cookie = governance_rewards_test.governance.with_realm().await?;
+    let mint = governance_rewards_test.bench.with_mint().await?;
+    let admin = Keypair::new();
+    let unlock_ts = 100;
+    let escrow_address = governance_rewards_test
+        .with_timelocked_escrow(
+            &user.pubkey(),
+            &mint.address,
+            &realm_cookie,
+            &admin.pubkey(),
+            unlock_ts,
+        )
+        .await?;
+    with_escrow_balance(&governance_rewards_test, escrow_address, 100).await?;
+
+    let recipient = governance_rewards_test
+        .bench
+        .create_associated_token_account(user.pubkey(), mint.address)
+        .await?;
+
+    governance_rewards_test.bench.set_unix_time(50).await;
+
+    // Act
+    governance_rewards_test
+        .release_timelocked_escrow(
+            &escrow_address,
+            &user.pubkey(),
+            &realm_cookie,
+            &recipient,
+            &admin.pubkey(),
+            &admin,
+            unlock_ts,
+            40,
+        )
+        .await?;
+
+    // Assert
+    let token_account = governance_rewards_test
+        .bench
+        .get_token_account(&recipient.address)
+        .await
+        .unwrap();
+    assert_eq!(token_account.amount, 40);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_close_escrow() -> TestOutcome {
+    // Arrange
+    let mut governance_rewards_test = GovernanceRewardsTest::start_new().await;
+    let user = Keypair::new();
+    let realm_cookie = governance_rewards_test.governance.with_realm().await?;
+    let mint = governance_rewards_test.bench.with_mint().await?;
+    let admin = Keypair::new();
+    let escrow_address = governance_rewards_test
+        .with_escrow(
+            &user.pubkey(),
+            &mint.address,
+            &realm_cookie,
+            &admin.pubkey(),
+        )
+        .await?;
+
+    let escrow_state = governance_rewards_test
+        .bench
+        .get_anchor_account::<EscrowState>(EscrowState::get_address(escrow_address))
+        .await;
+    assert_eq!(
+        escrow_state.payer,
+        governance_rewards_test.bench.payer.pubkey()
+    );
+    assert_eq!(
+        escrow_state.bump,
+        EscrowState::find_address(escrow_address).1
+    );
+    assert_eq!(
+        escrow_state.escrow_owner_bump,
+        find_escrow_owner(realm_cookie.address).1
+    );
+    assert_eq!(
+        escrow_state.escrow_bump,
+        find_escrow_address(
+            realm_cookie.address,
+            admin.pubkey(),
+            user.pubkey(),
+            mint.address
+        )
+        .1
+    );
+
+    // Act
+    governance_rewards_test
+        .close_escrow(
+            &escrow_address,
+            &user.pubkey(),
+            &realm_cookie,
+            &admin.pubkey(),
+            &governance_rewards_test.bench.payer.pubkey(),
+            &user,
+            None,
+        )
+        .await?;
+
+    // Assert
+    let escrow_account = governance_rewards_test
+        .bench
+        .get_account(&escrow_address)
+        .await;
+    assert!(escrow_account.is_none());
+
+    let escrow_state_account = governance_rewards_test
+        .bench
+        .get_account(&EscrowState::get_address(escrow_address))
+        .await;
+    assert!(escrow_state_account.is_none());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_transfer_from_escrow_without_state() -> TestOutcome {
+    // Arrange
+    let mut governance_rewards_test = GovernanceRewardsTest::start_new().await;
+    let user = Keypair::new();
+    let realm_cookie = governance_rewards_test.governance.with_realm().await?;
+    let mint = governance_rewards_test.bench.with_mint().await?;
+    let admin = Keypair::new();
+    let escrow_address = governance_rewards_test
+        .with_escrow(
+            &user.pubkey(),
+            &mint.address,
+            &realm_cookie,
+            &admin.pubkey(),
+        )
+        .await?;
+    with_escrow_balance(&governance_rewards_test, escrow_address, 100).await?;
+
+    // Escrows created before escrow state was recorded have none, so their bumps are derived
+    governance_rewards_test
+        .bench
+        .set_account(
+            vec![],
+            EscrowState::get_address(escrow_address),
+            system_program::id(),
+        )
+        .await?;
+
+    let recipient = governance_rewards_test
+        .bench
+        .create_associated_token_account(user.pubkey(), mint.address)
+        .await?;
+
+    // Act
+    governance_rewards_test
+        .transfer_from_escrow(
+            &escrow_address,
+            &user,
+            &realm_cookie,
+            &recipient,
+            &admin,
+            100,
+        )
+        .await?;
+
+    // Assert
+    let token_account = governance_rewards_test
+        .bench
+        .get_token_account(&recipient.address)
+        .await
+        .unwrap();
+    assert_eq!(token_account.amount, 100);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_close_timelocked_escrow_by_admin() -> TestOutcome {
+    // Arrange
+    let mut governance_rewards_test = GovernanceRewardsTest::start_new().await;
+    let user = Keypair::new();
+    let realm_cookie = governance_rewards_test.governance.with_realm().await?;
+    let mint = governance_rewards_test.bench.with_mint().await?;
+    let admin = Keypair::new();
+    let unlock_ts = 100;
+    let escrow_address = governance_rewards_test
+        .with_timelocked_escrow(
+            &user.pubkey(),
+            &mint.address,
+            &realm_cookie,
+            &admin.pubkey(),
+            unlock_ts,
+        )
+        .await?;
+
+    // Act
+    governance_rewards_test
+        .close_escrow(
+            &escrow_address,
+            &user.pubkey(),
+            &realm_cookie,
+            &admin.pubkey(),
+            &governance_rewards_test.bench.payer.pubkey(),
+            &admin,
+            Some(unlock_ts),
+        )
+        .await?;
+
+    // Assert
+    let escrow_account = governance_rewards_test
+        .bench
+        .get_account(&escrow_address)
+        .await;
+    assert!(escrow_account.is_none());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_close_escrow_not_empty_err() -> TestOutcome {
+    // Arrange
+    let mut governance_rewards_test = GovernanceRewardsTest::start_new().await;
+    let user = Keypair::new();
+    let realm_cookie = governance_rewards_test.governance.with_realm().await?;
+    let mint = governance_rewards_test.bench.with_mint().await?;
+    let admin = Keypair::new();
+    let escrow_address = governance_rewards_test
+        .with_escrow(
+            &user.pubkey(),
+            &mint.address,
+            &realm_cookie,
+            &admin.pubkey(),
+        )
+        .await?;
+    with_escrow_balance(&governance_rewards_test, escrow_address, 1).await?;
+
+    // Act
+    let err = governance_rewards_test
+        .close_escrow(
+            &escrow_address,
+            &user.pubkey(),
+            &realm_cookie,
+            &admin.pubkey(),
+            &governance_rewards_test.bench.payer.pubkey(),
+            &user,
+            None,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_governance_rewards_err(err, GovernanceRewardsError::EscrowNotEmpty);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_close_escrow_wrong_rent_recipient_err() -> TestOutcome {
+    // Arrange
+    let mut governance_rewards_test = GovernanceRewardsTest::start_new().await;
+    let user = Keypair::new();
+    let realm_cookie = governance_rewards_test.governance.with_realm().await?;
+    let mint = governance_rewards_test.bench.with_mint().await?;
+    let admin = Keypair::new();
+    let escrow_address = governance_rewards_test
+        .with_escrow(
+            &user.pubkey(),
+            &mint.address,
+            &realm_cookie,
+            &admin.pubkey(),
+        )
+        .await?;
+
+    // Act
+    let err = governance_rewards_test
+        .close_escrow(
+            &escrow_address,
+            &user.pubkey(),
+            &realm_cookie,
+            &admin.pubkey(),
+            &user.pubkey(),
+            &user,
+            None,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_governance_rewards_err(err, GovernanceRewardsError::WrongRentPayer);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_close_escrow_by_other_user_err() -> TestOutcome {
+    // Arrange
+    let mut governance_rewards_test = GovernanceRewardsTest::start_new().await;
+    let user = Keypair::new();
+    let other_user = Keypair::new();
+    let realm_cookie = governance_rewards_test.governance.with_realm().await?;
+    let mint = governance_rewards_test.bench.with_mint().await?;
+    let admin = Keypair::new();
+    let escrow_address = governance_rewards_test
+        .with_escrow(
+            &user.pubkey(),
+            &mint.address,
+            &realm_cookie,
+            &admin.pubkey(),
+        )
+        .await?;
+
+    // Act
+    let err = governance_rewards_test
+        .close_escrow(
+            &escrow_address,
+            &user.pubkey(),
+            &realm_cookie,
+            &admin.pubkey(),
+            &governance_rewards_test.bench.payer.pubkey(),
+            &other_user,
+            None,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_governance_rewards_err(err, GovernanceRewardsError::CannotCloseEscrow);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_transfer_from_escrow_with_consent() -> TestOutcome {
+    // Arrange
+    let mut governance_rewards_test = GovernanceRewardsTest::start_new().await;
+    let user = Keypair::new();
+    let realm_cookie = governance_rewards_test.governance.with_realm().await?;
+    let mint = governance_rewards_test.bench.with_mint().await?;
+    let admin = Keypair::new();
+    let escrow_address = governance_rewards_test
+        .with_escrow(
+            &user.pubkey(),
+            &mint.address,
+            &realm_cookie,
+            &admin.pubkey(),
+        )
+        .await?;
+    with_escrow_balance(&governance_rewards_test, escrow_address, 100).await?;
+
+    // Any token account of the mint, here one owned by the bench payer
+    let recipient = governance_rewards_test
+        .bench
+        .with_token_account(&mint.address)
+        .await?;
+
+    // Act
+    governance_rewards_test
+        .transfer_from_escrow_with_consent(
+            &escrow_address,
+            &user,
+            &realm_cookie,
+            &recipient,
+            &admin,
+            100,
+        )
+        .await?;
+
+    // Assert
+    let token_account = governance_rewards_test
+        .bench
+        .get_token_account(&recipient.address)
+        .await
+        .unwrap();
+    assert_eq!(token_account.amount, 100);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_transfer_from_escrow_with_consent_wrong_mint_err() -> TestOutcome {
+    // Arrange
+    let mut governance_rewards_test = GovernanceRewardsTest::start_new().await;
+    let user = Keypair::new();
+    let realm_cookie = governance_rewards_test.governance.with_realm().await?;
+    let mint = governance_rewards_test.bench.with_mint().await?;
+    let other_mint = governance_rewards_test.bench.with_mint().await?;
+    let admin = Keypair::new();
+    let escrow_address = governance_rewards_test
+        .with_escrow(
+            &user.pubkey(),
+            &mint.address,
+            &realm_cookie,
+            &admin.pubkey(),
+        )
+        .await?;
+    with_escrow_balance(&governance_rewards_test, escrow_address, 100).await?;
+
+    let recipient = governance_rewards_test
+        .bench
+        .with_token_account(&other_mint.address)
+        .await?;
+
+    // Act
+    let err = governance_rewards_test
+        .transfer_from_escrow_with_consent(
+            &escrow_address,
+            &user,
+            &realm_cookie,
+            &recipient,
+            &admin,
+            100,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_governance_rewards_err(err, GovernanceRewardsError::EscrowMintMismatch);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_deposit_from_escrow() -> TestOutcome {
+    // Arrange
+    let mut governance_rewards_test = GovernanceRewardsTest::start_new().await;
+    let user = governance_rewards_test.bench.with_wallet().await;
+    let realm_cookie = governance_rewards_test.governance.with_realm().await?;
+    let token_owner_record = governance_rewards_test
+        .governance
+        .with_token_owner_record(&realm_cookie, &user)
+        .await?;
+    let admin = Keypair::new();
+    let escrow_address = governance_rewards_test
+        .with_escrow(
+            &user.address,
+            &realm_cookie.community_mint_cookie.address,
+            &realm_cookie,
+            &admin.pubkey(),
+        )
+        .await?;
+    with_escrow_balance(&governance_rewards_test, escrow_address, 100).await?;
+
+    // Act
+    governance_rewards_test
+        .deposit_from_escrow(&escrow_address, user.address, &realm_cookie, &admin, 60)
+        .await?;
+
+    // Assert
+    let token_owner_record = governance_rewards_test
+        .governance
+        .get_token_owner_record(&token_owner_record.address)
+        .await;
+    assert_eq!(token_owner_record.governing_token_deposit_amount, 60);
+
+    let escrow_account = governance_rewards_test
+        .bench
+        .get_token_account(&escrow_address)
+        .await
+        .unwrap();
+    assert_eq!(escrow_account.amount, 40);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_deposit_from_escrow_without_token_owner_record_err() -> TestOutcome {
+    // Arrange
+    let mut governance_rewards_test = GovernanceRewardsTest::start_new().await;
+    let user = Keypair::new();
+    let realm_cookie = governance_rewards_test.governance.with_realm().await?;
+    let admin = Keypair::new();
+    let escrow_address = governance_rewards_test
+        .with_escrow(
+            &user.pubkey(),
+            &realm_cookie.community_mint_cookie.address,
+            &realm_cookie,
+            &admin.pubkey(),
+        )
+        .await?;
+    with_escrow_balance(&governance_rewards_test, escrow_address, 100).await?;
+
+    // Act
+    let err = governance_rewards_test
+        .deposit_from_escrow(&escrow_address, user.pubkey(), &realm_cookie, &admin, 100)
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_governance_rewards_err(err, GovernanceRewardsError::MissingTokenOwnerRecord);
+
+    Ok(())
+}
+
+async fn with_multisig_escrow(
+    governance_rewards_test: &mut GovernanceRewardsTest,
+    signers: &[&Keypair],
+    threshold: u8,
+) -> Result<(Keypair, RealmCookie, Pubkey, Pubkey, TokenAccountCookie), TransportError> {
+    let user = Keypair::new();
+    let realm_cookie = governance_rewards_test.governance.with_realm().await?;
+    let mint = governance_rewards_test.bench.with_mint().await?;
+    let config = governance_rewards_test
+        .with_escrow_admin_config(signers, threshold)
+        .await?;
+    let escrow_address = governance_rewards_test
+        .with_escrow(&user.pubkey(), &mint.address, &realm_cookie, &config)
+        .await?;
+    with_escrow_balance(governance_rewards_test, escrow_address, 100).await?;
+
+    let recipient = governance_rewards_test
+        .bench
+        .create_associated_token_account(user.pubkey(), mint.address)
+        .await?;
+
+    Ok((user, realm_cookie, config, escrow_address, recipient))
+}
+
+#[tokio::test]
+async fn test_execute_approved_escrow_release() -> TestOutcome {
+    // Arrange
+    let mut governance_rewards_test = GovernanceRewardsTest::start_new().await;
+    let signers = [Keypair::new(), Keypair::new(), Keypair::new()];
+    let (user, realm_cookie, config, escrow_address, recipient) = with_multisig_escrow(
+        &mut governance_rewards_test,
+        &[&signers[0], &signers[1], &signers[2]],
+        2,
+    )
+    .await?;
+
+    let proposal = governance_rewards_test
+        .propose_escrow_release(
+            &config,
+            &escrow_address,
+            &user.pubkey(),
+            &realm_cookie,
+            &recipient,
+            &signers[0],
+            60,
+        )
+        .await?;
+    governance_rewards_test
+        .approve_escrow_release(&config, &proposal, &signers[2])
+        .await?;
+
+    // Act
+    governance_rewards_test
+        .execute_escrow_release(
+            &config,
+            &proposal,
+            &escrow_address,
+            &user.pubkey(),
+            &realm_cookie,
+            &recipient,
+        )
+        .await?;
+
+    // Assert
+    let token_account = governance_rewards_test
+        .bench
+        .get_token_account(&recipient.address)
+        .await
+        .unwrap();
+    assert_eq!(token_account.amount, 60);
+
+    let proposal_account = governance_rewards_test.bench.get_account(&proposal).await;
+    assert!(proposal_account.is_none());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_execute_escrow_release_below_threshold_err() -> TestOutcome {
+    // Arrange
+    let mut governance_rewards_test = GovernanceRewardsTest::start_new().await;
+    let signers = [Keypair::new(), Keypair::new()];
+    let (user, realm_cookie, config, escrow_address, recipient) =
+        with_multisig_escrow(&mut governance_rewards_test, &[&signers[0], &signers[1]], 2).await?;
+
+    let proposal = governance_rewards_test
+        .propose_escrow_release(
+            &config,
+            &escrow_address,
+            &user.pubkey(),
+            &realm_cookie,
+            &recipient,
+            &signers[0],
+            100,
+        )
+        .await?;
+
+    // Act
+    let err = governance_rewards_test
+        .execute_escrow_release(
+            &config,
+            &proposal,
+            &escrow_address,
+            &user.pubkey(),
+            &realm_cookie,
+            &recipient,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_governance_rewards_err(err, GovernanceRewardsError::EscrowReleaseNotApproved);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_execute_expired_escrow_release_err() -> TestOutcome {
+    // Arrange
+    let mut governance_rewards_test = GovernanceRewardsTest::start_new().await;
+    let signers = [Keypair::new(), Keypair::new()];
+    let (user, realm_cookie, config, escrow_address, recipient) =
+        with_multisig_escrow(&mut governance_rewards_test, &[&signers[0], &signers[1]], 2).await?;
+
+    let proposal = governance_rewards_test
+        .propose_escrow_release(
+            &config,
+            &escrow_address,
+            &user.pubkey(),
+            &realm_cookie,
+            &recipient,
+            &signers[0],
+            100,
+        )
+        .await?;
+    governance_rewards_test
+        .approve_escrow_release(&config, &proposal, &signers[1])
+        .await?;
+    let clock = governance_rewards_test.bench.get_clock().await;
+    governance_rewards_test
+        .bench
+        .set_unix_time(clock.unix_timestamp + ESCROW_RELEASE_PROPOSAL_LIFETIME as i64 + 1)
+        .await;
+
+    // Act
+    let err = governance_rewards_test
+        .execute_escrow_release(
+            &config,
+            &proposal,
+            &escrow_address,
+            &user.pubkey(),
+            &realm_cookie,
+            &recipient,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_governance_rewards_err(err, GovernanceRewardsError::EscrowReleaseProposalExpired);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_approve_escrow_release_twice_err() -> TestOutcome {
+    // Arrange
+    let mut governance_rewards_test = GovernanceRewardsTest::start_new().await;
+    let signers = [Keypair::new(), Keypair::new()];
+    let (user, realm_cookie, config, escrow_address, recipient) =
+        with_multisig_escrow(&mut governance_rewards_test, &[&signers[0], &signers[1]], 2).await?;
+
+    let proposal = governance_rewards_test
+        .propose_escrow_release(
+            &config,
+            &escrow_address,
+            &user.pubkey(),
+            &realm_cookie,
+            &recipient,
+            &signers[0],
+            100,
+        )
+        .await?;
+
+    // Act
+    let err = governance_rewards_test
+        .approve_escrow_release(&config, &proposal, &signers[0])
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_governance_rewards_err(err, GovernanceRewardsError::EscrowReleaseAlreadyApproved);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_propose_escrow_release_by_non_signer_err() -> TestOutcome {
+    // Arrange
+    let mut governance_rewards_test = GovernanceRewardsTest::start_new().await;
+    let signers = [Keypair::new(), Keypair::new()];
+    let (user, realm_cookie, config, escrow_address, recipient) =
+        with_multisig_escrow(&mut governance_rewards_test, &[&signers[0], &signers[1]], 1).await?;
+
+    // Act
+    let err = governance_rewards_test
+        .propose_escrow_release(
+            &config,
+            &escrow_address,
+            &user.pubkey(),
+            &realm_cookie,
+            &recipient,
+            &Keypair::new(),
+            100,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_governance_rewards_err(err, GovernanceRewardsError::NotEscrowAdminSigner);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_create_escrow_admin_config_invalid_threshold_err() -> TestOutcome {
+    // Arrange
+    let governance_rewards_test = GovernanceRewardsTest::start_new().await;
+    let signers = [Keypair::new(), Keypair::new()];
+
+    // Act
+    let err = governance_rewards_test
+        .with_escrow_admin_config(&[&signers[0], &signers[1]], 3)
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_governance_rewards_err(err, GovernanceRewardsError::InvalidEscrowAdminThreshold);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_transfer_from_escrow_through_governance_proposal() -> TestOutcome {
+    // Arrange
+    let mut governance_rewards_test = GovernanceRewardsTest::start_new().await;
+    let user = Keypair::new();
+    let realm_cookie = governance_rewards_test.governance.with_realm().await?;
+    let governance_cookie = governance_rewards_test
+        .governance
+        .with_governance(&realm_cookie)
+        .await?;
+    let mint = governance_rewards_test.bench.with_mint().await?;
+    let escrow_address = governance_rewards_test
+        .with_escrow(
+            &user.pubkey(),
+            &mint.address,
+            &realm_cookie,
+            &governance_cookie.address,
+        )
+        .await?;
+    with_escrow_balance(&governance_rewards_test, escrow_address, 100).await?;
+
+    let recipient = governance_rewards_test
+        .bench
+        .create_associated_token_account(user.pubkey(), mint.address)
+        .await?;
+
+    let transfer_ix = GovernanceRewardsTest::transfer_from_escrow_ix(
+        &escrow_address,
+        &user.pubkey(),
+        &realm_cookie,
+        &recipient,
+        &governance_cookie.address,
+        100,
+    );
+
+    // Act
+    governance_rewards_test
+        .governance
+        .execute_through_proposal(&realm_cookie, &governance_cookie, transfer_ix)
+        .await?;
+
+    // Assert
+    let token_account = governance_rewards_test
+        .bench
+        .get_token_account(&recipient.address)
+        .await
+        .unwrap();
+    assert_eq!(token_account.amount, 100);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_transfer_from_escrow_limited_per_period() -> TestOutcome {
+    // Arrange
+    let mut governance_rewards_test = GovernanceRewardsTest::start_new().await;
+    let user = Keypair::new();
+    let realm_cookie = governance_rewards_test.governance.with_realm().await?;
+    let mint = governance_rewards_test.bench.with_mint().await?;
+    let admin = Keypair::new();
+    let escrow_address = governance_rewards_test
+        .with_escrow(
+            &user.pubkey(),
+            &mint.address,
+            &realm_cookie,
+            &admin.pubkey(),
+        )
+        .await?;
+    with_escrow_balance(&governance_rewards_test, escrow_address, 100).await?;
+
+    let recipient = governance_rewards_test
+        .bench
+        .create_associated_token_account(user.pubkey(), mint.address)
+        .await?;
+
+    governance_rewards_test.bench.set_unix_time(1000).await;
+    governance_rewards_test
+        .set_escrow_release_schedule(
+            &escrow_address,
+            &user.pubkey(),
+            &realm_cookie,
+            &admin,
+            ReleaseLimit::AmountPerPeriod {
+                amount: 40,
+                period: 100,
+            },
+        )
+        .await?;
+
+    governance_rewards_test
+        .transfer_from_escrow(
+            &escrow_address,
+            &user,
+            &realm_cookie,
+            &recipient,
+            &admin,
+            40,
+        )
+        .await?;
+
+    // Act
+    let err = governance_rewards_test
+        .transfer_from_escrow(&escrow_address, &user, &realm_cookie, &recipient, &admin, 1)
+        .await
+        .err()
+        .unwrap();
+
+    governance_rewards_test.bench.set_unix_time(1100).await;
+    governance_rewards_test
+        .transfer_from_escrow(
+            &escrow_address,
+            &user,
+            &realm_cookie,
+            &recipient,
+            &admin,
+            30,
+        )
+        .await?;
+
+    // Assert
+    assert_governance_rewards_err(err, GovernanceRewardsError::EscrowReleaseLimitExceeded);
+
+    let token_account = governance_rewards_test
+        .bench
+        .get_token_account(&recipient.address)
+        .await
+        .unwrap();
+    assert_eq!(token_account.amount, 70);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_transfer_from_escrow_limited_per_epoch() -> TestOutcome {
+    // Arrange
+    let mut governance_rewards_test = GovernanceRewardsTest::start_new().await;
+    let user = Keypair::new();
+    let realm_cookie = governance_rewards_test.governance.with_realm().await?;
+    let mint = governance_rewards_test.bench.with_mint().await?;
+    let admin = Keypair::new();
+    let escrow_address = governance_rewards_test
+        .with_escrow(
+            &user.pubkey(),
+            &mint.address,
+            &realm_cookie,
+            &admin.pubkey(),
+        )
+        .await?;
+    with_escrow_balance(&governance_rewards_test, escrow_address, 100).await?;
+
+    let recipient = governance_rewards_test
+        .bench
+        .create_associated_token_account(user.pubkey(), mint.address)
+        .await?;
+
+    governance_rewards_test
+        .set_escrow_release_schedule(
+            &escrow_address,
+            &user.pubkey(),
+            &realm_cookie,
+            &admin,
+            ReleaseLimit::PercentPerEpoch { basis_points: 2500 },
+        )
+        .await?;
+
+    governance_rewards_test
+        .transfer_from_escrow(
+            &escrow_address,
+            &user,
+            &realm_cookie,
+            &recipient,
+            &admin,
+            25,
+        )
+        .await?;
+
+    // Act
+    let err = governance_rewards_test
+        .transfer_from_escrow(&escrow_address, &user, &realm_cookie, &recipient, &admin, 1)
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_governance_rewards_err(err, GovernanceRewardsError::EscrowReleaseLimitExceeded);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_set_escrow_release_schedule_invalid_err() -> TestOutcome {
+    // Arrange
+    let mut governance_rewards_test = GovernanceRewardsTest::start_new().await;
+    let user = Keypair::new();
+    let realm_cookie = governance_rewards_test.governance.with_realm().await?;
+    let mint = governance_rewards_test.bench.with_mint().await?;
+    let admin = Keypair::new();
+    let escrow_address = governance_rewards_test
+        .with_escrow(
+            &user.pubkey(),
+            &mint.address,
+            &realm_cookie,
+            &admin.pubkey(),
+        )
+        .await?;
+
+    // Act
+    let err = governance_rewards_test
+        .set_escrow_release_schedule(
+            &escrow_address,
+            &user.pubkey(),
+            &realm_cookie,
+            &admin,
+            ReleaseLimit::PercentPerEpoch {
+                basis_points: 10_001,
+            },
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_governance_rewards_err(err, GovernanceRewardsError::InvalidReleaseSchedule);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_set_escrow_release_schedule_zero_amount_err() -> TestOutcome {
+    // Arrange
+    let mut governance_rewards_test = GovernanceRewardsTest::start_new().await;
+    let user = Keypair::new();
+    let realm_cookie = governance_rewards_test.governance.with_realm().await?;
+    let mint = governance_rewards_test.bench.with_mint().await?;
+    let admin = Keypair::new();
+    let escrow_address = governance_rewards_test
+        .with_escrow(
+            &user.pubkey(),
+            &mint.address,
+            &realm_cookie,
+            &admin.pubkey(),
+        )
+        .await?;
+
+    // Act
+    let err = governance_rewards_test
+        .set_escrow_release_schedule(
+            &escrow_address,
+            &user.pubkey(),
+            &realm_cookie,
+            &admin,
+            ReleaseLimit::AmountPerPeriod {
+                amount: 0,
+                period: 100,
+            },
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_governance_rewards_err(err, GovernanceRewardsError::InvalidReleaseSchedule);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_changing_escrow_release_schedule_keeps_period_releases() -> TestOutcome {
+    // Arrange
+    let mut governance_rewards_test = GovernanceRewardsTest::start_new().await;
+    let user = Keypair::new();
+    let realm_cookie = governance_rewards_test.governance.with_realm().await?;
+    let mint = governance_rewards_test.bench.with_mint().await?;
+    let admin = Keypair::new();
+    let escrow_address = governance_rewards_test
+        .with_escrow(
+            &user.pubkey(),
+            &mint.address,
+            &realm_cookie,
+            &admin.pubkey(),
+        )
+        .await?;
+    with_escrow_balance(&governance_rewards_test, escrow_address, 100).await?;
+
+    let recipient = governance_rewards_test
+        .bench
+        .create_associated_token_account(user.pubkey(), mint.address)
+        .await?;
+
+    governance_rewards_test.bench.set_unix_time(1000).await;
+    governance_rewards_test
+        .set_escrow_release_schedule(
+            &escrow_address,
+            &user.pubkey(),
+            &realm_cookie,
+            &admin,
+            ReleaseLimit::AmountPerPeriod {
+                amount: 40,
+                period: 100,
+            },
+        )
+        .await?;
+    governance_rewards_test
+        .transfer_from_escrow(
+            &escrow_address,
+            &user,
+            &realm_cookie,
+            &recipient,
+            &admin,
+            40,
+        )
+        .await?;
+
+    // Act
+    governance_rewards_test
+        .set_escrow_release_schedule(
+            &escrow_address,
+            &user.pubkey(),
+            &realm_cookie,
+            &admin,
+            ReleaseLimit::AmountPerPeriod {
+                amount: 50,
+                period: 100,
+            },
+        )
+        .await?;
+    let err = governance_rewards_test
+        .transfer_from_escrow(
+            &escrow_address,
+            &user,
+            &realm_cookie,
+            &recipient,
+            &admin,
+            11,
+        )
+        .await
+        .err()
+        .unwrap();
+    governance_rewards_test
+        .transfer_from_escrow(
+            &escrow_address,
+            &user,
+            &realm_cookie,
+            &recipient,
+            &admin,
+            10,
+        )
+        .await?;
+
+    // Assert
+    // The 40 released before the change count against the new allowance of 50
+    assert_governance_rewards_err(err, GovernanceRewardsError::EscrowReleaseLimitExceeded);
+
+    let token_account = governance_rewards_test
+        .bench
+        .get_token_account(&recipient.address)
+        .await
+        .unwrap();
+    assert_eq!(token_account.amount, 50);
+
+    Ok(())
+}
+
+async fn with_early_release_escrow(
+    governance_rewards_test: &mut GovernanceRewardsTest,
+    penalty_basis_points: Option<u16>,
+) -> Result<
+    (
+        Keypair,
+        Keypair,
+        RealmCookie,
+        Pubkey,
+        TokenAccountCookie,
+        TokenAccountCookie,
+    ),
+    TransportError,
+> {
+    let user = Keypair::new();
+    let admin = Keypair::new();
+    let realm_cookie = governance_rewards_test.governance.with_realm().await?;
+    let mint = governance_rewards_test.bench.with_mint().await?;
+    let escrow_address = governance_rewards_test
+        .with_timelocked_escrow(
+            &user.pubkey(),
+            &mint.address,
+            &realm_cookie,
+            &admin.pubkey(),
+            100,
+        )
+        .await?;
+    with_escrow_balance(governance_rewards_test, escrow_address, 100).await?;
+
+    let recipient = governance_rewards_test
+        .bench
+        .create_associated_token_account(user.pubkey(), mint.address)
+        .await?;
+    let penalty_account = governance_rewards_test
+        .bench
+        .with_token_account(&mint.address)
+        .await?;
+
+    if let Some(penalty_basis_points) = penalty_basis_points {
+        governance_rewards_test
+            .set_early_release_policy(
+                &escrow_address,
+                &user.pubkey(),
+                &realm_cookie,
+                &admin,
+                100,
+                &penalty_account,
+                penalty_basis_points,
+            )
+            .await?;
+    }
+
+    Ok((
+        user,
+        admin,
+        realm_cookie,
+        escrow_address,
+        recipient,
+        penalty_account,
+    ))
+}
+
+#[tokio::test]
+async fn test_release_timelocked_escrow_early_with_penalty() -> TestOutcome {
+    // Arrange
+    let mut governance_rewards_test = GovernanceRewardsTest::start_new().await;
+    let (user, admin, realm_cookie, escrow_address, recipient, penalty_account) =
+        with_early_release_escrow(&mut governance_rewards_test, Some(2000)).await?;
+    governance_rewards_test.bench.set_unix_time(50).await;
+
+    // Act
+    governance_rewards_test
+        .release_timelocked_escrow_early(
+            &escrow_address,
+            &user,
+            &realm_cookie,
+            &recipient,
+            &penalty_account,
+            &admin.pubkey(),
+            100,
+            100,
+        )
+        .await?;
+
+    // Assert
+    let token_account = governance_rewards_test
+        .bench
+        .get_token_account(&recipient.address)
+        .await
+        .unwrap();
+    assert_eq!(token_account.amount, 80);
+
+    let penalty_token_account = governance_rewards_test
+        .bench
+        .get_token_account(&penalty_account.address)
+        .await
+        .unwrap();
+    assert_eq!(penalty_token_account.amount, 20);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_release_timelocked_escrow_early_rounds_penalty_up() -> TestOutcome {
+    // Arrange
+    let mut governance_rewards_test = GovernanceRewardsTest::start_new().await;
+    let (user, admin, realm_cookie, escrow_address, recipient, penalty_account) =
+        with_early_release_escrow(&mut governance_rewards_test, Some(1000)).await?;
+    governance_rewards_test.bench.set_unix_time(50).await;
+
+    // Act
+    governance_rewards_test
+        .release_timelocked_escrow_early(
+            &escrow_address,
+            &user,
+            &realm_cookie,
+            &recipient,
+            &penalty_account,
+            &admin.pubkey(),
+            100,
+            9,
+        )
+        .await?;
+
+    // Assert
+    let token_account = governance_rewards_test
+        .bench
+        .get_token_account(&recipient.address)
+        .await
+        .unwrap();
+    assert_eq!(token_account.amount, 8);
+
+    let penalty_token_account = governance_rewards_test
+        .bench
+        .get_token_account(&penalty_account.address)
+        .await
+        .unwrap();
+    assert_eq!(penalty_token_account.amount, 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_release_timelocked_escrow_early_after_unlock_without_penalty() -> TestOutcome {
+    // Arrange
+    let mut governance_rewards_test = GovernanceRewardsTest::start_new().await;
+    let (user, admin, realm_cookie, escrow_address, recipient, penalty_account) =
+        with_early_release_escrow(&mut governance_rewards_test, Some(2000)).await?;
+    governance_rewards_test.bench.set_unix_time(100).await;
+
+    // Act
+    governance_rewards_test
+        .release_timelocked_escrow_early(
+            &escrow_address,
+            &user,
+            &realm_cookie,
+            &recipient,
+            &penalty_account,
+            &admin.pubkey(),
+            100,
+            100,
+        )
+        .await?;
+
+    // Assert
+    let token_account = governance_rewards_test
+        .bench
+        .get_token_account(&recipient.address)
+        .await
+        .unwrap();
+    assert_eq!(token_account.amount, 100);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_release_timelocked_escrow_early_to_wrong_penalty_account_err() -> TestOutcome {
+    // Arrange
+    let mut governance_rewards_test = GovernanceRewardsTest::start_new().await;
+    let (user, admin, realm_cookie, escrow_address, recipient, _) =
+        with_early_release_escrow(&mut governance_rewards_test, Some(2000)).await?;
+    governance_rewards_test.bench.set_unix_time(50).await;
+
+    // Act
+    let err = governance_rewards_test
+        .release_timelocked_escrow_early(
+            &escrow_address,
+            &user,
+            &realm_cookie,
+            &recipient,
+            &recipient,
+            &admin.pubkey(),
+            100,
+            100,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_governance_rewards_err(err, GovernanceRewardsError::WrongPenaltyAccount);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_release_timelocked_escrow_early_without_policy_err() -> TestOutcome {
+    // Arrange
+    let mut governance_rewards_test = GovernanceRewardsTest::start_new().await;
+    let (user, admin, realm_cookie, escrow_address, recipient, penalty_account) =
+        with_early_release_escrow(&mut governance_rewards_test, None).await?;
+    governance_rewards_test.bench.set_unix_time(50).await;
+
+    // Act
+    let err = governance_rewards_test
+        .release_timelocked_escrow_early(
+            &escrow_address,
+            &user,
+            &realm_cookie,
+            &recipient,
+            &penalty_account,
+            &admin.pubkey(),
+            100,
+            100,
+        )
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_anchor_err(err, anchor_lang::error::ErrorCode::AccountNotInitialized);
+
+    Ok(())
+}
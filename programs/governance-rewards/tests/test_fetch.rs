@@ -0,0 +1,259 @@
+use crate::program_test::governance_rewards_test::GovernanceRewardsTest;
+use anchor_lang::prelude::Pubkey;
+use async_trait::async_trait;
+use governance_rewards::state::{
+    claim_data::ClaimData,
+    preferences::{ResolutionPreference, UserPreferences},
+};
+use governance_rewards_client::{
+    fetch::{self, AccountSource, FetchError, MemcmpFilter},
+    pda,
+};
+use solana_program_test::{tokio, BanksClient};
+use solana_sdk::{account::Account, signature::Keypair, signer::Signer, transport::TransportError};
+
+mod program_test;
+
+type TestOutcome = Result<(), TransportError>;
+
+/**
+ * Lists program accounts out of a known set of addresses, applying filters the way an
+ * RPC node does, since `BanksClient` cannot list accounts.
+ */
+struct KnownAccounts {
+    banks_client: BanksClient,
+    addresses: Vec<Pubkey>,
+}
+
+#[async_trait]
+impl AccountSource for KnownAccounts {
+    async fn get_account(&mut self, address: Pubkey) -> Result<Option<Account>, FetchError> {
+        AccountSource::get_account(&mut self.banks_client, address).await
+    }
+
+    async fn get_program_accounts(
+        &mut self,
+        program: Pubkey,
+        filters: &[MemcmpFilter],
+    ) -> Result<Vec<(Pubkey, Account)>, FetchError> {
+        let mut accounts = vec![];
+        for address in self.addresses.clone() {
+            if let Some(account) = self.get_account(address).await? {
+                if account.owner == program
+                    && filters.iter().all(|filter| filter.matches(&account.data))
+                {
+                    accounts.push((address, account));
+                }
+            }
+        }
+        Ok(accounts)
+    }
+}
+
+#[tokio::test]
+async fn test_get_distribution() -> TestOutcome {
+    // Arrange
+    let mut governance_rewards_test = GovernanceRewardsTest::start_new().await;
+    let realm_cookie = governance_rewards_test.governance.with_realm().await?;
+    let key_cookie = governance_rewards_test.with_distribution_keypair();
+    let distribution_cookie = governance_rewards_test
+        .with_funded_distribution(&realm_cookie, &key_cookie, u64::max_value())
+        .await?;
+    let mut banks_client = governance_rewards_test.bench.banks_client();
+
+    // Act
+    let distribution = fetch::get_distribution(&mut banks_client, distribution_cookie.address)
+        .await
+        .unwrap();
+
+    // Assert
+    let expected = governance_rewards_test
+        .get_distribution_account(distribution_cookie.address)
+        .await;
+    assert_eq!(distribution, expected);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_get_missing_distribution_err() -> TestOutcome {
+    // Arrange
+    let governance_rewards_test = GovernanceRewardsTest::start_new().await;
+    let mut banks_client = governance_rewards_test.bench.banks_client();
+    let address = Pubkey::new_unique();
+
+    // Act
+    let err = fetch::get_distribution(&mut banks_client, address)
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert!(matches!(err, FetchError::AccountNotFound(missing) if missing == address));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_get_claim() -> TestOutcome {
+    // Arrange
+    let mut governance_rewards_test = GovernanceRewardsTest::start_new().await;
+    let realm_cookie = governance_rewards_test.governance.with_realm().await?;
+    let key_cookie = governance_rewards_test.with_distribution_keypair();
+    let distribution_cookie = governance_rewards_test
+        .with_funded_distribution(&realm_cookie, &key_cookie, u64::max_value())
+        .await?;
+    let user = Keypair::new().pubkey();
+    governance_rewards_test
+        .with_registered_user(&distribution_cookie, user, 10)
+        .await?;
+    let mut banks_client = governance_rewards_test.bench.banks_client();
+
+    // Act
+    let claim = fetch::get_claim(&mut banks_client, user, distribution_cookie.address)
+        .await
+        .unwrap();
+    let unregistered = fetch::get_claim(
+        &mut banks_client,
+        Keypair::new().pubkey(),
+        distribution_cookie.address,
+    )
+    .await
+    .unwrap();
+
+    // Assert
+    let claim = claim.unwrap();
+    assert_eq!(claim.weight, 10);
+    assert_eq!(claim.distribution, distribution_cookie.address);
+    assert_eq!(claim.belongs_to, user);
+    assert_eq!(claim.version, ClaimData::VERSION);
+    assert!(unregistered.is_none());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_get_user_preferences() -> TestOutcome {
+    // Arrange
+    let mut governance_rewards_test = GovernanceRewardsTest::start_new().await;
+    let realm_cookie = governance_rewards_test.governance.with_realm().await?;
+    let user = Keypair::new().pubkey();
+    let preferred_mints = vec![Pubkey::new_unique()];
+    governance_rewards_test
+        .with_preferences(
+            &UserPreferences {
+                preferred_mints: preferred_mints.clone(),
+                resolution_preference: ResolutionPreference::Wallet,
+                ..Default::default()
+            },
+            &realm_cookie,
+            user,
+        )
+        .await?;
+    let mut banks_client = governance_rewards_test.bench.banks_client();
+
+    // Act
+    let preferences = fetch::get_user_preferences(&mut banks_client, user, realm_cookie.address)
+        .await
+        .unwrap();
+    let unset = fetch::get_user_preferences(
+        &mut banks_client,
+        Keypair::new().pubkey(),
+        realm_cookie.address,
+    )
+    .await
+    .unwrap();
+
+    // Assert
+    let preferences = preferences.unwrap();
+    assert_eq!(preferences.preferred_mints, preferred_mints);
+    assert!(matches!(
+        preferences.resolution_preference,
+        ResolutionPreference::Wallet
+    ));
+    assert!(unset.is_none());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_list_claims() -> TestOutcome {
+    // Arrange
+    let mut governance_rewards_test = GovernanceRewardsTest::start_new().await;
+    let realm_cookie = governance_rewards_test.governance.with_realm().await?;
+    let first_key = governance_rewards_test.with_distribution_keypair();
+    let first = governance_rewards_test
+        .with_funded_distribution(&realm_cookie, &first_key, u64::max_value())
+        .await?;
+    let second_key = governance_rewards_test.with_distribution_keypair();
+    let second = governance_rewards_test
+        .with_funded_distribution(&realm_cookie, &second_key, u64::max_value())
+        .await?;
+
+    let user = Keypair::new().pubkey();
+    let other_user = Keypair::new().pubkey();
+    let mut addresses = vec![first.address, second.address];
+    for (distribution, registrant) in [(&first, user), (&second, user), (&first, other_user)] {
+        governance_rewards_test
+            .with_registered_user(distribution, registrant, 10)
+            .await?;
+        addresses.push(pda::claim_data(registrant, distribution.address));
+    }
+    let mut source = KnownAccounts {
+        banks_client: governance_rewards_test.bench.banks_client(),
+        addresses,
+    };
+
+    // Act
+    let first_claims = fetch::list_claims_for_distribution(&mut source, first.address)
+        .await
+        .unwrap();
+    let user_claims = fetch::list_claims_for_user(&mut source, user)
+        .await
+        .unwrap();
+
+    // Assert
+    let mut first_registrants: Vec<Pubkey> = first_claims
+        .iter()
+        .map(|(address, claim)| {
+            assert_eq!(*address, pda::claim_data(claim.belongs_to, first.address));
+            claim.belongs_to
+        })
+        .collect();
+    first_registrants.sort();
+    let mut expected = vec![user, other_user];
+    expected.sort();
+    assert_eq!(first_registrants, expected);
+
+    let mut user_distributions: Vec<Pubkey> = user_claims
+        .iter()
+        .map(|(address, claim)| {
+            assert_eq!(*address, pda::claim_data(user, claim.distribution));
+            claim.distribution
+        })
+        .collect();
+    user_distributions.sort();
+    let mut expected = vec![first.address, second.address];
+    expected.sort();
+    assert_eq!(user_distributions, expected);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_list_claims_with_banks_client_err() -> TestOutcome {
+    // Arrange
+    let governance_rewards_test = GovernanceRewardsTest::start_new().await;
+    let mut banks_client = governance_rewards_test.bench.banks_client();
+
+    // Act
+    let err = fetch::list_claims_for_user(&mut banks_client, Keypair::new().pubkey())
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert!(matches!(err, FetchError::Unsupported));
+
+    Ok(())
+}
@@ -0,0 +1,416 @@
+use std::mem::size_of;
+
+use crate::program_test::governance_rewards_test::{DistributionCookie, GovernanceRewardsTest};
+use crate::program_test::tools::{assert_anchor_err, assert_governance_rewards_err};
+use anchor_lang::{prelude::ErrorCode, AccountSerialize};
+use governance_rewards::{
+    error::GovernanceRewardsError,
+    state::{
+        addin::VoterWeightRecord,
+        claim_data::ClaimData,
+        distribution::Distribution,
+        legacy::{ClaimDataV0, DistributionOptionV0, DistributionV0, UserPreferencesV0},
+        preferences::{ResolutionPreference, UserPreferences},
+    },
+};
+use solana_program_test::tokio;
+use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer, transport::TransportError};
+
+mod program_test;
+
+type TestOutcome = Result<(), TransportError>;
+
+/**
+ * Writes `legacy` to `address` the way the program allocates it, zero padded to
+ * `8 + size_of::<V>()` bytes.
+ */
+async fn set_legacy_account<V: AccountSerialize>(
+    governance_rewards_test: &GovernanceRewardsTest,
+    legacy: &V,
+    address: Pubkey,
+) -> Result<(), TransportError> {
+    let mut data: Vec<u8> = Vec::new();
+    legacy.try_serialize(&mut data).unwrap();
+    data.resize(data.len().max(8 + size_of::<V>()), 0);
+    governance_rewards_test
+        .bench
+        .set_account(data, address, governance_rewards::id())
+        .await
+}
+
+/**
+ * Replaces a distribution with its pre-versioning layout, returning what it held.
+ * `paid` tokens are added to the legacy funding of every option, as if they had already
+ * been paid out of its wallet.
+ */
+async fn with_legacy_distribution(
+    governance_rewards_test: &mut GovernanceRewardsTest,
+    distribution_cookie: &DistributionCookie,
+    paid: u64,
+) -> Result<Distribution, TransportError> {
+    let distribution = governance_rewards_test
+        .get_distribution_account(distribution_cookie.address)
+        .await;
+
+    let mut distribution_options = [None; 8];
+    for (legacy, option) in distribution_options
+        .iter_mut()
+        .zip(distribution.distribution_options.iter())
+    {
+        *legacy = option.map(|option| DistributionOptionV0 {
+            total_vote_weight: option.total_vote_weight,
+            total_amount: option.total_amount + paid,
+            extra_reclaimed: option.extra_reclaimed,
+            mint: option.mint,
+            wallet: option.wallet,
+        });
+    }
+    let legacy = DistributionV0 {
+        registration_period_end_ts: distribution.registration_period_end_ts,
+        voter_weight_program: distribution.voter_weight_program,
+        realm: distribution.realm,
+        registrar: distribution.registrar,
+        total_vote_weight: distribution.total_vote_weight,
+        total_vote_weight_claimed: distribution.total_vote_weight_claimed,
+        distribution_options,
+        admin: distribution.admin,
+    };
+    set_legacy_account(
+        governance_rewards_test,
+        &legacy,
+        distribution_cookie.address,
+    )
+    .await?;
+
+    Ok(distribution)
+}
+
+#[tokio::test]
+async fn test_migrate_distribution() -> TestOutcome {
+    // Arrange
+    let mut governance_rewards_test = GovernanceRewardsTest::start_new().await;
+    let realm_cookie = governance_rewards_test.governance.with_realm().await?;
+    let key_cookie = governance_rewards_test.with_distribution_keypair();
+    let distribution_cookie = governance_rewards_test
+        .with_funded_distribution(&realm_cookie, &key_cookie, u64::max_value())
+        .await?;
+    let paid = 40;
+    let current =
+        with_legacy_distribution(&mut governance_rewards_test, &distribution_cookie, paid).await?;
+
+    // Act
+    governance_rewards_test
+        .migrate_distribution(&distribution_cookie)
+        .await?;
+
+    // Assert
+    let distribution = governance_rewards_test
+        .get_distribution_account(distribution_cookie.address)
+        .await;
+    assert_eq!(distribution.realm, current.realm);
+    assert_eq!(distribution.admin, current.admin);
+    assert_eq!(distribution.total_vote_weight, current.total_vote_weight);
+    assert_eq!(distribution.crank_fee, None);
+    assert_eq!(distribution.registrant_count, 0);
+    assert_eq!(distribution.crank_fees_paid, 0);
+    assert_eq!(distribution.version, Distribution::VERSION);
+    assert_eq!(
+        distribution
+            .payout_authority(distribution_cookie.address)
+            .unwrap(),
+        Distribution::get_payout_authority(distribution_cookie.address)
+    );
+
+    let option = distribution.distribution_options[0].unwrap();
+    let current_option = current.distribution_options[0].unwrap();
+    assert_eq!(option.wallet, current_option.wallet);
+    assert_eq!(option.total_amount, current_option.total_amount + paid);
+    assert_eq!(option.total_paid, paid);
+    assert_eq!(option.total_reclaimed, 0);
+
+    let account = governance_rewards_test
+        .bench
+        .get_account(&distribution_cookie.address)
+        .await
+        .unwrap();
+    assert_eq!(account.data.len(), Distribution::SPACE);
+    assert!(
+        account.lamports
+            >= governance_rewards_test
+                .bench
+                .rent
+                .minimum_balance(Distribution::SPACE)
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_migrate_distribution_without_wallets_err() -> TestOutcome {
+    // Arrange
+    let mut governance_rewards_test = GovernanceRewardsTest::start_new().await;
+    let realm_cookie = governance_rewards_test.governance.with_realm().await?;
+    let key_cookie = governance_rewards_test.with_distribution_keypair();
+    let mut distribution_cookie = governance_rewards_test
+        .with_funded_distribution(&realm_cookie, &key_cookie, u64::max_value())
+        .await?;
+    with_legacy_distribution(&mut governance_rewards_test, &distribution_cookie, 0).await?;
+    distribution_cookie.funding.clear();
+
+    // Act
+    let err = governance_rewards_test
+        .migrate_distribution(&distribution_cookie)
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_governance_rewards_err(err, GovernanceRewardsError::MissingOptionWallets);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_register_after_migrating_distribution() -> TestOutcome {
+    // Arrange
+    let mut governance_rewards_test = GovernanceRewardsTest::start_new().await;
+    let realm_cookie = governance_rewards_test.governance.with_realm().await?;
+    let key_cookie = governance_rewards_test.with_distribution_keypair();
+    let distribution_cookie = governance_rewards_test
+        .with_funded_distribution(&realm_cookie, &key_cookie, u64::max_value())
+        .await?;
+    with_legacy_distribution(&mut governance_rewards_test, &distribution_cookie, 0).await?;
+
+    let token_mint = governance_rewards_test.bench.with_mint().await?;
+    let vwr = governance_rewards_test
+        .with_dummy_voter_weight_record(
+            &VoterWeightRecord::create_test(
+                realm_cookie.address,
+                token_mint.address,
+                Keypair::new().pubkey(),
+                distribution_cookie.address,
+                10,
+                Some(u64::MAX),
+            ),
+            distribution_cookie.account.voter_weight_program,
+        )
+        .await?;
+
+    let err = governance_rewards_test
+        .with_registrant(&distribution_cookie, &vwr)
+        .await
+        .err()
+        .unwrap();
+    assert_anchor_err(err, ErrorCode::AccountDidNotDeserialize);
+    governance_rewards_test.bench.advance_clock().await;
+
+    // Act
+    governance_rewards_test
+        .migrate_distribution(&distribution_cookie)
+        .await?;
+    governance_rewards_test
+        .with_registrant(&distribution_cookie, &vwr)
+        .await?;
+
+    // Assert
+    let distribution = governance_rewards_test
+        .get_distribution_account(distribution_cookie.address)
+        .await;
+    assert_eq!(distribution.total_vote_weight, 10);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_migrate_claim_data() -> TestOutcome {
+    // Arrange
+    let mut governance_rewards_test = GovernanceRewardsTest::start_new().await;
+    let realm_cookie = governance_rewards_test.governance.with_realm().await?;
+    let key_cookie = governance_rewards_test.with_distribution_keypair();
+    let distribution_cookie = governance_rewards_test
+        .with_funded_distribution(&realm_cookie, &key_cookie, u64::max_value())
+        .await?;
+    let user = Keypair::new();
+
+    let vote_weight = 10;
+    let claim_data_address = ClaimData::get_address(user.pubkey(), distribution_cookie.address);
+    let legacy = ClaimDataV0 {
+        weight: vote_weight,
+        distribution: distribution_cookie.address,
+        claim_option: 0,
+        has_claimed: false,
+        has_registered: true,
+        belongs_to: user.pubkey(),
+    };
+    set_legacy_account(&governance_rewards_test, &legacy, claim_data_address).await?;
+
+    // Act
+    governance_rewards_test
+        .migrate_claim_data(user.pubkey(), distribution_cookie.address)
+        .await?;
+
+    // Assert
+    let claim_data = governance_rewards_test
+        .bench
+        .get_anchor_account::<ClaimData>(claim_data_address)
+        .await;
+    assert_eq!(claim_data.weight, vote_weight);
+    assert_eq!(claim_data.distribution, distribution_cookie.address);
+    assert_eq!(claim_data.belongs_to, user.pubkey());
+    assert_eq!(claim_data.payer, user.pubkey());
+    assert!(claim_data.has_registered);
+    assert!(!claim_data.has_claimed);
+    assert_eq!(claim_data.version, ClaimData::VERSION);
+    assert_eq!(claim_data.address().unwrap(), claim_data_address);
+
+    let account = governance_rewards_test
+        .bench
+        .get_account(&claim_data_address)
+        .await
+        .unwrap();
+    assert_eq!(account.data.len(), ClaimData::SPACE);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_migrate_user_preferences() -> TestOutcome {
+    // Arrange
+    let governance_rewards_test = GovernanceRewardsTest::start_new().await;
+    let user = Keypair::new();
+    let realm = Pubkey::new_unique();
+    let escrow_admin = Pubkey::new_unique();
+    let preferred_mint = Pubkey::new_unique();
+
+    let address = UserPreferences::get_address(user.pubkey(), realm);
+    set_legacy_account(
+        &governance_rewards_test,
+        &UserPreferencesV0 {
+            preferred_mint: Some(preferred_mint),
+            resolution_preference: ResolutionPreference::Escrow { escrow_admin },
+        },
+        address,
+    )
+    .await?;
+
+    // Act
+    governance_rewards_test
+        .migrate_user_preferences(user.pubkey(), realm)
+        .await?;
+
+    // Assert
+    let preferences = governance_rewards_test
+        .bench
+        .get_anchor_account::<UserPreferences>(address)
+        .await;
+    assert_eq!(preferences.preferred_mints, vec![preferred_mint]);
+    assert!(matches!(
+        preferences.resolution_preference,
+        ResolutionPreference::Escrow { escrow_admin: admin } if admin == escrow_admin
+    ));
+    assert_eq!(preferences.version, UserPreferences::VERSION);
+    assert_eq!(
+        preferences.bump,
+        UserPreferences::find_address(user.pubkey(), realm).1
+    );
+
+    let account = governance_rewards_test
+        .bench
+        .get_account(&address)
+        .await
+        .unwrap();
+    assert_eq!(account.data.len(), UserPreferences::space(1));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_register_with_legacy_preferences() -> TestOutcome {
+    // Arrange
+    let mut governance_rewards_test = GovernanceRewardsTest::start_new().await;
+    let realm_cookie = governance_rewards_test.governance.with_realm().await?;
+    let key_cookie = governance_rewards_test.with_distribution_keypair();
+
+    let first_mint = governance_rewards_test.bench.with_mint().await?;
+    let first_funding = governance_rewards_test
+        .with_owned_tokens(&first_mint, &key_cookie, 100)
+        .await?;
+    let second_mint = governance_rewards_test.bench.with_mint().await?;
+    let second_funding = governance_rewards_test
+        .with_owned_tokens(&second_mint, &key_cookie, 100)
+        .await?;
+    let distribution_cookie = governance_rewards_test
+        .with_distribution(
+            &realm_cookie,
+            &key_cookie,
+            u64::max_value(),
+            &[&first_funding, &second_funding],
+        )
+        .await?;
+
+    let user = Keypair::new();
+    set_legacy_account(
+        &governance_rewards_test,
+        &UserPreferencesV0 {
+            preferred_mint: Some(second_mint.address),
+            resolution_preference: ResolutionPreference::Wallet,
+        },
+        UserPreferences::get_address(user.pubkey(), realm_cookie.address),
+    )
+    .await?;
+
+    let token_mint = governance_rewards_test.bench.with_mint().await?;
+    let vwr = governance_rewards_test
+        .with_dummy_voter_weight_record(
+            &VoterWeightRecord::create_test(
+                realm_cookie.address,
+                token_mint.address,
+                user.pubkey(),
+                distribution_cookie.address,
+                10,
+                Some(u64::MAX),
+            ),
+            distribution_cookie.account.voter_weight_program,
+        )
+        .await?;
+
+    // Act
+    governance_rewards_test
+        .with_registrant(&distribution_cookie, &vwr)
+        .await?;
+
+    // Assert
+    let claim_data = governance_rewards_test
+        .bench
+        .get_anchor_account::<ClaimData>(ClaimData::get_address(
+            user.pubkey(),
+            distribution_cookie.address,
+        ))
+        .await;
+    assert_eq!(claim_data.claim_option, 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_migrate_current_distribution_err() -> TestOutcome {
+    // Arrange
+    let mut governance_rewards_test = GovernanceRewardsTest::start_new().await;
+    let realm_cookie = governance_rewards_test.governance.with_realm().await?;
+    let key_cookie = governance_rewards_test.with_distribution_keypair();
+    let distribution_cookie = governance_rewards_test
+        .with_funded_distribution(&realm_cookie, &key_cookie, u64::max_value())
+        .await?;
+
+    // Act
+    let err = governance_rewards_test
+        .migrate_distribution(&distribution_cookie)
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_governance_rewards_err(err, GovernanceRewardsError::AccountAlreadyMigrated);
+
+    Ok(())
+}
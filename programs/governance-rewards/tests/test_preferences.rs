@@ -0,0 +1,214 @@
+use crate::program_test::governance_rewards_test::GovernanceRewardsTest;
+use crate::program_test::tools::assert_governance_rewards_err;
+use governance_rewards::{
+    error::GovernanceRewardsError,
+    state::{
+        distribution_preferences::DistributionPreferences,
+        preferences::{ResolutionPreference, UserPreferences, MAX_PREFERRED_MINTS},
+    },
+};
+use solana_program_test::tokio;
+use solana_sdk::{signature::Keypair, signer::Signer, transport::TransportError};
+
+mod program_test;
+
+type TestOutcome = Result<(), TransportError>;
+
+#[tokio::test]
+async fn test_set_preferred_mints() -> TestOutcome {
+    // Arrange
+    let mut governance_rewards_test = GovernanceRewardsTest::start_new().await;
+    let realm_cookie = governance_rewards_test.governance.with_realm().await?;
+    let user = governance_rewards_test.bench.with_wallet().await;
+    governance_rewards_test
+        .bench
+        .with_lamports(&user.address, 1_000_000_000)
+        .await?;
+    let preferred_mints = vec![
+        Keypair::new().pubkey(),
+        Keypair::new().pubkey(),
+        Keypair::new().pubkey(),
+    ];
+
+    // Act
+    let address = governance_rewards_test
+        .set_preferred_mints(&user.signer, &realm_cookie, preferred_mints.clone())
+        .await?;
+
+    // Assert
+    let preferences = governance_rewards_test
+        .bench
+        .get_anchor_account::<UserPreferences>(address)
+        .await;
+    assert_eq!(preferences.preferred_mints, preferred_mints);
+    assert_eq!(preferences.version, UserPreferences::VERSION);
+    assert_eq!(
+        preferences.bump,
+        UserPreferences::find_address(user.address, realm_cookie.address).1
+    );
+
+    let account = governance_rewards_test
+        .bench
+        .get_account(&address)
+        .await
+        .unwrap();
+    assert_eq!(account.data.len(), UserPreferences::space(3));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_set_resolution_preference() -> TestOutcome {
+    // Arrange
+    let mut governance_rewards_test = GovernanceRewardsTest::start_new().await;
+    let realm_cookie = governance_rewards_test.governance.with_realm().await?;
+    let user = governance_rewards_test.bench.with_wallet().await;
+    governance_rewards_test
+        .bench
+        .with_lamports(&user.address, 1_000_000_000)
+        .await?;
+    let escrow_admin = Keypair::new().pubkey();
+
+    // Act
+    let address = governance_rewards_test
+        .set_resolution_preference(
+            &user.signer,
+            &realm_cookie,
+            ResolutionPreference::Escrow { escrow_admin },
+        )
+        .await?;
+
+    // Assert
+    let preferences = governance_rewards_test
+        .bench
+        .get_anchor_account::<UserPreferences>(address)
+        .await;
+    assert!(matches!(
+        preferences.resolution_preference,
+        ResolutionPreference::Escrow { escrow_admin: admin } if admin == escrow_admin
+    ));
+    assert_eq!(preferences.version, UserPreferences::VERSION);
+    assert_eq!(
+        preferences.bump,
+        UserPreferences::find_address(user.address, realm_cookie.address).1
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_set_preferred_mints_shrinks_account() -> TestOutcome {
+    // Arrange
+    let mut governance_rewards_test = GovernanceRewardsTest::start_new().await;
+    let realm_cookie = governance_rewards_test.governance.with_realm().await?;
+    let user = governance_rewards_test.bench.with_wallet().await;
+    governance_rewards_test
+        .bench
+        .with_lamports(&user.address, 1_000_000_000)
+        .await?;
+    governance_rewards_test
+        .set_preferred_mints(
+            &user.signer,
+            &realm_cookie,
+            vec![Keypair::new().pubkey(), Keypair::new().pubkey()],
+        )
+        .await?;
+    governance_rewards_test.bench.advance_clock().await;
+
+    // Act
+    let preferred_mint = Keypair::new().pubkey();
+    let address = governance_rewards_test
+        .set_preferred_mints(&user.signer, &realm_cookie, vec![preferred_mint])
+        .await?;
+
+    // Assert
+    let preferences = governance_rewards_test
+        .bench
+        .get_anchor_account::<UserPreferences>(address)
+        .await;
+    assert_eq!(preferences.preferred_mints, vec![preferred_mint]);
+
+    let account = governance_rewards_test
+        .bench
+        .get_account(&address)
+        .await
+        .unwrap();
+    assert_eq!(account.data.len(), UserPreferences::space(1));
+    assert_eq!(
+        account.lamports,
+        governance_rewards_test
+            .bench
+            .rent
+            .minimum_balance(UserPreferences::space(1))
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_set_too_many_preferred_mints_err() -> TestOutcome {
+    // Arrange
+    let mut governance_rewards_test = GovernanceRewardsTest::start_new().await;
+    let realm_cookie = governance_rewards_test.governance.with_realm().await?;
+    let user = governance_rewards_test.bench.with_wallet().await;
+    governance_rewards_test
+        .bench
+        .with_lamports(&user.address, 1_000_000_000)
+        .await?;
+    let preferred_mints = (0..=MAX_PREFERRED_MINTS)
+        .map(|_| Keypair::new().pubkey())
+        .collect();
+
+    // Act
+    let err = governance_rewards_test
+        .set_preferred_mints(&user.signer, &realm_cookie, preferred_mints)
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_governance_rewards_err(err, GovernanceRewardsError::TooManyPreferredMints);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_set_distribution_preferences() -> TestOutcome {
+    // Arrange
+    let mut governance_rewards_test = GovernanceRewardsTest::start_new().await;
+    let realm_cookie = governance_rewards_test.governance.with_realm().await?;
+    let key_cookie = governance_rewards_test.with_distribution_keypair();
+    let distribution_cookie = governance_rewards_test
+        .with_funded_distribution(&realm_cookie, &key_cookie, u64::max_value())
+        .await?;
+    let user = governance_rewards_test.bench.with_wallet().await;
+    governance_rewards_test
+        .bench
+        .with_lamports(&user.address, 1_000_000_000)
+        .await?;
+    let preferred_mint = Keypair::new().pubkey();
+    let escrow_admin = Keypair::new().pubkey();
+
+    // Act
+    let address = governance_rewards_test
+        .set_distribution_preferences(
+            &user.signer,
+            &distribution_cookie,
+            Some(preferred_mint),
+            Some(ResolutionPreference::Escrow { escrow_admin }),
+        )
+        .await?;
+
+    // Assert
+    let preferences = governance_rewards_test
+        .bench
+        .get_anchor_account::<DistributionPreferences>(address)
+        .await;
+    assert_eq!(preferences.preferred_mint, Some(preferred_mint));
+    assert!(matches!(
+        preferences.resolution_preference,
+        Some(ResolutionPreference::Escrow { escrow_admin: admin }) if admin == escrow_admin
+    ));
+
+    Ok(())
+}
@@ -1,7 +1,15 @@
-use crate::program_test::governance_rewards_test::GovernanceRewardsTest;
+use crate::program_test::governance_rewards_test::{
+    DistributionCookie, GovernanceRewardsTest, PreferenceCookie,
+};
+use crate::program_test::governance_test::RealmCookie;
 use crate::program_test::tools::assert_governance_rewards_err;
 use governance_rewards::{
-    error::GovernanceRewardsError, state::distribution_option::DistributionOption,
+    error::GovernanceRewardsError,
+    state::{
+        crank_fee::CrankFee,
+        distribution_option::DistributionOption,
+        preferences::{ResolutionPreference, UserPreferences},
+    },
 };
 use solana_program_test::tokio;
 use solana_sdk::transport::TransportError;
@@ -211,3 +219,425 @@ async fn test_reclaim_early_err() -> TestOutcome {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_reclaim_holds_back_crank_fee_reserve() -> TestOutcome {
+    // Arrange
+    let mut governance_rewards_test = GovernanceRewardsTest::start_new().await;
+    let realm_cookie = governance_rewards_test.governance.with_realm().await?;
+    let key_cookie = governance_rewards_test.with_distribution_keypair();
+
+    let funding_amount = 100;
+    let funding_mint_1 = governance_rewards_test.bench.with_mint().await?;
+    let funding_account_1 = governance_rewards_test
+        .with_owned_tokens(&funding_mint_1, &key_cookie, funding_amount)
+        .await?;
+
+    governance_rewards_test.bench.set_unix_time(9).await;
+
+    let distribution_cookie = governance_rewards_test
+        .with_crank_fee_distribution(
+            &realm_cookie,
+            &key_cookie,
+            10,
+            &[&funding_account_1],
+            CrankFee::Tokens {
+                option: 0,
+                amount: 5,
+            },
+        )
+        .await?;
+
+    let mut distribution_data = governance_rewards_test
+        .get_distribution_account(distribution_cookie.address)
+        .await;
+
+    distribution_data.distribution_options[0] = Some(DistributionOption {
+        total_vote_weight: 30,
+        ..distribution_data.distribution_options[0].unwrap()
+    });
+    distribution_data.total_vote_weight = 100;
+    distribution_data.registrant_count = 1;
+
+    governance_rewards_test
+        .bench
+        .set_anchor_account(
+            &distribution_data,
+            distribution_cookie.address,
+            governance_rewards::id(),
+        )
+        .await?;
+    governance_rewards_test.bench.set_unix_time(11).await;
+
+    // Act
+    let to_receive = governance_rewards_test
+        .bench
+        .with_token_account(&funding_mint_1.address)
+        .await?;
+    governance_rewards_test
+        .reclaim_funds(&distribution_cookie, 0, &to_receive)
+        .await?;
+
+    // Assert
+    // 10 is reserved for crank fees and 27 of the remaining 90 is owed to registrants
+    let token_account = governance_rewards_test
+        .bench
+        .get_token_account(&to_receive.address)
+        .await
+        .unwrap();
+    assert_eq!(token_account.amount, 63);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_reclaim_with_no_registrants() -> TestOutcome {
+    // Arrange
+    let mut governance_rewards_test = GovernanceRewardsTest::start_new().await;
+    let realm_cookie = governance_rewards_test.governance.with_realm().await?;
+    let key_cookie = governance_rewards_test.with_distribution_keypair();
+
+    let funding_amount = 100;
+    let funding_mint_1 = governance_rewards_test.bench.with_mint().await?;
+    let funding_account_1 = governance_rewards_test
+        .with_owned_tokens(&funding_mint_1, &key_cookie, funding_amount)
+        .await?;
+
+    governance_rewards_test.bench.set_unix_time(9).await;
+
+    let distribution_cookie = governance_rewards_test
+        .with_distribution(&realm_cookie, &key_cookie, 10, &[&funding_account_1])
+        .await?;
+
+    governance_rewards_test.bench.set_unix_time(11).await;
+
+    // Act
+    let to_receive = governance_rewards_test
+        .bench
+        .with_token_account(&funding_mint_1.address)
+        .await?;
+    governance_rewards_test
+        .reclaim_funds(&distribution_cookie, 0, &to_receive)
+        .await?;
+
+    // Assert
+    let token_account = governance_rewards_test
+        .bench
+        .get_token_account(&to_receive.address)
+        .await
+        .unwrap();
+    assert_eq!(token_account.amount, funding_amount);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_reclaim_option_with_no_registrants() -> TestOutcome {
+    // Arrange
+    let mut governance_rewards_test = GovernanceRewardsTest::start_new().await;
+    let realm_cookie = governance_rewards_test.governance.with_realm().await?;
+    let key_cookie = governance_rewards_test.with_distribution_keypair();
+
+    let funding_mint_1 = governance_rewards_test.bench.with_mint().await?;
+    let funding_account_1 = governance_rewards_test
+        .with_owned_tokens(&funding_mint_1, &key_cookie, 100)
+        .await?;
+
+    let funding_amount = 150;
+    let funding_mint_2 = governance_rewards_test.bench.with_mint().await?;
+    let funding_account_2 = governance_rewards_test
+        .with_owned_tokens(&funding_mint_2, &key_cookie, funding_amount)
+        .await?;
+
+    governance_rewards_test.bench.set_unix_time(9).await;
+
+    let distribution_cookie = governance_rewards_test
+        .with_distribution(
+            &realm_cookie,
+            &key_cookie,
+            10,
+            &[&funding_account_1, &funding_account_2],
+        )
+        .await?;
+
+    let mut distribution_data = governance_rewards_test
+        .get_distribution_account(distribution_cookie.address)
+        .await;
+
+    distribution_data.distribution_options[0] = Some(DistributionOption {
+        total_vote_weight: 100,
+        ..distribution_data.distribution_options[0].unwrap()
+    });
+    distribution_data.total_vote_weight = 100;
+
+    governance_rewards_test
+        .bench
+        .set_anchor_account(
+            &distribution_data,
+            distribution_cookie.address,
+            governance_rewards::id(),
+        )
+        .await?;
+    governance_rewards_test.bench.set_unix_time(11).await;
+
+    // Act
+    let to_receive = governance_rewards_test
+        .bench
+        .with_token_account(&funding_mint_2.address)
+        .await?;
+    governance_rewards_test
+        .reclaim_funds(&distribution_cookie, 1, &to_receive)
+        .await?;
+
+    // Assert
+    let token_account = governance_rewards_test
+        .bench
+        .get_token_account(&to_receive.address)
+        .await
+        .unwrap();
+    assert_eq!(token_account.amount, funding_amount);
+
+    Ok(())
+}
+
+/**
+ * Registers a new user with `vote_weight`, paid to their wallet.
+ */
+async fn with_wallet_user(
+    governance_rewards_test: &mut GovernanceRewardsTest,
+    realm_cookie: &RealmCookie,
+    distribution_cookie: &DistributionCookie,
+    vote_weight: u64,
+) -> Result<(Keypair, PreferenceCookie), TransportError> {
+    let user = Keypair::new();
+    let preferences = governance_rewards_test
+        .with_preferences(
+            &UserPreferences {
+                preferred_mints: vec![],
+                resolution_preference: ResolutionPreference::Wallet,
+                ..Default::default()
+            },
+            realm_cookie,
+            user.pubkey(),
+        )
+        .await?;
+    governance_rewards_test
+        .with_registered_user(distribution_cookie, user.pubkey(), vote_weight)
+        .await?;
+
+    Ok((user, preferences))
+}
+
+#[tokio::test]
+async fn test_sweep_rounding_dust() -> TestOutcome {
+    // Arrange
+    let mut governance_rewards_test = GovernanceRewardsTest::start_new().await;
+    let realm_cookie = governance_rewards_test.governance.with_realm().await?;
+    let key_cookie = governance_rewards_test.with_distribution_keypair();
+
+    governance_rewards_test.bench.set_unix_time(5).await;
+    let distribution_cookie = governance_rewards_test
+        .with_funded_distribution(&realm_cookie, &key_cookie, 10)
+        .await?;
+
+    let mut users = vec![];
+    for _ in 0..3 {
+        users.push(
+            with_wallet_user(
+                &mut governance_rewards_test,
+                &realm_cookie,
+                &distribution_cookie,
+                1,
+            )
+            .await?,
+        );
+    }
+
+    governance_rewards_test.bench.set_unix_time(11).await;
+
+    let funding = distribution_cookie.funding[0];
+    for (user, preferences) in &users {
+        governance_rewards_test
+            .bench
+            .create_associated_token_account(user.pubkey(), funding.mint)
+            .await?;
+        governance_rewards_test
+            .claim(
+                user,
+                funding.address,
+                funding.mint,
+                &distribution_cookie,
+                preferences,
+            )
+            .await?;
+    }
+
+    // Act
+    let to_receive = governance_rewards_test
+        .bench
+        .with_token_account(&funding.mint)
+        .await?;
+    governance_rewards_test
+        .sweep_funds(&distribution_cookie, 0, &to_receive)
+        .await?;
+
+    // Assert
+    // Each of the three registrants was paid 33, leaving 1 behind
+    let token_account = governance_rewards_test
+        .bench
+        .get_token_account(&to_receive.address)
+        .await
+        .unwrap();
+    assert_eq!(token_account.amount, 1);
+
+    let wallet = governance_rewards_test
+        .bench
+        .get_token_account(&funding.address)
+        .await
+        .unwrap();
+    assert_eq!(wallet.amount, 0);
+
+    let option = governance_rewards_test
+        .get_distribution_account(distribution_cookie.address)
+        .await
+        .distribution_options[0]
+        .unwrap();
+    assert_eq!(option.total_paid, 99);
+    assert_eq!(option.total_reclaimed, 1);
+    assert_eq!(
+        option.total_paid + option.total_reclaimed + wallet.amount,
+        option.total_amount
+    );
+    assert!(option.extra_reclaimed);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_sweep_after_reclaim() -> TestOutcome {
+    // Arrange
+    let mut governance_rewards_test = GovernanceRewardsTest::start_new().await;
+    let realm_cookie = governance_rewards_test.governance.with_realm().await?;
+    let key_cookie = governance_rewards_test.with_distribution_keypair();
+
+    governance_rewards_test.bench.set_unix_time(9).await;
+    let distribution_cookie = governance_rewards_test
+        .with_funded_distribution(&realm_cookie, &key_cookie, 10)
+        .await?;
+    governance_rewards_test.bench.set_unix_time(11).await;
+
+    let funding = distribution_cookie.funding[0];
+    let to_receive = governance_rewards_test
+        .bench
+        .with_token_account(&funding.mint)
+        .await?;
+    governance_rewards_test
+        .reclaim_funds(&distribution_cookie, 0, &to_receive)
+        .await?;
+
+    // Act
+    governance_rewards_test
+        .sweep_funds(&distribution_cookie, 0, &to_receive)
+        .await?;
+
+    // Assert
+    let token_account = governance_rewards_test
+        .bench
+        .get_token_account(&to_receive.address)
+        .await
+        .unwrap();
+    assert_eq!(token_account.amount, 100);
+
+    let option = governance_rewards_test
+        .get_distribution_account(distribution_cookie.address)
+        .await
+        .distribution_options[0]
+        .unwrap();
+    assert_eq!(option.total_paid, 0);
+    assert_eq!(option.total_reclaimed, 100);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_sweep_before_claims_err() -> TestOutcome {
+    // Arrange
+    let mut governance_rewards_test = GovernanceRewardsTest::start_new().await;
+    let realm_cookie = governance_rewards_test.governance.with_realm().await?;
+    let key_cookie = governance_rewards_test.with_distribution_keypair();
+
+    governance_rewards_test.bench.set_unix_time(5).await;
+    let distribution_cookie = governance_rewards_test
+        .with_funded_distribution(&realm_cookie, &key_cookie, 10)
+        .await?;
+    with_wallet_user(
+        &mut governance_rewards_test,
+        &realm_cookie,
+        &distribution_cookie,
+        10,
+    )
+    .await?;
+    governance_rewards_test.bench.set_unix_time(11).await;
+
+    // Act
+    let to_receive = governance_rewards_test
+        .bench
+        .with_token_account(&distribution_cookie.funding[0].mint)
+        .await?;
+    let err = governance_rewards_test
+        .sweep_funds(&distribution_cookie, 0, &to_receive)
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_governance_rewards_err(err, GovernanceRewardsError::CannotSweepYet);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_sweep_with_unaccounted_funds_err() -> TestOutcome {
+    // Arrange
+    let mut governance_rewards_test = GovernanceRewardsTest::start_new().await;
+    let realm_cookie = governance_rewards_test.governance.with_realm().await?;
+    let key_cookie = governance_rewards_test.with_distribution_keypair();
+
+    governance_rewards_test.bench.set_unix_time(9).await;
+    let distribution_cookie = governance_rewards_test
+        .with_funded_distribution(&realm_cookie, &key_cookie, 10)
+        .await?;
+
+    let mut distribution_data = governance_rewards_test
+        .get_distribution_account(distribution_cookie.address)
+        .await;
+    distribution_data.distribution_options[0] = Some(DistributionOption {
+        total_amount: 200,
+        ..distribution_data.distribution_options[0].unwrap()
+    });
+    governance_rewards_test
+        .bench
+        .set_anchor_account(
+            &distribution_data,
+            distribution_cookie.address,
+            governance_rewards::id(),
+        )
+        .await?;
+    governance_rewards_test.bench.set_unix_time(11).await;
+
+    // Act
+    let to_receive = governance_rewards_test
+        .bench
+        .with_token_account(&distribution_cookie.funding[0].mint)
+        .await?;
+    let err = governance_rewards_test
+        .sweep_funds(&distribution_cookie, 0, &to_receive)
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_governance_rewards_err(err, GovernanceRewardsError::ReconciliationFailed);
+
+    Ok(())
+}
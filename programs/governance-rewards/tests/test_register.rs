@@ -4,6 +4,7 @@ use governance_rewards::{
     state::{
         addin::{VoterWeightAction, VoterWeightRecord},
         claim_data::ClaimData,
+        distribution_preferences::DistributionPreferences,
         preferences::UserPreferences,
     },
 };
@@ -82,6 +83,14 @@ async fn test_register() -> TestOutcome {
     assert!(!claim_record.has_claimed);
     assert!(claim_record.has_registered);
     assert_eq!(claim_record.weight, vote_weight);
+    assert_eq!(
+        claim_record.payer,
+        governance_rewards_test.bench.payer.pubkey()
+    );
+    assert_eq!(
+        claim_record.address().unwrap(),
+        ClaimData::get_address(vwr.user, distribution_cookie.address)
+    );
 
     Ok(())
 }
@@ -513,8 +522,9 @@ async fn test_register_with_preferred_mint() -> TestOutcome {
     governance_rewards_test
         .with_preferences(
             &UserPreferences {
-                preferred_mint: Some(funding_mint_2.address),
+                preferred_mints: vec![funding_mint_2.address],
                 resolution_preference: Default::default(),
+                ..Default::default()
             },
             &realm_cookie,
             vwr.user,
@@ -609,8 +619,9 @@ async fn test_register_with_unavailable_preferred_mint() -> TestOutcome {
     governance_rewards_test
         .with_preferences(
             &UserPreferences {
-                preferred_mint: Some(Keypair::new().pubkey()),
+                preferred_mints: vec![Keypair::new().pubkey()],
                 resolution_preference: Default::default(),
+                ..Default::default()
             },
             &realm_cookie,
             vwr.user,
@@ -645,3 +656,250 @@ async fn test_register_with_unavailable_preferred_mint() -> TestOutcome {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_register_with_ranked_preferred_mints() -> TestOutcome {
+    // Arrange
+    let mut governance_rewards_test = GovernanceRewardsTest::start_new().await;
+    let realm_cookie = governance_rewards_test.governance.with_realm().await?;
+    let key_cookie = governance_rewards_test.with_distribution_keypair();
+
+    let funding_amount = 100;
+    let funding_mint_1 = governance_rewards_test.bench.with_mint().await?;
+    let funding_account_1 = governance_rewards_test
+        .with_owned_tokens(&funding_mint_1, &key_cookie, funding_amount)
+        .await?;
+
+    let funding_amount = 150;
+    let funding_mint_2 = governance_rewards_test.bench.with_mint().await?;
+    let funding_account_2 = governance_rewards_test
+        .with_owned_tokens(&funding_mint_2, &key_cookie, funding_amount)
+        .await?;
+
+    let funding_amount = 200;
+    let funding_mint_3 = governance_rewards_test.bench.with_mint().await?;
+    let funding_account_3 = governance_rewards_test
+        .with_owned_tokens(&funding_mint_3, &key_cookie, funding_amount)
+        .await?;
+
+    let distribution_cookie = governance_rewards_test
+        .with_distribution(
+            &realm_cookie,
+            &key_cookie,
+            u64::max_value(),
+            &[&funding_account_1, &funding_account_2, &funding_account_3],
+        )
+        .await?;
+    let token_mint = governance_rewards_test.bench.with_mint().await?;
+    let token_account = governance_rewards_test
+        .with_owned_tokens(&token_mint, &key_cookie, 1)
+        .await?;
+
+    let vote_weight = 10;
+    let vwr = governance_rewards_test
+        .with_dummy_voter_weight_record(
+            &VoterWeightRecord::create_test(
+                realm_cookie.address,
+                token_mint.address,
+                token_account.address,
+                distribution_cookie.address,
+                vote_weight,
+                Some(u64::MAX),
+            ),
+            distribution_cookie.account.voter_weight_program,
+        )
+        .await?;
+
+    governance_rewards_test
+        .with_preferences(
+            &UserPreferences {
+                preferred_mints: vec![
+                    Keypair::new().pubkey(),
+                    funding_mint_3.address,
+                    funding_mint_2.address,
+                ],
+                resolution_preference: Default::default(),
+                ..Default::default()
+            },
+            &realm_cookie,
+            vwr.user,
+        )
+        .await?;
+    governance_rewards_test.bench.advance_clock().await;
+
+    // Act
+    governance_rewards_test
+        .with_registrant(&distribution_cookie, &vwr)
+        .await?;
+
+    // Assert
+    let distribution_record = governance_rewards_test
+        .get_distribution_account(distribution_cookie.address)
+        .await;
+
+    assert_eq!(
+        distribution_record.distribution_options[2]
+            .unwrap()
+            .total_vote_weight,
+        vote_weight
+    );
+
+    let claim_record = governance_rewards_test
+        .bench
+        .get_anchor_account::<ClaimData>(ClaimData::get_address(
+            vwr.user,
+            distribution_cookie.address,
+        ))
+        .await;
+    assert_eq!(claim_record.claim_option, 2);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_register_with_distribution_preferred_mint() -> TestOutcome {
+    // Arrange
+    let mut governance_rewards_test = GovernanceRewardsTest::start_new().await;
+    let realm_cookie = governance_rewards_test.governance.with_realm().await?;
+    let key_cookie = governance_rewards_test.with_distribution_keypair();
+
+    let funding_amount = 100;
+    let funding_mint_1 = governance_rewards_test.bench.with_mint().await?;
+    let funding_account_1 = governance_rewards_test
+        .with_owned_tokens(&funding_mint_1, &key_cookie, funding_amount)
+        .await?;
+
+    let funding_amount = 150;
+    let funding_mint_2 = governance_rewards_test.bench.with_mint().await?;
+    let funding_account_2 = governance_rewards_test
+        .with_owned_tokens(&funding_mint_2, &key_cookie, funding_amount)
+        .await?;
+
+    let distribution_cookie = governance_rewards_test
+        .with_distribution(
+            &realm_cookie,
+            &key_cookie,
+            u64::max_value(),
+            &[&funding_account_1, &funding_account_2],
+        )
+        .await?;
+    let token_mint = governance_rewards_test.bench.with_mint().await?;
+    let token_account = governance_rewards_test
+        .with_owned_tokens(&token_mint, &key_cookie, 1)
+        .await?;
+
+    let vote_weight = 10;
+    let vwr = governance_rewards_test
+        .with_dummy_voter_weight_record(
+            &VoterWeightRecord::create_test(
+                realm_cookie.address,
+                token_mint.address,
+                token_account.address,
+                distribution_cookie.address,
+                vote_weight,
+                Some(u64::MAX),
+            ),
+            distribution_cookie.account.voter_weight_program,
+        )
+        .await?;
+
+    governance_rewards_test
+        .with_preferences(
+            &UserPreferences {
+                preferred_mints: vec![funding_mint_1.address],
+                resolution_preference: Default::default(),
+                ..Default::default()
+            },
+            &realm_cookie,
+            vwr.user,
+        )
+        .await?;
+    governance_rewards_test
+        .with_distribution_preferences(
+            &DistributionPreferences {
+                preferred_mint: Some(funding_mint_2.address),
+                resolution_preference: None,
+            },
+            &distribution_cookie,
+            vwr.user,
+        )
+        .await?;
+    governance_rewards_test.bench.advance_clock().await;
+
+    // Act
+    governance_rewards_test
+        .with_registrant(&distribution_cookie, &vwr)
+        .await?;
+
+    // Assert
+    let claim_record = governance_rewards_test
+        .bench
+        .get_anchor_account::<ClaimData>(ClaimData::get_address(
+            vwr.user,
+            distribution_cookie.address,
+        ))
+        .await;
+    assert_eq!(claim_record.claim_option, 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_register_with_overflowing_weight_err() -> TestOutcome {
+    // Arrange
+    let mut governance_rewards_test = GovernanceRewardsTest::start_new().await;
+    let realm_cookie = governance_rewards_test.governance.with_realm().await?;
+    let key_cookie = governance_rewards_test.with_distribution_keypair();
+    let distribution_cookie = governance_rewards_test
+        .with_funded_distribution(&realm_cookie, &key_cookie, u64::max_value())
+        .await?;
+    let token_mint = governance_rewards_test.bench.with_mint().await?;
+
+    let whale_vwr = governance_rewards_test
+        .with_dummy_voter_weight_record(
+            &VoterWeightRecord::create_test(
+                realm_cookie.address,
+                token_mint.address,
+                Keypair::new().pubkey(),
+                distribution_cookie.address,
+                u64::MAX,
+                Some(u64::MAX),
+            ),
+            distribution_cookie.account.voter_weight_program,
+        )
+        .await?;
+    governance_rewards_test
+        .with_registrant(&distribution_cookie, &whale_vwr)
+        .await?;
+
+    let vwr = governance_rewards_test
+        .with_dummy_voter_weight_record(
+            &VoterWeightRecord::create_test(
+                realm_cookie.address,
+                token_mint.address,
+                Keypair::new().pubkey(),
+                distribution_cookie.address,
+                1,
+                Some(u64::MAX),
+            ),
+            distribution_cookie.account.voter_weight_program,
+        )
+        .await?;
+
+    // Act
+    let err = governance_rewards_test
+        .with_registrant(&distribution_cookie, &vwr)
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_governance_rewards_err(err, GovernanceRewardsError::MathOverflow);
+
+    let distribution_record = governance_rewards_test
+        .get_distribution_account(distribution_cookie.address)
+        .await;
+    assert_eq!(distribution_record.total_vote_weight, u64::MAX);
+
+    Ok(())
+}
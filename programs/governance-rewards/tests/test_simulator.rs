@@ -0,0 +1,316 @@
+use crate::program_test::governance_rewards_test::{
+    DistributionCookie, GovernanceRewardsTest, PreferenceCookie, VoterWeightRecordCookie,
+};
+use crate::program_test::governance_test::RealmCookie;
+use crate::program_test::program_test_bench::MintCookie;
+use governance_rewards::{
+    error::GovernanceRewardsError,
+    state::{
+        addin::VoterWeightRecord,
+        claim_data::ClaimData,
+        preferences::{ResolutionPreference, UserPreferences},
+    },
+};
+use governance_rewards_client::simulate::{simulate_registration, Registration};
+use solana_program::pubkey::Pubkey;
+use solana_program_test::tokio;
+use solana_sdk::{signature::Keypair, signer::Signer, transport::TransportError};
+
+mod program_test;
+
+type TestOutcome = Result<(), TransportError>;
+
+/**
+ * A distribution closing registration at time 10, paying 100 tokens of its first mint
+ * and 150 of its second, with one registrant of weight 10 on the first option.
+ */
+struct SimulatorCookie {
+    realm_cookie: RealmCookie,
+    distribution_cookie: DistributionCookie,
+    first_mint: MintCookie,
+    second_mint: MintCookie,
+}
+
+async fn with_simulated_distribution(
+    governance_rewards_test: &mut GovernanceRewardsTest,
+) -> Result<SimulatorCookie, TransportError> {
+    let realm_cookie = governance_rewards_test.governance.with_realm().await?;
+    let key_cookie = governance_rewards_test.with_distribution_keypair();
+
+    let first_mint = governance_rewards_test.bench.with_mint().await?;
+    let first_funding = governance_rewards_test
+        .with_owned_tokens(&first_mint, &key_cookie, 100)
+        .await?;
+    let second_mint = governance_rewards_test.bench.with_mint().await?;
+    let second_funding = governance_rewards_test
+        .with_owned_tokens(&second_mint, &key_cookie, 150)
+        .await?;
+
+    governance_rewards_test.bench.set_unix_time(5).await;
+    let distribution_cookie = governance_rewards_test
+        .with_distribution(
+            &realm_cookie,
+            &key_cookie,
+            10,
+            &[&first_funding, &second_funding],
+        )
+        .await?;
+
+    let other = with_voter(
+        governance_rewards_test,
+        &realm_cookie,
+        &distribution_cookie,
+        Keypair::new().pubkey(),
+        10,
+    )
+    .await?;
+    governance_rewards_test
+        .with_registrant(&distribution_cookie, &other)
+        .await?;
+
+    Ok(SimulatorCookie {
+        realm_cookie,
+        distribution_cookie,
+        first_mint,
+        second_mint,
+    })
+}
+
+async fn with_voter(
+    governance_rewards_test: &mut GovernanceRewardsTest,
+    realm_cookie: &RealmCookie,
+    distribution_cookie: &DistributionCookie,
+    user: Pubkey,
+    vote_weight: u64,
+) -> Result<VoterWeightRecordCookie, TransportError> {
+    let token_mint = governance_rewards_test.bench.with_mint().await?;
+    governance_rewards_test
+        .with_dummy_voter_weight_record(
+            &VoterWeightRecord::create_test(
+                realm_cookie.address,
+                token_mint.address,
+                user,
+                distribution_cookie.address,
+                vote_weight,
+                Some(u64::MAX),
+            ),
+            distribution_cookie.account.voter_weight_program,
+        )
+        .await
+}
+
+async fn with_preferred_mint(
+    governance_rewards_test: &mut GovernanceRewardsTest,
+    realm_cookie: &RealmCookie,
+    user: Pubkey,
+    mint: Pubkey,
+) -> Result<PreferenceCookie, TransportError> {
+    governance_rewards_test
+        .with_preferences(
+            &UserPreferences {
+                preferred_mints: vec![mint],
+                resolution_preference: ResolutionPreference::Wallet,
+                ..Default::default()
+            },
+            realm_cookie,
+            user,
+        )
+        .await
+}
+
+/**
+ * Claims the rewards of `user` from the distribution's second option, returning the
+ * amount paid.
+ */
+async fn claim_second_option(
+    governance_rewards_test: &mut GovernanceRewardsTest,
+    cookie: &SimulatorCookie,
+    user: &Keypair,
+    preferences: &PreferenceCookie,
+) -> Result<u64, TransportError> {
+    governance_rewards_test.bench.set_unix_time(11).await;
+    let payout = cookie.distribution_cookie.funding[1];
+    let user_token_account = governance_rewards_test
+        .bench
+        .create_associated_token_account(user.pubkey(), payout.mint)
+        .await?;
+    governance_rewards_test
+        .claim(
+            user,
+            payout.address,
+            payout.mint,
+            &cookie.distribution_cookie,
+            preferences,
+        )
+        .await?;
+
+    Ok(governance_rewards_test
+        .bench
+        .get_token_account(&user_token_account.address)
+        .await
+        .unwrap()
+        .amount)
+}
+
+#[tokio::test]
+async fn test_simulate_registration() -> TestOutcome {
+    // Arrange
+    let mut governance_rewards_test = GovernanceRewardsTest::start_new().await;
+    let cookie = with_simulated_distribution(&mut governance_rewards_test).await?;
+    let user = Keypair::new();
+    let vwr = with_voter(
+        &mut governance_rewards_test,
+        &cookie.realm_cookie,
+        &cookie.distribution_cookie,
+        user.pubkey(),
+        30,
+    )
+    .await?;
+    let preferences = with_preferred_mint(
+        &mut governance_rewards_test,
+        &cookie.realm_cookie,
+        user.pubkey(),
+        cookie.second_mint.address,
+    )
+    .await?;
+    let distribution = governance_rewards_test
+        .get_distribution_account(cookie.distribution_cookie.address)
+        .await;
+    let now = governance_rewards_test
+        .bench
+        .get_clock()
+        .await
+        .unix_timestamp as u64;
+
+    // Act
+    let projection = simulate_registration(
+        &distribution,
+        &Registration {
+            weight: 30,
+            preferred_mints: vec![cookie.second_mint.address],
+        },
+        None,
+        now,
+    )
+    .unwrap();
+
+    // Assert
+    assert_eq!(projection.option, 1);
+    assert_eq!(projection.payout().amount, 150 * 30 / 40);
+    assert_eq!(
+        projection
+            .payout_in(cookie.first_mint.address)
+            .unwrap()
+            .amount,
+        100 * 30 / 40
+    );
+    assert!(projection.payout_in(Pubkey::new_unique()).is_none());
+
+    governance_rewards_test
+        .with_registrant(&cookie.distribution_cookie, &vwr)
+        .await?;
+    let paid =
+        claim_second_option(&mut governance_rewards_test, &cookie, &user, &preferences).await?;
+    assert_eq!(paid, projection.payout().amount);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_simulate_switching_preferred_mint() -> TestOutcome {
+    // Arrange
+    let mut governance_rewards_test = GovernanceRewardsTest::start_new().await;
+    let cookie = with_simulated_distribution(&mut governance_rewards_test).await?;
+    let user = Keypair::new();
+    let vwr = with_voter(
+        &mut governance_rewards_test,
+        &cookie.realm_cookie,
+        &cookie.distribution_cookie,
+        user.pubkey(),
+        30,
+    )
+    .await?;
+    governance_rewards_test
+        .with_registrant(&cookie.distribution_cookie, &vwr)
+        .await?;
+
+    let distribution = governance_rewards_test
+        .get_distribution_account(cookie.distribution_cookie.address)
+        .await;
+    let claim_data = governance_rewards_test
+        .bench
+        .get_anchor_account::<ClaimData>(ClaimData::get_address(
+            user.pubkey(),
+            cookie.distribution_cookie.address,
+        ))
+        .await;
+    let now = governance_rewards_test
+        .bench
+        .get_clock()
+        .await
+        .unix_timestamp as u64;
+
+    // Act
+    let projection = simulate_registration(
+        &distribution,
+        &Registration {
+            weight: 30,
+            preferred_mints: vec![cookie.second_mint.address],
+        },
+        Some(&claim_data),
+        now,
+    )
+    .unwrap();
+
+    // Assert
+    assert_eq!(claim_data.claim_option, 0);
+    assert_eq!(projection.option, 1);
+    assert_eq!(projection.payout().amount, 150 * 30 / 40);
+
+    let preferences = with_preferred_mint(
+        &mut governance_rewards_test,
+        &cookie.realm_cookie,
+        user.pubkey(),
+        cookie.second_mint.address,
+    )
+    .await?;
+    governance_rewards_test
+        .switch_option(&cookie.distribution_cookie, user.pubkey())
+        .await?;
+    let paid =
+        claim_second_option(&mut governance_rewards_test, &cookie, &user, &preferences).await?;
+    assert_eq!(paid, projection.payout().amount);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_simulate_registration_after_cutoff_err() -> TestOutcome {
+    // Arrange
+    let mut governance_rewards_test = GovernanceRewardsTest::start_new().await;
+    let cookie = with_simulated_distribution(&mut governance_rewards_test).await?;
+    let distribution = governance_rewards_test
+        .get_distribution_account(cookie.distribution_cookie.address)
+        .await;
+
+    // Act
+    let err = simulate_registration(
+        &distribution,
+        &Registration {
+            weight: 30,
+            preferred_mints: vec![],
+        },
+        None,
+        distribution.registration_period_end_ts,
+    )
+    .err()
+    .unwrap();
+
+    // Assert
+    assert_eq!(
+        err,
+        anchor_lang::error::Error::from(GovernanceRewardsError::RegistrationOver)
+    );
+
+    Ok(())
+}
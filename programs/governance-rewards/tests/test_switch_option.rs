@@ -0,0 +1,162 @@
+use crate::program_test::governance_rewards_test::GovernanceRewardsTest;
+use crate::program_test::tools::assert_governance_rewards_err;
+use governance_rewards::{
+    error::GovernanceRewardsError,
+    state::{addin::VoterWeightRecord, claim_data::ClaimData, preferences::UserPreferences},
+};
+use solana_program_test::tokio;
+use solana_sdk::transport::TransportError;
+
+mod program_test;
+
+type TestOutcome = Result<(), TransportError>;
+
+#[tokio::test]
+async fn test_switch_option() -> TestOutcome {
+    // Arrange
+    let mut governance_rewards_test = GovernanceRewardsTest::start_new().await;
+    let realm_cookie = governance_rewards_test.governance.with_realm().await?;
+    let key_cookie = governance_rewards_test.with_distribution_keypair();
+
+    let funding_amount = 100;
+    let funding_mint_1 = governance_rewards_test.bench.with_mint().await?;
+    let funding_account_1 = governance_rewards_test
+        .with_owned_tokens(&funding_mint_1, &key_cookie, funding_amount)
+        .await?;
+
+    let funding_amount = 150;
+    let funding_mint_2 = governance_rewards_test.bench.with_mint().await?;
+    let funding_account_2 = governance_rewards_test
+        .with_owned_tokens(&funding_mint_2, &key_cookie, funding_amount)
+        .await?;
+
+    let distribution_cookie = governance_rewards_test
+        .with_distribution(
+            &realm_cookie,
+            &key_cookie,
+            u64::max_value(),
+            &[&funding_account_1, &funding_account_2],
+        )
+        .await?;
+    let token_mint = governance_rewards_test.bench.with_mint().await?;
+    let token_account = governance_rewards_test
+        .with_owned_tokens(&token_mint, &key_cookie, 1)
+        .await?;
+
+    let vote_weight = 10;
+    let vwr = governance_rewards_test
+        .with_dummy_voter_weight_record(
+            &VoterWeightRecord::create_test(
+                realm_cookie.address,
+                token_mint.address,
+                token_account.address,
+                distribution_cookie.address,
+                vote_weight,
+                Some(u64::MAX),
+            ),
+            distribution_cookie.account.voter_weight_program,
+        )
+        .await?;
+
+    governance_rewards_test
+        .with_registrant(&distribution_cookie, &vwr)
+        .await?;
+
+    governance_rewards_test
+        .with_preferences(
+            &UserPreferences {
+                preferred_mints: vec![funding_mint_2.address],
+                resolution_preference: Default::default(),
+                ..Default::default()
+            },
+            &realm_cookie,
+            vwr.user,
+        )
+        .await?;
+
+    // Act
+    governance_rewards_test
+        .switch_option(&distribution_cookie, vwr.user)
+        .await?;
+
+    // Assert
+    let distribution_record = governance_rewards_test
+        .get_distribution_account(distribution_cookie.address)
+        .await;
+
+    assert_eq!(distribution_record.total_vote_weight, vote_weight);
+    assert_eq!(
+        distribution_record.distribution_options[0]
+            .unwrap()
+            .total_vote_weight,
+        0
+    );
+    assert_eq!(
+        distribution_record.distribution_options[1]
+            .unwrap()
+            .total_vote_weight,
+        vote_weight
+    );
+
+    let claim_record = governance_rewards_test
+        .bench
+        .get_anchor_account::<ClaimData>(ClaimData::get_address(
+            vwr.user,
+            distribution_cookie.address,
+        ))
+        .await;
+    assert_eq!(claim_record.claim_option, 1);
+    assert_eq!(claim_record.weight, vote_weight);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_switch_option_after_registration_err() -> TestOutcome {
+    // Arrange
+    let mut governance_rewards_test = GovernanceRewardsTest::start_new().await;
+    let realm_cookie = governance_rewards_test.governance.with_realm().await?;
+    let key_cookie = governance_rewards_test.with_distribution_keypair();
+
+    governance_rewards_test.bench.set_unix_time(5).await;
+    let distribution_cookie = governance_rewards_test
+        .with_funded_distribution(&realm_cookie, &key_cookie, 10)
+        .await?;
+    let token_mint = governance_rewards_test.bench.with_mint().await?;
+    let token_account = governance_rewards_test
+        .with_owned_tokens(&token_mint, &key_cookie, 1)
+        .await?;
+
+    let vote_weight = 10;
+    let vwr = governance_rewards_test
+        .with_dummy_voter_weight_record(
+            &VoterWeightRecord::create_test(
+                realm_cookie.address,
+                token_mint.address,
+                token_account.address,
+                distribution_cookie.address,
+                vote_weight,
+                Some(u64::MAX),
+            ),
+            distribution_cookie.account.voter_weight_program,
+        )
+        .await?;
+
+    governance_rewards_test
+        .with_registrant(&distribution_cookie, &vwr)
+        .await?;
+
+    governance_rewards_test.bench.set_unix_time(11).await;
+
+    // Act
+    let err = governance_rewards_test
+        .switch_option(&distribution_cookie, vwr.user)
+        .await
+        .err()
+        .unwrap();
+
+    // Assert
+    assert_governance_rewards_err(err, GovernanceRewardsError::RegistrationOver);
+
+    Ok(())
+}